@@ -0,0 +1,51 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API of the parachain staking pallet, for wallets and dashboards to read the staking
+//! state without decoding its storage.
+//! Runtimes implement it by forwarding each call to the function of the same name of
+//! `pallet_parachain_staking::Pallet`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+
+pub use pallet_parachain_staking::{
+    CandidateProfile, NominationPlacement, NominationPosition, StakingRewards,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait ParachainStakingApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// The profile of `candidate`, None if it is not a candidate
+        fn candidate_profile(candidate: AccountId) -> Option<CandidateProfile<AccountId, Balance>>;
+        /// The nominations of `nominator`, with their placement and pending request
+        fn nomination_positions(
+            nominator: AccountId,
+        ) -> Vec<NominationPosition<AccountId, Balance>>;
+        /// The minimum amount of a new nomination to enter the top nominations of `candidate`,
+        /// None if it is not a candidate
+        fn min_top_nomination_stake(candidate: AccountId) -> Option<Balance>;
+        /// The minimum total counted stake of a candidate to be selected at the next era
+        fn min_selection_stake() -> Balance;
+        /// The rewards of `account` which are not in its balance yet
+        fn staking_rewards(account: AccountId) -> StakingRewards<Balance>;
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Nimbus author filtering of the selected candidates
+
+use crate::pallet::{Config, EligibleRatio, Pallet};
+use frame_support::traits::Randomness;
+use nimbus_primitives::{AccountLookup, CanAuthor, EventHandler, NimbusId};
+use parity_scale_codec::Decode;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the sorted selected candidates eligible to author `slot`: all of them, or a
+    /// random `EligibleRatio` of them, rounded up, when it is set.
+    pub fn eligible_authors(slot: u32) -> Vec<T::AccountId> {
+        let mut selected = Self::selected_candidates().into_inner();
+        let ratio = match <EligibleRatio<T>>::get() {
+            Some(ratio) => ratio,
+            None => return selected,
+        };
+        let count = ratio.mul_ceil(selected.len() as u32) as usize;
+        if count >= selected.len() {
+            return selected
+        }
+
+        let (randomness, _) = T::RandomnessSource::random(b"author_filter");
+        let mut eligible = Vec::with_capacity(count);
+        for i in 0..count as u32 {
+            let hash = BlakeTwo256::hash_of(&(randomness, slot, i));
+            let index = u32::decode(&mut hash.as_ref()).unwrap_or_default() as usize;
+            eligible.push(selected.swap_remove(index % selected.len()));
+        }
+        eligible.sort();
+        eligible
+    }
+}
+
+impl<T: Config> CanAuthor<T::AccountId> for Pallet<T> {
+    fn can_author(account: &T::AccountId, slot: &u32) -> bool {
+        Self::eligible_authors(*slot).binary_search(account).is_ok()
+    }
+}
+
+/// Lets the nimbus author inherent drive block author rewards.
+impl<T: Config> EventHandler<T::AccountId> for Pallet<T> {
+    fn note_author(author: T::AccountId) {
+        Self::award_points_to_block_author(author);
+    }
+}
+
+/// For chains where collators author with the key of their account: the nimbus key is decoded as
+/// the account, which must be a selected candidate.
+impl<T: Config> AccountLookup<T::AccountId> for Pallet<T> {
+    fn lookup_account(author: &NimbusId) -> Option<T::AccountId> {
+        T::AccountId::decode(&mut AsRef::<[u8]>::as_ref(author))
+            .ok()
+            .filter(Self::is_selected_candidate)
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Auto-compounding and restaking functionality for rewards
+
+use crate::{
+    pallet::{
+        AutoCompoundingNominations, BalanceOf, CandidateInfo, Config, Error, Event, NominatorState,
+        Pallet,
+    },
+    types::AddGet,
+};
+use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    ensure,
+    storage::{with_transaction, TransactionOutcome},
+    BoundedVec, RuntimeDebug,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, Percent};
+use sp_std::prelude::*;
+
+/// Represents the auto-compounding amount for a nomination.
+#[derive(
+    Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord, MaxEncodedLen,
+)]
+pub struct AutoCompoundConfig<AccountId> {
+    pub nominator: AccountId,
+    pub value: Percent,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Sets the auto-compounding value for a nomination. A zero value removes the config.
+    /// The nomination must exist.
+    pub(crate) fn nomination_set_auto_compound(
+        candidate: T::AccountId,
+        nominator: T::AccountId,
+        value: Percent,
+        candidate_auto_compounding_nomination_count_hint: u32,
+        nomination_count_hint: u32,
+    ) -> DispatchResultWithPostInfo {
+        let state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
+        ensure!(
+            state.nominations.0.len() as u32 <= nomination_count_hint,
+            <Error<T>>::TooLowNominationCountToAutoCompound,
+        );
+        ensure!(state.get_bond_amount(&candidate).is_some(), <Error<T>>::NominationDNE);
+
+        let mut auto_compounding = <AutoCompoundingNominations<T>>::get(&candidate);
+        ensure!(
+            auto_compounding.len() as u32 <= candidate_auto_compounding_nomination_count_hint,
+            <Error<T>>::TooLowCandidateAutoCompoundingNominationCountToAutoCompound,
+        );
+
+        Self::set_auto_compound_config(&mut auto_compounding, nominator.clone(), value)?;
+        <AutoCompoundingNominations<T>>::insert(candidate.clone(), auto_compounding);
+
+        Self::deposit_event(Event::AutoCompoundSet { candidate, nominator, value });
+        Ok(().into())
+    }
+
+    /// Inserts, updates or removes (if `value` is zero) the nominator's config in the sorted
+    /// list of auto-compounding configs of a candidate.
+    /// The list needs to be persisted by the caller of this function.
+    pub(crate) fn set_auto_compound_config(
+        auto_compounding: &mut BoundedVec<
+            AutoCompoundConfig<T::AccountId>,
+            AddGet<T::MaxTopNominationsPerCandidate, T::MaxBottomNominationsPerCandidate>,
+        >,
+        nominator: T::AccountId,
+        value: Percent,
+    ) -> DispatchResult {
+        match auto_compounding.binary_search_by(|c| c.nominator.cmp(&nominator)) {
+            Ok(idx) =>
+                if value.is_zero() {
+                    auto_compounding.remove(idx);
+                } else if let Some(config) = auto_compounding.get_mut(idx) {
+                    config.value = value;
+                },
+            Err(idx) =>
+                if !value.is_zero() {
+                    auto_compounding
+                        .try_insert(idx, AutoCompoundConfig { nominator, value })
+                        .map_err(|_| <Error<T>>::ExceedMaxNominationsPerNominator)?;
+                },
+        }
+        Ok(())
+    }
+
+    /// Removes the auto-compounding config of a nomination, if it exists.
+    pub(crate) fn nomination_remove_auto_compound(
+        candidate: &T::AccountId,
+        nominator: &T::AccountId,
+    ) {
+        let mut auto_compounding = <AutoCompoundingNominations<T>>::get(candidate);
+        if let Ok(idx) = auto_compounding.binary_search_by(|c| c.nominator.cmp(nominator)) {
+            auto_compounding.remove(idx);
+            <AutoCompoundingNominations<T>>::insert(candidate, auto_compounding);
+        }
+    }
+
+    /// Returns the auto-compounding value of a nomination, zero if none is set.
+    pub fn nomination_auto_compound(candidate: &T::AccountId, nominator: &T::AccountId) -> Percent {
+        let auto_compounding = <AutoCompoundingNominations<T>>::get(candidate);
+        auto_compounding
+            .binary_search_by(|c| c.nominator.cmp(nominator))
+            .map(|idx| auto_compounding[idx].value)
+            .unwrap_or_else(|_| Percent::zero())
+    }
+
+    /// Restakes `amount` of a paid reward into the nomination towards `candidate`, through the
+    /// same path as `nominator_bond_more`. Nominations pending revocation are not compounded.
+    /// Returns true if the amount was restaked.
+    pub(crate) fn nomination_compound(
+        candidate: T::AccountId,
+        nominator: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> bool {
+        if amount.is_zero() || Self::nomination_request_revoke_exists(&candidate, &nominator) {
+            return false
+        }
+        let mut state = match <NominatorState<T>>::get(&nominator) {
+            Some(state) => state,
+            None => return false,
+        };
+        match revert_on_error(|| state.increase_nomination::<T>(candidate.clone(), amount)) {
+            Ok(_) => {
+                Self::deposit_event(Event::Compounded { candidate, nominator, amount });
+                true
+            },
+            Err(error) => {
+                log::warn!(
+                    "Auto-compounding {:?} for nominator {:?} failed with error: {:?}",
+                    amount,
+                    nominator,
+                    error
+                );
+                false
+            },
+        }
+    }
+
+    /// Bonds `amount` of a paid reward into the collator candidate self bond, through the same
+    /// path as `candidate_bond_more`.
+    /// Returns true if the amount was bonded.
+    pub(crate) fn candidate_compound(candidate: T::AccountId, amount: BalanceOf<T>) -> bool {
+        if amount.is_zero() {
+            return false
+        }
+        let mut state = match <CandidateInfo<T>>::get(&candidate) {
+            Some(state) => state,
+            None => return false,
+        };
+        if let Err(error) = revert_on_error(|| state.bond_more::<T>(candidate.clone(), amount)) {
+            log::warn!(
+                "Restaking {:?} for candidate {:?} failed with error: {:?}",
+                amount,
+                candidate,
+                error
+            );
+            return false
+        }
+        let (is_active, total_counted) = (state.is_active(), state.total_counted);
+        <CandidateInfo<T>>::insert(&candidate, state);
+        if is_active {
+            Self::update_active(candidate, total_counted);
+        }
+        true
+    }
+}
+
+/// Runs `f`, reverting its storage changes if it fails. Rewards are compounded from the payouts
+/// of `on_initialize`, which are not transactional.
+fn revert_on_error(f: impl FnOnce() -> DispatchResult) -> DispatchResult {
+    with_transaction(|| match f() {
+        Ok(()) => TransactionOutcome::Commit(Ok(())),
+        Err(error) => TransactionOutcome::Rollback(Err(error)),
+    })
+}
@@ -18,11 +18,18 @@
 
 //! Benchmarking
 use crate::{
-    AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, Era, NominationAction, Pallet,
-    Points, ScheduledRequest,
+    AtStake, AwardedPts, BalanceOf, Call, CandidateBondLessRequest, CandidateInfo,
+    ClaimableRewards, CollatorSnapshot, Config, Era, FailedRewardPayment, FailedRewardPayments,
+    LiquidStakingStrategy, NominationAction, NominationTargets, Pallet, PayoutMode,
+    PendingStakingParams, Points, Range, ReportOffence, RewardDestination, ScheduledRequest,
+    SelectedCandidates, StakingParameter, Total, UnappliedSlash, UnappliedSlashes,
+    UnderperformingEras,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, vec};
-use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize, ReservableCurrency};
+use frame_support::{
+    traits::{Currency, Get, OnFinalize, OnInitialize, ReservableCurrency},
+    BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_runtime::{Perbill, Percent};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
@@ -120,6 +127,40 @@ const USER_SEED: u32 = 999666;
 benchmarks! {
     // ROOT DISPATCHABLES
 
+    set_staking_expectations {
+        let stake_range: Range<Perbill> = Range {
+            min: Perbill::from_percent(10),
+            ideal: Perbill::from_percent(20),
+            max: Perbill::from_percent(30),
+        };
+    }: _(RawOrigin::Root, stake_range)
+    verify {
+        assert_eq!(Pallet::<T>::inflation_config().expect, stake_range);
+    }
+
+    set_inflation {
+        let inflation_range: Range<Perbill> = Range {
+            min: Perbill::from_perthousand(1),
+            ideal: Perbill::from_perthousand(2),
+            max: Perbill::from_perthousand(3),
+        };
+    }: _(RawOrigin::Root, inflation_range)
+    verify {
+        assert_eq!(Pallet::<T>::inflation_config().annual, inflation_range);
+    }
+
+    set_parachain_bond_account {
+        let parachain_bond_account: T::AccountId = account("TEST", 0u32, USER_SEED);
+    }: _(RawOrigin::Root, parachain_bond_account.clone())
+    verify {
+        assert_eq!(Pallet::<T>::parachain_bond_info().account, parachain_bond_account);
+    }
+
+    set_parachain_bond_reserve_percent {}: _(RawOrigin::Root, Percent::from_percent(33))
+    verify {
+        assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
+    }
+
     set_total_selected {
         Pallet::<T>::set_blocks_per_era(RawOrigin::Root.into(), 100u32)?;
     }: _(RawOrigin::Root, 100u32)
@@ -132,10 +173,52 @@ benchmarks! {
         assert_eq!(Pallet::<T>::era().length, 1200u32);
     }
 
+    set_payout_mode {}: _(RawOrigin::Root, PayoutMode::Claim)
+    verify {
+        assert_eq!(Pallet::<T>::payout_mode(), PayoutMode::Claim);
+    }
+
+    set_liquid_strategy {}: _(RawOrigin::Root, LiquidStakingStrategy::LeastBacked)
+    verify {
+        assert_eq!(Pallet::<T>::liquid_strategy(), LiquidStakingStrategy::LeastBacked);
+    }
+
+    set_eligible_ratio {}: _(RawOrigin::Root, Some(Percent::from_percent(50)))
+    verify {
+        assert_eq!(Pallet::<T>::eligible_ratio(), Some(Percent::from_percent(50)));
+    }
+
+    set_staking_parameter {
+        // worst case is a parameter already set during the era
+        let params = Pallet::<T>::staking_params();
+        <PendingStakingParams<T>>::put(params);
+        let new = StakingParameter::LeaveCandidatesDelay(params.leave_candidates_delay + 1);
+    }: _(RawOrigin::Root, new)
+    verify {
+        assert_eq!(Pallet::<T>::pending_staking_params().map(|p| p.get(&new)), Some(new));
+    }
+
+    cancel_deferred_slash {
+        // x controls the number of slashes deferred to the era
+        let x in 1..<<T as Config>::MaxCandidates as Get<u32>>::get();
+        for i in 0..x {
+            <UnappliedSlashes<T>>::try_append(3, UnappliedSlash {
+                collator: account::<T::AccountId>("collator", i, USER_SEED),
+                era: 1,
+                own: min_candidate_stk::<T>(),
+                others: BoundedVec::default(),
+            }).expect("no more slashes than MaxCandidates");
+        }
+        let slash_indices: Vec<u32> = (0..x).collect();
+    }: _(RawOrigin::Root, 3, slash_indices)
+    verify {
+        assert!(Pallet::<T>::unapplied_slashes(3).is_empty());
+    }
+
     // USER DISPATCHABLES
 
     join_candidates {
-        let x in 3..1_000;
+        let x in 3..<<T as Config>::MaxCandidates as Get<u32>>::get();
         // Worst Case Complexity is insertion into an ordered list so \exists full list before call
         let mut candidate_count = 1u32;
         for i in 2..x {
@@ -158,7 +241,7 @@ benchmarks! {
     // This call schedules the collator's exit and removes them from the candidate pool
     // -> it retains the self-bond and nominator bonds
     schedule_leave_candidates {
-        let x in 3..1_000;
+        let x in 3..<<T as Config>::MaxCandidates as Get<u32>>::get();
         // Worst Case Complexity is removal from an ordered list so \exists full list before call
         let mut candidate_count = 1u32;
         for i in 2..x {
@@ -245,7 +328,7 @@ benchmarks! {
     }
 
     cancel_leave_candidates {
-        let x in 3..1_000;
+        let x in 3..<<T as Config>::MaxCandidates as Get<u32>>::get();
         // Worst Case Complexity is removal from an ordered list so \exists full list before call
         let mut candidate_count = 1u32;
         for i in 2..x {
@@ -386,6 +469,21 @@ benchmarks! {
         );
     }
 
+    set_commission {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), Perbill::from_percent(10))
+    verify {
+        assert!(
+            Pallet::<T>::candidate_info(&caller).unwrap().commission_request.is_some()
+        );
+    }
+
     nominate {
         let x in 3..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
         let y in 2..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
@@ -446,6 +544,162 @@ benchmarks! {
         assert!(Pallet::<T>::is_nominator(&caller));
     }
 
+    nominate_many {
+        let x in 1..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * x.into());
+        // Worst Case is as many new nominations as the caller can have
+        let mut amounts: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+        for i in 1..=x {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            amounts.push((collator, bond));
+        }
+    }: _(RawOrigin::Signed(caller.clone()), NominationTargets::Amounts(amounts))
+    verify {
+        let state = Pallet::<T>::nominator_state(&caller).expect("caller is a nominator");
+        assert_eq!(state.nominations.0.len() as u32, x);
+    }
+
+    nominate_with_auto_compound {
+        let x in 2..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let y in 0..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let z in 1..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = <<T as Config>::MinNominatorStk as Get<BalanceOf<T>>>::get();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * z.into());
+        // Nominate z - 1 other collator candidates first
+        let mut del_del_count = 0u32;
+        for i in 1..z {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            Pallet::<T>::nominate(
+                RawOrigin::Signed(caller.clone()).into(), collator, bond, 0u32, del_del_count
+            )?;
+            del_del_count += 1u32;
+        }
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            z,
+        )?;
+        // Fill the collator with x - 1 nominations, of which up to y auto-compound
+        let mut col_del_count = 0u32;
+        let mut col_auto_compound_count = 0u32;
+        for i in 1..x {
+            let nominator = create_funded_nominator::<T>(
+                "nominator",
+                USER_SEED + i,
+                0u32.into(),
+                collator.clone(),
+                true,
+                col_del_count,
+            )?;
+            col_del_count += 1u32;
+            if col_auto_compound_count < y {
+                Pallet::<T>::set_auto_compound(
+                    RawOrigin::Signed(nominator).into(),
+                    collator.clone(),
+                    Percent::from_percent(50),
+                    col_auto_compound_count,
+                    1u32,
+                )?;
+                col_auto_compound_count += 1u32;
+            }
+        }
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        collator.clone(),
+        bond,
+        Percent::from_percent(50),
+        col_del_count,
+        col_auto_compound_count,
+        del_del_count
+    )
+    verify {
+        assert!(Pallet::<T>::is_nominator(&caller));
+        assert_eq!(
+            Pallet::<T>::nomination_auto_compound(&collator, &caller),
+            Percent::from_percent(50)
+        );
+    }
+
+    set_auto_compound {
+        let x in 0..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let y in 1..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = <<T as Config>::MinNominatorStk as Get<BalanceOf<T>>>::get();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * y.into());
+        // Nominate y - 1 other collator candidates first
+        let mut del_del_count = 0u32;
+        for i in 1..y {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            Pallet::<T>::nominate(
+                RawOrigin::Signed(caller.clone()).into(), collator, bond, 0u32, del_del_count
+            )?;
+            del_del_count += 1u32;
+        }
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            y,
+        )?;
+        Pallet::<T>::nominate(
+            RawOrigin::Signed(caller.clone()).into(), collator.clone(), bond, 0u32, del_del_count
+        )?;
+        del_del_count += 1u32;
+        // Fill the auto-compounding list of the collator with x other nominations
+        let mut col_auto_compound_count = 0u32;
+        for i in 0..x {
+            let nominator = create_funded_nominator::<T>(
+                "nominator",
+                USER_SEED + i + 1,
+                0u32.into(),
+                collator.clone(),
+                true,
+                col_auto_compound_count + 1u32,
+            )?;
+            Pallet::<T>::set_auto_compound(
+                RawOrigin::Signed(nominator).into(),
+                collator.clone(),
+                Percent::from_percent(50),
+                col_auto_compound_count,
+                1u32,
+            )?;
+            col_auto_compound_count += 1u32;
+        }
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        collator.clone(),
+        Percent::from_percent(50),
+        col_auto_compound_count,
+        del_del_count
+    )
+    verify {
+        assert_eq!(
+            Pallet::<T>::nomination_auto_compound(&collator, &caller),
+            Percent::from_percent(50)
+        );
+    }
+
     schedule_leave_nominators {
         let collator: T::AccountId = create_funded_collator::<T>(
             "collator",
@@ -763,99 +1017,592 @@ benchmarks! {
         );
     }
 
-    // ON_INITIALIZE
+    set_payee {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let (payee, _) = create_funded_user::<T>("payee", USER_SEED, 0u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), RewardDestination::Account(payee.clone()))
+    verify {
+        assert_eq!(Pallet::<T>::payee(&caller), RewardDestination::Account(payee));
+    }
 
-    era_transition_on_initialize {
-        // TOTAL SELECTED COLLATORS PER ERA
-        let x in 8..100;
-        // NOMINATIONS
-        let y in 0..(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get() * 100);
-        let max_nominators_per_collator =
-            <<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
-        let max_nominations = x * max_nominators_per_collator;
-        // y should depend on x but cannot directly, we overwrite y here if necessary to bound it
-        let total_nominations: u32 = if max_nominations < y { max_nominations } else { y };
-        // INITIALIZE RUNTIME STATE
-        // To set total selected to 40, must first increase era length to at least 40
-        // to avoid hitting EraLengthMustBeAtLeastTotalSelectedCollators
-        Pallet::<T>::set_blocks_per_era(RawOrigin::Root.into(), 100u32)?;
-        Pallet::<T>::set_total_selected(RawOrigin::Root.into(), 100u32)?;
-        // INITIALIZE COLLATOR STATE
-        let mut collators: Vec<T::AccountId> = Vec::new();
-        let mut collator_count = 1u32;
+    claim_rewards {
+        // x controls the number of candidates the claimed rewards were earned through
+        let x in 1..100;
+        let (caller, initial_balance) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let reward_pot = Pallet::<T>::compute_reward_pot_account_id();
+        let credit: BalanceOf<T> = 100u32.into();
+        T::Currency::make_free_balance_be(&reward_pot, min_candidate_stk::<T>() + credit * x.into());
         for i in 0..x {
-            let seed = USER_SEED - i;
-            let collator = create_funded_collator::<T>(
-                "collator",
+            let candidate: T::AccountId = account("candidate", i, USER_SEED);
+            <ClaimableRewards<T>>::insert(&caller, &candidate, credit);
+        }
+    }: _(RawOrigin::Signed(caller.clone()), x)
+    verify {
+        assert_eq!(<ClaimableRewards<T>>::iter_prefix(&caller).count(), 0);
+        assert_eq!(T::Currency::free_balance(&caller), initial_balance + credit * x.into());
+    }
+
+    retry_failed_reward_payment {
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let (staker, initial_balance) = create_funded_user::<T>("staker", USER_SEED, 0u32.into());
+        let reward_pot = Pallet::<T>::compute_reward_pot_account_id();
+        let amount: BalanceOf<T> = 100u32.into();
+        T::Currency::make_free_balance_be(&reward_pot, min_candidate_stk::<T>() + amount);
+        <FailedRewardPayments<T>>::insert(&staker, 1, FailedRewardPayment { amount, attempts: 1 });
+    }: _(RawOrigin::Signed(caller), 1, staker.clone())
+    verify {
+        assert!(Pallet::<T>::failed_reward_payments(&staker, 1).is_none());
+        assert_eq!(T::Currency::free_balance(&staker), initial_balance + amount);
+    }
+
+    create_pool {
+        let x in 1..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        // Worst Case Complexity is insertion into an almost full collator
+        let mut col_del_count = 0u32;
+        for i in 1..x {
+            let seed = USER_SEED + i;
+            let _ = create_funded_nominator::<T>(
+                "nominator",
                 seed,
-                min_candidate_stk::<T>() * 1_000_000u32.into(),
+                0u32.into(),
+                collator.clone(),
                 true,
-                collator_count
+                col_del_count,
             )?;
-            collators.push(collator);
-            collator_count += 1u32;
+            col_del_count += 1u32;
         }
-        // STORE starting balances for all collators
-        let collator_starting_balances: Vec<(
-            T::AccountId,
-            <<T as Config>::Currency as Currency<T::AccountId>>::Balance
-        )> = collators.iter().map(|x| (x.clone(), T::Currency::free_balance(&x))).collect();
-        // INITIALIZE NOMINATIONS
-        let mut col_del_count: BTreeMap<T::AccountId, u32> = BTreeMap::new();
-        collators.iter().for_each(|x| {
-            col_del_count.insert(x.clone(), 0u32);
-        });
-        let mut nominators: Vec<T::AccountId> = Vec::new();
-        let mut remaining_nominations = if total_nominations > max_nominators_per_collator {
-            for j in 1..(max_nominators_per_collator + 1) {
-                let seed = USER_SEED + j;
-                let nominator = create_funded_nominator::<T>(
-                    "nominator",
-                    seed,
-                    min_candidate_stk::<T>() * 1_000_000u32.into(),
-                    collators[0].clone(),
-                    true,
-                    nominators.len() as u32,
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let bond = min_nominator_stk::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), collator, bond, col_del_count)
+    verify {
+        assert_eq!(Pallet::<T>::pool(0).map(|pool| pool.operator), Some(caller));
+        assert!(Pallet::<T>::is_nominator(&Pallet::<T>::pool_account(0)));
+    }
+
+    pool_nominate {
+        let x in 1..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let y in 2..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let extra = bond * y.into();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, extra);
+        // Worst Case is the pool full of nominations before calling `pool_nominate`
+        let mut del_del_count = 0u32;
+        for i in 1..y {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            if i == 1 {
+                Pallet::<T>::create_pool(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    collator,
+                    bond,
+                    0u32,
                 )?;
-                nominators.push(nominator);
-            }
-            total_nominations - max_nominators_per_collator
-        } else {
-            for j in 1..(total_nominations + 1) {
-                let seed = USER_SEED + j;
-                let nominator = create_funded_nominator::<T>(
-                    "nominator",
-                    seed,
-                    min_candidate_stk::<T>() * 1_000_000u32.into(),
-                    collators[0].clone(),
-                    true,
-                    nominators.len() as u32,
+            } else {
+                Pallet::<T>::pool_nominate(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    0,
+                    collator,
+                    bond,
+                    0u32,
+                    del_del_count,
                 )?;
-                nominators.push(nominator);
             }
-            0u32
-        };
-        col_del_count.insert(collators[0].clone(), nominators.len() as u32);
-        // FILL remaining nominations
-        if remaining_nominations > 0 {
-            for (col, n_count) in col_del_count.iter_mut() {
-                if n_count < &mut (nominators.len() as u32) {
-                    // assumes nominators.len() <= MaxTopNominationsPerCandidate
-                    let mut open_spots = nominators.len() as u32 - *n_count;
-                    while open_spots > 0 && remaining_nominations > 0 {
-                        let caller = nominators[open_spots as usize - 1usize].clone();
-                        if let Ok(_) = Pallet::<T>::nominate(RawOrigin::Signed(
-                            caller.clone()).into(),
-                            col.clone(),
-                            <<T as Config>::MinNominatorStk as Get<BalanceOf<T>>>::get(),
-                            *n_count,
-                            collators.len() as u32, // overestimate
-                        ) {
-                            *n_count += 1;
-                            remaining_nominations -= 1;
-                        }
-                        open_spots -= 1;
-                    }
+            del_del_count += 1u32;
+        }
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            y,
+        )?;
+        let mut col_del_count = 0u32;
+        for i in 1..x {
+            let seed = USER_SEED + i;
+            let _ = create_funded_nominator::<T>(
+                "nominator",
+                seed,
+                0u32.into(),
+                collator.clone(),
+                true,
+                col_del_count,
+            )?;
+            col_del_count += 1u32;
+        }
+    }: _(RawOrigin::Signed(caller), 0, collator.clone(), bond, col_del_count, del_del_count)
+    verify {
+        let pool_state = Pallet::<T>::nominator_state(&Pallet::<T>::pool_account(0));
+        assert!(pool_state.map_or(false, |state| state.get_bond_amount(&collator).is_some()));
+    }
+
+    join_pool {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let (operator, _) = create_funded_user::<T>("operator", USER_SEED, 0u32.into());
+        let bond = min_nominator_stk::<T>();
+        Pallet::<T>::create_pool(RawOrigin::Signed(operator).into(), collator, bond, 0u32)?;
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let amount = <<T as Config>::MinPoolJoin as Get<BalanceOf<T>>>::get();
+    }: _(RawOrigin::Signed(caller.clone()), 0, amount)
+    verify {
+        assert_eq!(Pallet::<T>::pool_member(0, &caller).map(|member| member.points), Some(amount));
+    }
+
+    unbond_from_pool {
+        let max_nominations = <<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>(
+            "caller",
+            USER_SEED,
+            bond * (max_nominations + 1u32).into(),
+        );
+        // Worst Case is going through all the nominations of the pool
+        for i in 1..=max_nominations {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            if i == 1 {
+                Pallet::<T>::create_pool(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    collator,
+                    bond + bond,
+                    0u32,
+                )?;
+            } else {
+                Pallet::<T>::pool_nominate(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    0,
+                    collator,
+                    bond,
+                    0u32,
+                    i - 1,
+                )?;
+            }
+        }
+        let points = bond;
+    }: _(RawOrigin::Signed(caller.clone()), 0, points)
+    verify {
+        assert_eq!(Pallet::<T>::pool_unbondings(0, &caller).len(), 1);
+    }
+
+    withdraw_unbonded_from_pool {
+        let x in 1..<<T as Config>::MaxPoolUnbonding as Get<u32>>::get();
+        let max_nominations = <<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>(
+            "caller",
+            USER_SEED,
+            bond * (max_nominations + 1u32).into(),
+        );
+        for i in 1..=max_nominations {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            if i == 1 {
+                Pallet::<T>::create_pool(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    collator,
+                    bond + bond,
+                    0u32,
+                )?;
+            } else {
+                Pallet::<T>::pool_nominate(
+                    RawOrigin::Signed(caller.clone()).into(),
+                    0,
+                    collator,
+                    bond + bond,
+                    0u32,
+                    i - 1,
+                )?;
+            }
+        }
+        // Worst Case is executing a request of the pool for each unbonding
+        for _ in 0..x {
+            Pallet::<T>::unbond_from_pool(RawOrigin::Signed(caller.clone()).into(), 0, bond)?;
+        }
+        roll_to_and_author::<T>(2, caller.clone());
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+    verify {
+        assert!(Pallet::<T>::pool_unbondings(0, &caller).is_empty());
+    }
+
+    claim_pool_rewards {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let bond = min_nominator_stk::<T>();
+        Pallet::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), collator, bond, 0u32)?;
+        let pool_account = Pallet::<T>::pool_account(0);
+        let rewards: BalanceOf<T> = 100u32.into();
+        T::Currency::make_free_balance_be(
+            &pool_account,
+            T::Currency::free_balance(&pool_account) + rewards,
+        );
+        let initial_balance = T::Currency::free_balance(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+    verify {
+        assert_eq!(T::Currency::free_balance(&caller), initial_balance + rewards);
+    }
+
+    liquid_stake {
+        let x in 1..<<T as Config>::MaxCandidates as Get<u32>>::get();
+        // Worst Case is going through all the selected candidates for a new nomination
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 0..x {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i + 1,
+            )?;
+            collators.push(collator);
+        }
+        <SelectedCandidates<T>>::put(
+            BoundedVec::try_from(collators.clone()).expect("no more than MaxCandidates")
+        );
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let amount = min_nominator_stk::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        let liquid_state = Pallet::<T>::nominator_state(&Pallet::<T>::liquid_staking_account());
+        assert!(liquid_state.map_or(false, |state| state.total == amount));
+    }
+
+    liquid_unstake {
+        let max_nominations = <<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>(
+            "caller",
+            USER_SEED,
+            bond * (max_nominations + max_nominations + 1u32).into(),
+        );
+        // Worst Case is going through all the nominations of the liquid staking account
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 1..=max_nominations {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            collators.push(collator);
+        }
+        <SelectedCandidates<T>>::put(
+            BoundedVec::try_from(collators).expect("no more than MaxCandidates")
+        );
+        for _ in 0..max_nominations {
+            Pallet::<T>::liquid_stake(RawOrigin::Signed(caller.clone()).into(), bond + bond)?;
+        }
+    }: _(RawOrigin::Signed(caller.clone()), bond)
+    verify {
+        assert_eq!(Pallet::<T>::liquid_unbondings(&caller).len(), 1);
+    }
+
+    withdraw_liquid_unbonded {
+        let x in 1..<<T as Config>::MaxLiquidUnbonding as Get<u32>>::get();
+        let max_nominations = <<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>(
+            "caller",
+            USER_SEED,
+            bond * (max_nominations + max_nominations + 1u32).into(),
+        );
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 1..=max_nominations {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            collators.push(collator);
+        }
+        <SelectedCandidates<T>>::put(
+            BoundedVec::try_from(collators).expect("no more than MaxCandidates")
+        );
+        for _ in 0..max_nominations {
+            Pallet::<T>::liquid_stake(RawOrigin::Signed(caller.clone()).into(), bond + bond)?;
+        }
+        // Worst Case is executing a request of the liquid staking account for each unbonding
+        for _ in 0..x {
+            Pallet::<T>::liquid_unstake(RawOrigin::Signed(caller.clone()).into(), bond)?;
+        }
+        roll_to_and_author::<T>(2, caller.clone());
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Pallet::<T>::liquid_unbondings(&caller).is_empty());
+    }
+
+    switch_nomination {
+        let x in 1..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let y in 1..<<T as Config>::MaxNominationsPerNominator as Get<u32>>::get();
+        let bond = min_nominator_stk::<T>();
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, bond * y.into());
+        // Nominate y collator candidates, switching away from the first one
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        let mut del_del_count = 0u32;
+        for i in 1..=y {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i,
+            )?;
+            Pallet::<T>::nominate(
+                RawOrigin::Signed(caller.clone()).into(),
+                collator.clone(),
+                bond,
+                0u32,
+                del_del_count,
+            )?;
+            del_del_count += 1u32;
+            collators.push(collator);
+        }
+        let from = collators[0].clone();
+        // Worst Case is switching into a new nomination of an almost full collator
+        let to = create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, y + 1u32)?;
+        let mut col_del_count = 0u32;
+        for i in 1..x {
+            let _ = create_funded_nominator::<T>(
+                "nominator",
+                USER_SEED + i,
+                0u32.into(),
+                to.clone(),
+                true,
+                col_del_count,
+            )?;
+            col_del_count += 1u32;
+        }
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        from.clone(),
+        to.clone(),
+        bond,
+        col_del_count,
+        del_del_count
+    )
+    verify {
+        let state = Pallet::<T>::nominator_state(&caller).expect("caller is a nominator");
+        assert_eq!(state.get_bond_amount(&from), None);
+        assert_eq!(state.get_bond_amount(&to), Some(bond));
+    }
+
+    set_controller {
+        let caller = create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, 1u32)?;
+        // Worst Case is replacing the previous controller
+        let old: T::AccountId = account("controller", 0, USER_SEED);
+        Pallet::<T>::set_controller(RawOrigin::Signed(caller.clone()).into(), old)?;
+        let controller: T::AccountId = account("controller", 1, USER_SEED);
+    }: _(RawOrigin::Signed(caller.clone()), controller.clone())
+    verify {
+        assert_eq!(Pallet::<T>::controller(&caller), Some(controller));
+    }
+
+    clear_controller {
+        let caller = create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, 1u32)?;
+        let controller: T::AccountId = account("controller", 0, USER_SEED);
+        Pallet::<T>::set_controller(RawOrigin::Signed(caller.clone()).into(), controller.clone())?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(Pallet::<T>::stash(&controller), None);
+    }
+
+    set_operator_profile {
+        let max_length = <<T as Config>::MaxProfileFieldLength as Get<u32>>::get();
+        let deposit = <<T as Config>::ProfileDepositBase as Get<BalanceOf<T>>>::get() +
+            <<T as Config>::ProfileDepositPerByte as Get<BalanceOf<T>>>::get() *
+                (4 * max_length).into();
+        let caller = create_funded_collator::<T>("collator", USER_SEED, deposit, true, 1u32)?;
+        // Worst Case is replacing a profile with fields of the maximum length
+        Pallet::<T>::set_operator_profile(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"collator".to_vec(),
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )?;
+        let field = vec![0u8; max_length as usize];
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        field.clone(),
+        field.clone(),
+        field.clone(),
+        field,
+        Some(true)
+    )
+    verify {
+        assert!(Pallet::<T>::operator_profile(&caller).is_some());
+    }
+
+    clear_operator_profile {
+        let max_length = <<T as Config>::MaxProfileFieldLength as Get<u32>>::get();
+        let deposit = <<T as Config>::ProfileDepositBase as Get<BalanceOf<T>>>::get() +
+            <<T as Config>::ProfileDepositPerByte as Get<BalanceOf<T>>>::get() *
+                (4 * max_length).into();
+        let caller = create_funded_collator::<T>("collator", USER_SEED, deposit, true, 1u32)?;
+        Pallet::<T>::set_operator_profile(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"collator".to_vec(),
+            vec![],
+            vec![],
+            vec![],
+            None,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Pallet::<T>::operator_profile(&caller).is_none());
+    }
+
+    set_nomination_policy {
+        let max_allowlist = <<T as Config>::MaxNominationAllowlist as Get<u32>>::get();
+        let caller = create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, 1u32)?;
+        // Worst Case is sorting a full allowlist
+        let allowlist: Vec<T::AccountId> =
+            (0..max_allowlist).map(|i| account("nominator", i, USER_SEED)).collect();
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        Some(min_nominator_stk::<T>()),
+        Some(max_allowlist),
+        Some(allowlist)
+    )
+    verify {
+        assert!(Pallet::<T>::nomination_policy(&caller).is_some());
+    }
+
+    // ON_INITIALIZE
+
+    era_transition_on_initialize {
+        // TOTAL SELECTED COLLATORS PER ERA
+        let x in 8..100;
+        // NOMINATIONS
+        let y in 0..(<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get() * 100);
+        let max_nominators_per_collator =
+            <<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let max_nominations = x * max_nominators_per_collator;
+        // y should depend on x but cannot directly, we overwrite y here if necessary to bound it
+        let total_nominations: u32 = if max_nominations < y { max_nominations } else { y };
+        // INITIALIZE RUNTIME STATE
+        // To set total selected to 40, must first increase era length to at least 40
+        // to avoid hitting EraLengthMustBeAtLeastTotalSelectedCollators
+        Pallet::<T>::set_blocks_per_era(RawOrigin::Root.into(), 100u32)?;
+        Pallet::<T>::set_total_selected(RawOrigin::Root.into(), 100u32)?;
+        // INITIALIZE COLLATOR STATE
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        let mut collator_count = 1u32;
+        for i in 0..x {
+            let seed = USER_SEED - i;
+            let collator = create_funded_collator::<T>(
+                "collator",
+                seed,
+                min_candidate_stk::<T>() * 1_000_000u32.into(),
+                true,
+                collator_count
+            )?;
+            collators.push(collator);
+            collator_count += 1u32;
+        }
+        // STORE starting balances for all collators
+        let collator_starting_balances: Vec<(
+            T::AccountId,
+            <<T as Config>::Currency as Currency<T::AccountId>>::Balance
+        )> = collators.iter().map(|x| (x.clone(), T::Currency::free_balance(&x))).collect();
+        // INITIALIZE NOMINATIONS
+        let mut col_del_count: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+        collators.iter().for_each(|x| {
+            col_del_count.insert(x.clone(), 0u32);
+        });
+        let mut nominators: Vec<T::AccountId> = Vec::new();
+        let mut remaining_nominations = if total_nominations > max_nominators_per_collator {
+            for j in 1..(max_nominators_per_collator + 1) {
+                let seed = USER_SEED + j;
+                let nominator = create_funded_nominator::<T>(
+                    "nominator",
+                    seed,
+                    min_candidate_stk::<T>() * 1_000_000u32.into(),
+                    collators[0].clone(),
+                    true,
+                    nominators.len() as u32,
+                )?;
+                nominators.push(nominator);
+            }
+            total_nominations - max_nominators_per_collator
+        } else {
+            for j in 1..(total_nominations + 1) {
+                let seed = USER_SEED + j;
+                let nominator = create_funded_nominator::<T>(
+                    "nominator",
+                    seed,
+                    min_candidate_stk::<T>() * 1_000_000u32.into(),
+                    collators[0].clone(),
+                    true,
+                    nominators.len() as u32,
+                )?;
+                nominators.push(nominator);
+            }
+            0u32
+        };
+        col_del_count.insert(collators[0].clone(), nominators.len() as u32);
+        // FILL remaining nominations
+        if remaining_nominations > 0 {
+            for (col, n_count) in col_del_count.iter_mut() {
+                if n_count < &mut (nominators.len() as u32) {
+                    // assumes nominators.len() <= MaxTopNominationsPerCandidate
+                    let mut open_spots = nominators.len() as u32 - *n_count;
+                    while open_spots > 0 && remaining_nominations > 0 {
+                        let caller = nominators[open_spots as usize - 1usize].clone();
+                        if let Ok(_) = Pallet::<T>::nominate(RawOrigin::Signed(
+                            caller.clone()).into(),
+                            col.clone(),
+                            <<T as Config>::MinNominatorStk as Get<BalanceOf<T>>>::get(),
+                            *n_count,
+                            collators.len() as u32, // overestimate
+                        ) {
+                            *n_count += 1;
+                            remaining_nominations -= 1;
+                        }
+                        open_spots -= 1;
+                    }
                 }
                 if remaining_nominations == 0 {
                     break;
@@ -913,8 +1660,7 @@ benchmarks! {
 
         // must come after 'let foo in 0..` statements for macro
         use crate::{
-            DelayedPayout, DelayedPayouts, AtStake, CollatorSnapshot, Bond, Points,
-            AwardedPts,
+            DelayedPayout, DelayedPayouts, AtStake, CollatorSnapshot, Bond, AwardedPts,
         };
 
         let before_running_era_index = Pallet::<T>::era().current;
@@ -956,6 +1702,7 @@ benchmarks! {
             // NOTE: era_issuance is not correct here, but it doesn't seem to cause problems
             era_issuance: 1000u32.into(),
             total_staking_reward: total_staked,
+            total_points: 100,
         });
 
         let mut nominations: Vec<Bond<T::AccountId, BalanceOf<T>>> = Vec::new();
@@ -968,18 +1715,18 @@ benchmarks! {
 
         <AtStake<T>>::insert(era_for_payout, &sole_collator, CollatorSnapshot {
             bond: 1_000u32.into(),
-            nominations,
+            nominations: nominations.try_into().expect("no more than the max top nominations"),
             total: 1_000_000u32.into(),
+            commission: Perbill::zero(),
         });
 
-        <Points<T>>::insert(era_for_payout, 100);
         <AwardedPts<T>>::insert(era_for_payout, &sole_collator, 20);
 
     }: {
         let era_for_payout = 5;
         // TODO: this is an extra read right here (we should whitelist it?)
         let payout_info = Pallet::<T>::delayed_payouts(era_for_payout).expect("payout expected");
-        let result = Pallet::<T>::pay_one_collator_reward(era_for_payout, payout_info);
+        let result = Pallet::<T>::pay_one_collator_reward(era_for_payout, &payout_info);
         assert!(result.0.is_some()); // TODO: how to keep this in scope so it can be done in verify block?
     }
     verify {
@@ -997,6 +1744,135 @@ benchmarks! {
         }
     }
 
+    report_offence {
+        let collator = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+        let era = Pallet::<T>::era().current;
+        <AtStake<T>>::insert(era, &collator, CollatorSnapshot {
+            bond: min_candidate_stk::<T>(),
+            nominations: BoundedVec::default(),
+            total: min_candidate_stk::<T>(),
+            commission: Perbill::zero(),
+        });
+        let offenders = vec![(collator.clone(), era, Perbill::from_percent(10))];
+    }: { <Pallet<T> as ReportOffence<T::AccountId>>::report_offence(&offenders); }
+    verify {
+        let state = Pallet::<T>::candidate_info(&collator).expect("candidate exists");
+        assert!(!state.is_active());
+    }
+
+    check_collator_liveness {
+        // x is the number of collators selected for the era
+        let x in 1..<<T as Config>::MaxCandidates as Get<u32>>::get();
+        let mut collators: Vec<T::AccountId> = Vec::new();
+        for i in 0..x {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                i + 1,
+            )?;
+            // worst case: every collator authored no block and is idled
+            <UnderperformingEras<T>>::insert(
+                &collator,
+                <<T as Config>::MaxUnderperformingEras as Get<u32>>::get().saturating_sub(1),
+            );
+            collators.push(collator);
+        }
+        <SelectedCandidates<T>>::put(
+            BoundedVec::try_from(collators.clone()).expect("no more than MaxCandidates")
+        );
+        let era = Pallet::<T>::era().current;
+    }: { Pallet::<T>::check_collator_liveness(era, 1_000u32); }
+    verify {
+        for collator in collators {
+            let state = Pallet::<T>::candidate_info(&collator).expect("candidate exists");
+            assert!(!state.is_active());
+        }
+    }
+
+    repair_staking_state {
+        // x is the number of candidates repaired, y the number of other candidates
+        let x in 1..(<<T as Config>::MaxCandidates as Get<u32>>::get() / 2);
+        let y in 0..(<<T as Config>::MaxCandidates as Get<u32>>::get() / 2);
+        let mut candidate_count = 1u32;
+        let mut repaired: Vec<T::AccountId> = Vec::new();
+        for i in 0..x {
+            let collator = create_funded_collator::<T>(
+                "collator",
+                USER_SEED - i,
+                0u32.into(),
+                true,
+                candidate_count,
+            )?;
+            candidate_count += 1u32;
+            // worst case: the counted stake of every repaired candidate is wrong
+            let mut state = Pallet::<T>::candidate_info(&collator).expect("candidate exists");
+            state.total_counted += 1u32.into();
+            <CandidateInfo<T>>::insert(&collator, state);
+            repaired.push(collator);
+        }
+        for i in 0..y {
+            create_funded_collator::<T>(
+                "candidate",
+                USER_SEED + i,
+                0u32.into(),
+                true,
+                candidate_count,
+            )?;
+            candidate_count += 1u32;
+        }
+        let total = Pallet::<T>::total();
+        <Total<T>>::put(total + 1u32.into());
+    }: _(RawOrigin::Root, repaired.clone(), candidate_count)
+    verify {
+        assert_eq!(Pallet::<T>::total(), total);
+        for collator in repaired {
+            let state = Pallet::<T>::candidate_info(&collator).expect("candidate exists");
+            assert_eq!(state.total_counted, state.bond);
+        }
+    }
+
+    apply_slash {
+        // y controls number of nominations, its maximum per collator is the max top nominations
+        let y in 0..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+        let collator = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+        let mut others = Vec::new();
+        for i in 0..y {
+            let nominator = create_funded_nominator::<T>(
+                "nominator",
+                USER_SEED + i,
+                0u32.into(),
+                collator.clone(),
+                true,
+                i,
+            )?;
+            others.push((nominator, min_nominator_stk::<T>() / 2u32.into()));
+        }
+        let slash = UnappliedSlash {
+            collator: collator.clone(),
+            era: 1,
+            own: min_candidate_stk::<T>() / 2u32.into(),
+            others: others.try_into().expect("no more than the max top nominations"),
+        };
+    }: { Pallet::<T>::apply_slash(slash); }
+    verify {
+        let state = Pallet::<T>::candidate_info(&collator).expect("candidate exists");
+        assert!(state.bond < min_candidate_stk::<T>());
+    }
+
     base_on_initialize {
         let collator: T::AccountId = create_funded_collator::<T>(
             "collator",
@@ -1022,12 +1898,22 @@ benchmarks! {
 
 #[cfg(test)]
 mod tests {
-    use crate::{benchmarks::*, mock::Test};
-    use frame_support::assert_ok;
+    use crate::{
+        benchmarks::*,
+        mock::{MaxUnderperformingEras, Test, LIQUID_ASSET},
+    };
+    use frame_support::{assert_ok, traits::GenesisBuild};
     use sp_io::TestExternalities;
 
     pub fn new_test_ext() -> TestExternalities {
-        let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        pallet_assets::GenesisConfig::<Test> {
+            assets: vec![(LIQUID_ASSET, 0, true, 1)],
+            metadata: vec![],
+            accounts: vec![],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
         TestExternalities::new(t)
     }
 
@@ -1038,6 +1924,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_inflation() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_inflation());
+        });
+    }
+
     #[test]
     fn bench_set_parachain_bond_account() {
         new_test_ext().execute_with(|| {
@@ -1066,6 +1959,41 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_liquid_strategy() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_liquid_strategy());
+        });
+    }
+
+    #[test]
+    fn bench_set_payout_mode() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_payout_mode());
+        });
+    }
+
+    #[test]
+    fn bench_set_eligible_ratio() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_eligible_ratio());
+        });
+    }
+
+    #[test]
+    fn bench_set_staking_parameter() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_staking_parameter());
+        });
+    }
+
+    #[test]
+    fn bench_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_cancel_deferred_slash());
+        });
+    }
+
     #[test]
     fn bench_join_candidates() {
         new_test_ext().execute_with(|| {
@@ -1136,6 +2064,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_commission() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_commission());
+        });
+    }
+
     #[test]
     fn bench_nominate() {
         new_test_ext().execute_with(|| {
@@ -1143,6 +2078,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_nominate_with_auto_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_nominate_with_auto_compound());
+        });
+    }
+
+    #[test]
+    fn bench_nominate_many() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_nominate_many());
+        });
+    }
+
+    #[test]
+    fn bench_set_auto_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_auto_compound());
+        });
+    }
+
     #[test]
     fn bench_schedule_leave_nominators() {
         new_test_ext().execute_with(|| {
@@ -1213,6 +2169,132 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_payee() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_payee());
+        });
+    }
+
+    #[test]
+    fn bench_claim_rewards() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_claim_rewards());
+        });
+    }
+
+    #[test]
+    fn bench_retry_failed_reward_payment() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_retry_failed_reward_payment());
+        });
+    }
+
+    #[test]
+    fn bench_create_pool() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_create_pool());
+        });
+    }
+
+    #[test]
+    fn bench_pool_nominate() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_pool_nominate());
+        });
+    }
+
+    #[test]
+    fn bench_join_pool() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_join_pool());
+        });
+    }
+
+    #[test]
+    fn bench_unbond_from_pool() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_unbond_from_pool());
+        });
+    }
+
+    #[test]
+    fn bench_withdraw_unbonded_from_pool() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_withdraw_unbonded_from_pool());
+        });
+    }
+
+    #[test]
+    fn bench_claim_pool_rewards() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_claim_pool_rewards());
+        });
+    }
+
+    #[test]
+    fn bench_liquid_stake() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_liquid_stake());
+        });
+    }
+
+    #[test]
+    fn bench_liquid_unstake() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_liquid_unstake());
+        });
+    }
+
+    #[test]
+    fn bench_withdraw_liquid_unbonded() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_withdraw_liquid_unbonded());
+        });
+    }
+
+    #[test]
+    fn bench_switch_nomination() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_switch_nomination());
+        });
+    }
+
+    #[test]
+    fn bench_set_controller() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_controller());
+        });
+    }
+
+    #[test]
+    fn bench_clear_controller() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_clear_controller());
+        });
+    }
+
+    #[test]
+    fn bench_set_operator_profile() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_operator_profile());
+        });
+    }
+
+    #[test]
+    fn bench_clear_operator_profile() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_clear_operator_profile());
+        });
+    }
+
+    #[test]
+    fn bench_set_nomination_policy() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_nomination_policy());
+        });
+    }
+
     #[test]
     fn bench_era_transition_on_initialize() {
         new_test_ext().execute_with(|| {
@@ -1220,6 +2302,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_report_offence() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_report_offence());
+        });
+    }
+
+    #[test]
+    fn bench_check_collator_liveness() {
+        new_test_ext().execute_with(|| {
+            MaxUnderperformingEras::set(1);
+            assert_ok!(Pallet::<Test>::test_benchmark_check_collator_liveness());
+        });
+    }
+
+    #[test]
+    fn bench_repair_staking_state() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_repair_staking_state());
+        });
+    }
+
+    #[test]
+    fn bench_apply_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_apply_slash());
+        });
+    }
+
     #[test]
     fn bench_base_on_initialize() {
         new_test_ext().execute_with(|| {
@@ -0,0 +1,271 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The pool of active candidates, a bags list sorted by backing stake
+//!
+//! Candidates are kept in bags of backing stakes within a factor of about 1.044 of each other,
+//! each bag being a doubly linked list. A candidate changing bag moves in constant time, and the
+//! most backed candidates are read from the highest bags down, sorting only the bags read.
+
+use crate::{
+    pallet::{
+        BalanceOf, CandidatePoolBags, CandidatePoolCount, CandidatePoolNodes, Config, Error,
+        NonEmptyCandidatePoolBags, Pallet,
+    },
+    types::Bond,
+};
+use frame_support::{traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
+use sp_std::prelude::*;
+
+/// Index of a bag, increasing with the backing stakes of its candidates
+pub type BagIndex = u16;
+
+/// Bits of the backing stake after its leading bit which select the bag, i.e. 16 bags per
+/// doubling of the backing stake
+const BAG_PRECISION_BITS: u32 = 4;
+
+/// Returns the index of the bag of candidates backed by `amount`.
+pub fn bag_index<Balance: UniqueSaturatedInto<u128>>(amount: Balance) -> BagIndex {
+    let amount: u128 = amount.unique_saturated_into();
+    if amount == 0 {
+        return 0
+    }
+    let exponent = 127 - amount.leading_zeros();
+    let fraction = if exponent >= BAG_PRECISION_BITS {
+        amount >> (exponent - BAG_PRECISION_BITS)
+    } else {
+        amount << (BAG_PRECISION_BITS - exponent)
+    } & ((1 << BAG_PRECISION_BITS) - 1);
+    (1 + (exponent << BAG_PRECISION_BITS) + fraction as u32) as BagIndex
+}
+
+/// A bag of candidates, linked from its head to its tail
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Bag<AccountId> {
+    pub head: Option<AccountId>,
+    pub tail: Option<AccountId>,
+}
+
+impl<AccountId> Default for Bag<AccountId> {
+    fn default() -> Self {
+        Bag { head: None, tail: None }
+    }
+}
+
+/// A candidate of the pool, with its backing stake and neighbours in its bag
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolNode<AccountId, Balance> {
+    pub amount: Balance,
+    pub bag: BagIndex,
+    pub prev: Option<AccountId>,
+    pub next: Option<AccountId>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns true if `candidate` is in the candidate pool.
+    pub fn is_in_candidate_pool(candidate: &T::AccountId) -> bool {
+        <CandidatePoolNodes<T>>::contains_key(candidate)
+    }
+
+    /// Inserts `candidate` backed by `amount` at the tail of its bag.
+    /// Returns false if it already is in the pool.
+    pub(crate) fn candidate_pool_insert(
+        candidate: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<bool, DispatchError> {
+        if Self::is_in_candidate_pool(candidate) {
+            return Ok(false)
+        }
+        let count = <CandidatePoolCount<T>>::get();
+        if count >= T::MaxCandidates::get() {
+            return Err(<Error<T>>::CandidateLimitReached.into())
+        }
+        let index = bag_index(amount);
+        let mut bag = match <CandidatePoolBags<T>>::get(index) {
+            Some(bag) => bag,
+            None => {
+                <NonEmptyCandidatePoolBags<T>>::try_mutate(|bags| {
+                    let position = bags.binary_search(&index).unwrap_or_else(|p| p);
+                    bags.try_insert(position, index)
+                })
+                .map_err(|_| <Error<T>>::CandidateLimitReached)?;
+                Bag::default()
+            },
+        };
+        if let Some(tail) = &bag.tail {
+            <CandidatePoolNodes<T>>::mutate(tail, |node| {
+                if let Some(node) = node {
+                    node.next = Some(candidate.clone());
+                }
+            });
+        } else {
+            bag.head = Some(candidate.clone());
+        }
+        let node = PoolNode { amount, bag: index, prev: bag.tail.take(), next: None };
+        bag.tail = Some(candidate.clone());
+        <CandidatePoolBags<T>>::insert(index, bag);
+        <CandidatePoolNodes<T>>::insert(candidate, node);
+        <CandidatePoolCount<T>>::put(count.saturating_add(1));
+        Ok(true)
+    }
+
+    /// Removes `candidate` from the pool.
+    /// Returns true if it was in the pool.
+    pub(crate) fn candidate_pool_remove(candidate: &T::AccountId) -> bool {
+        let node = match <CandidatePoolNodes<T>>::take(candidate) {
+            Some(node) => node,
+            None => return false,
+        };
+        let mut bag = <CandidatePoolBags<T>>::get(node.bag).unwrap_or_default();
+        match &node.prev {
+            Some(prev) => <CandidatePoolNodes<T>>::mutate(prev, |prev| {
+                if let Some(prev) = prev {
+                    prev.next = node.next.clone();
+                }
+            }),
+            None => bag.head = node.next.clone(),
+        }
+        match &node.next {
+            Some(next) => <CandidatePoolNodes<T>>::mutate(next, |next| {
+                if let Some(next) = next {
+                    next.prev = node.prev.clone();
+                }
+            }),
+            None => bag.tail = node.prev.clone(),
+        }
+        if bag.head.is_none() {
+            <CandidatePoolBags<T>>::remove(node.bag);
+            <NonEmptyCandidatePoolBags<T>>::mutate(|bags| {
+                if let Ok(position) = bags.binary_search(&node.bag) {
+                    bags.remove(position);
+                }
+            });
+        } else {
+            <CandidatePoolBags<T>>::insert(node.bag, bag);
+        }
+        <CandidatePoolCount<T>>::mutate(|count| *count = count.saturating_sub(1));
+        true
+    }
+
+    /// Sets the backing stake of `candidate` in the pool to `amount`, moving it to the tail of
+    /// its new bag if it changes bag.
+    pub(crate) fn candidate_pool_update(
+        candidate: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<(), DispatchError> {
+        match <CandidatePoolNodes<T>>::get(candidate) {
+            Some(mut node) if node.bag == bag_index(amount) => {
+                node.amount = amount;
+                <CandidatePoolNodes<T>>::insert(candidate, node);
+            },
+            _ => {
+                Self::candidate_pool_remove(candidate);
+                Self::candidate_pool_insert(candidate, amount)?;
+            },
+        }
+        Ok(())
+    }
+
+    /// Returns the candidates of the bag at `index`, from its head to its tail.
+    fn bag_candidates(index: BagIndex) -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+        let mut candidates = Vec::new();
+        let mut next = <CandidatePoolBags<T>>::get(index).and_then(|bag| bag.head);
+        while let Some(owner) = next {
+            match <CandidatePoolNodes<T>>::get(&owner) {
+                Some(node) => {
+                    next = node.next;
+                    candidates.push(Bond { owner, amount: node.amount });
+                },
+                None => {
+                    log::error!(
+                        "LOGIC ERROR: candidate {:?} of bag {:?} has no node",
+                        owner,
+                        index
+                    );
+                    break
+                },
+            }
+        }
+        candidates
+    }
+
+    /// Returns the `n` most backed candidates of the pool, from the most to the least backed,
+    /// reading only the bags they are in. Candidates backed equally are ordered by account,
+    /// from the greatest to the least.
+    pub fn top_candidate_pool(n: usize) -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+        let mut top = Vec::new();
+        for index in <NonEmptyCandidatePoolBags<T>>::get().into_iter().rev() {
+            if top.len() >= n {
+                break
+            }
+            let mut candidates = Self::bag_candidates(index);
+            candidates.sort_by(|a, b| b.amount.cmp(&a.amount).then_with(|| b.owner.cmp(&a.owner)));
+            candidates.truncate(n - top.len());
+            top.append(&mut candidates);
+        }
+        top
+    }
+
+    /// Returns all the candidates of the pool, from the most to the least backed.
+    pub fn candidate_pool() -> Vec<Bond<T::AccountId, BalanceOf<T>>> {
+        Self::top_candidate_pool(usize::MAX)
+    }
+
+    /// Checks that the bags of the pool link all its candidates, each in the bag of its backing
+    /// stake, and that `CandidatePoolCount` counts them.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub(crate) fn check_candidate_pool() -> Result<(), &'static str> {
+        let non_empty_bags = <NonEmptyCandidatePoolBags<T>>::get();
+        frame_support::ensure!(
+            <CandidatePoolBags<T>>::iter_keys().count() == non_empty_bags.len(),
+            "NonEmptyCandidatePoolBags does not list the bags of the candidate pool"
+        );
+        let mut linked = 0u32;
+        for index in non_empty_bags {
+            let bag = <CandidatePoolBags<T>>::get(index).ok_or("non empty bag is missing")?;
+            let mut prev = None;
+            let mut next = bag.head.clone();
+            while let Some(owner) = next {
+                let node = <CandidatePoolNodes<T>>::get(&owner).ok_or("linked node is missing")?;
+                frame_support::ensure!(node.prev == prev, "node is not linked back to its prev");
+                frame_support::ensure!(
+                    node.bag == index && node.bag == bag_index(node.amount),
+                    "candidate is not in the bag of its backing stake"
+                );
+                linked = linked.saturating_add(1);
+                frame_support::ensure!(
+                    linked <= T::MaxCandidates::get(),
+                    "candidate pool bags link more than MaxCandidates"
+                );
+                next = node.next;
+                prev = Some(owner);
+            }
+            frame_support::ensure!(bag.tail == prev, "bag tail is not its last node");
+        }
+        frame_support::ensure!(
+            <CandidatePoolNodes<T>>::iter_keys().count() as u32 == linked,
+            "candidate pool node is not linked in its bag"
+        );
+        frame_support::ensure!(
+            <CandidatePoolCount<T>>::get() == linked,
+            "CandidatePoolCount does not count the candidate pool"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Controller accounts of candidates and nominators
+//!
+//! The account holding the bonded funds, its stash, can set a controller account to sign the
+//! operational calls in its place, so that the stash key can stay off the machines running
+//! them. A controller acts for its stash in every call going through `ensure_stash`; the calls
+//! bonding, moving or paying out funds still need to be signed by the stash. A paired controller
+//! cannot stake, and the pair is removed once the stash stops staking.
+
+use crate::pallet::{Config, Controllers, Error, Event, Pallet, Stashes};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+    /// The account the signer of `origin` acts for: its stash if it is a controller, itself
+    /// otherwise.
+    pub(crate) fn ensure_stash(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+        let signer = ensure_signed(origin)?;
+        Ok(<Stashes<T>>::get(&signer).unwrap_or(signer))
+    }
+
+    /// Sets the controller of `stash`, replacing its previous controller if any.
+    pub(crate) fn controller_set(
+        stash: T::AccountId,
+        controller: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(
+            Self::is_candidate(&stash) || Self::is_nominator(&stash),
+            Error::<T>::CannotSetControllerIfNotStaker
+        );
+        let old = <Controllers<T>>::get(&stash);
+        ensure!(old.as_ref() != Some(&controller), Error::<T>::NoWritingSameValue);
+        // a controller only acts for its stash, so it can neither stake nor control another one
+        ensure!(
+            controller != stash &&
+                !Self::is_candidate(&controller) &&
+                !Self::is_nominator(&controller),
+            Error::<T>::InvalidController
+        );
+        ensure!(!Self::is_controller(&controller), Error::<T>::ControllerAlreadyPaired);
+        if let Some(old) = &old {
+            <Stashes<T>>::remove(old);
+        }
+        <Controllers<T>>::insert(&stash, &controller);
+        <Stashes<T>>::insert(&controller, &stash);
+        Self::deposit_event(Event::ControllerSet { stash, old, new: controller });
+        Ok(().into())
+    }
+
+    /// Removes the controller of `stash`.
+    pub(crate) fn controller_clear(stash: T::AccountId) -> DispatchResultWithPostInfo {
+        ensure!(Self::controller_remove(&stash), Error::<T>::NoController);
+        Ok(().into())
+    }
+
+    /// Removes the controller of `stash` if it has one. Returns whether it had one.
+    pub(crate) fn controller_remove(stash: &T::AccountId) -> bool {
+        let controller = match <Controllers<T>>::take(stash) {
+            Some(controller) => controller,
+            None => return false,
+        };
+        <Stashes<T>>::remove(&controller);
+        Self::deposit_event(Event::ControllerCleared { stash: stash.clone(), controller });
+        true
+    }
+
+    /// Whether `account` is paired as the controller of a stash.
+    pub(crate) fn is_controller(account: &T::AccountId) -> bool {
+        <Stashes<T>>::contains_key(account)
+    }
+}
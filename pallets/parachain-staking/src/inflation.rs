@@ -0,0 +1,192 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helper methods for computing issuance based on inflation
+
+use crate::pallet::{BalanceOf, Config, EraIndex, Event, InflationConfig, Pallet, Staked};
+use frame_support::traits::{Currency, EstimateNextSessionRotation, Get};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+    traits::{SaturatedConversion, Saturating, Zero},
+    PerThing, Perbill, RuntimeDebug,
+};
+use substrate_fixed::{
+    transcendental::pow as floatpow,
+    types::{I32F32, I64F64},
+};
+
+const SECONDS_PER_YEAR: u32 = 31557600;
+const SECONDS_PER_BLOCK: u32 = 12;
+pub const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
+
+/// The number of blocks of an era: `Era.length`, or `T::SessionsPerEra` sessions of the average
+/// length estimated by `T::NextSessionRotation` for eras made of sessions.
+fn blocks_per_era<T: Config>() -> u32 {
+    let sessions_per_era = T::SessionsPerEra::get();
+    if sessions_per_era.is_zero() {
+        return <Pallet<T>>::era().length
+    }
+    let session_length: u32 =
+        T::NextSessionRotation::average_session_length().saturated_into::<u32>();
+    session_length.saturating_mul(sessions_per_era)
+}
+
+fn eras_per_year<T: Config>() -> u32 {
+    BLOCKS_PER_YEAR / blocks_per_era::<T>().max(1)
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct Range<T> {
+    pub min: T,
+    pub ideal: T,
+    pub max: T,
+}
+
+impl<T: Ord> Range<T> {
+    pub fn is_valid(&self) -> bool {
+        self.max >= self.ideal && self.ideal >= self.min
+    }
+}
+
+impl<T: Ord + Copy> From<T> for Range<T> {
+    fn from(other: T) -> Range<T> {
+        Range { min: other, ideal: other, max: other }
+    }
+}
+
+/// Convert an annual inflation to an era inflation
+/// era = (1+annual)^(1/eras_per_year) - 1
+pub fn perbill_annual_to_perbill_era(annual: Range<Perbill>, eras_per_year: u32) -> Range<Perbill> {
+    let exponent = I32F32::from_num(1) / I32F32::from_num(eras_per_year.max(1));
+    let annual_to_era = |annual: Perbill| -> Perbill {
+        let x = I32F32::from_num(annual.deconstruct()) / I32F32::from_num(Perbill::ACCURACY);
+        let y: I64F64 = floatpow(I32F32::from_num(1) + x, exponent)
+            .expect("Cannot overflow since eras_per_year is u32 so worst value is 0; QED");
+        Perbill::from_parts(
+            ((y - I64F64::from_num(1)) * I64F64::from_num(Perbill::ACCURACY))
+                .ceil()
+                .to_num::<u32>(),
+        )
+    };
+    Range {
+        min: annual_to_era(annual.min),
+        ideal: annual_to_era(annual.ideal),
+        max: annual_to_era(annual.max),
+    }
+}
+
+/// Convert annual inflation rate range to era inflation range
+pub fn annual_to_era<T: Config>(annual: Range<Perbill>) -> Range<Perbill> {
+    let periods = eras_per_year::<T>();
+    perbill_annual_to_perbill_era(annual, periods)
+}
+
+/// Compute era issuance range from era inflation range and current total issuance
+pub fn era_issuance_range<T: Config>(era: Range<Perbill>) -> Range<BalanceOf<T>> {
+    let circulating = T::Currency::total_issuance();
+    Range { min: era.min * circulating, ideal: era.ideal * circulating, max: era.max * circulating }
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InflationInfo {
+    /// Staking expectations, as shares of the total issuance at stake
+    pub expect: Range<Perbill>,
+    /// Annual inflation range
+    pub annual: Range<Perbill>,
+    /// Era inflation range
+    pub era: Range<Perbill>,
+}
+
+impl InflationInfo {
+    pub fn new<T: Config>(annual: Range<Perbill>, expect: Range<Perbill>) -> InflationInfo {
+        InflationInfo { expect, annual, era: annual_to_era::<T>(annual) }
+    }
+    /// Set era inflation range according to input annual inflation range
+    pub fn set_era_from_annual<T: Config>(&mut self, new: Range<Perbill>) {
+        self.era = annual_to_era::<T>(new);
+    }
+    /// Reset era inflation rate based on changes to era length
+    pub fn reset_era<T: Config>(&mut self) {
+        self.era = annual_to_era::<T>(self.annual);
+    }
+    /// Set staking expectations
+    pub fn set_expectations(&mut self, expect: Range<Perbill>) {
+        self.expect = expect;
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the issuance of an era in which `staked` was at stake: the era inflation of the
+    /// total issuance, scaled from `min` to `ideal` to `max` as the staked ratio, the share of the
+    /// total issuance at stake, goes from the minimum to the ideal to the maximum expectation.
+    pub fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+        let config = <InflationConfig<T>>::get();
+        let issuance = era_issuance_range::<T>(config.era);
+        let staked_ratio = Perbill::from_rational(staked, T::Currency::total_issuance());
+        let expect = config.expect;
+        if staked_ratio <= expect.min {
+            issuance.min
+        } else if staked_ratio >= expect.max {
+            issuance.max
+        } else if staked_ratio <= expect.ideal {
+            Self::interpolate(
+                issuance.min,
+                issuance.ideal,
+                staked_ratio.saturating_sub(expect.min),
+                expect.ideal.saturating_sub(expect.min),
+            )
+        } else {
+            Self::interpolate(
+                issuance.ideal,
+                issuance.max,
+                staked_ratio.saturating_sub(expect.ideal),
+                expect.max.saturating_sub(expect.ideal),
+            )
+        }
+    }
+
+    /// Returns the amount `progress / span` of the way from `from` to `to`.
+    fn interpolate(
+        from: BalanceOf<T>,
+        to: BalanceOf<T>,
+        progress: Perbill,
+        span: Perbill,
+    ) -> BalanceOf<T> {
+        if span.is_zero() {
+            return to
+        }
+        let share = Perbill::from_rational(progress.deconstruct(), span.deconstruct());
+        from.saturating_add(share * to.saturating_sub(from))
+    }
+
+    /// Mints the inflation of `era`, scaled by the stake snapshot at its start, into the reward
+    /// pot which holds the fees collected for staking rewards.
+    pub(crate) fn mint_era_inflation(era: EraIndex) {
+        let inflation = Self::compute_issuance(<Staked<T>>::get(era));
+        if inflation.is_zero() {
+            return
+        }
+        T::Currency::deposit_creating(&Self::compute_reward_pot_account_id(), inflation);
+        Self::deposit_event(Event::InflationMinted { era, amount: inflation });
+    }
+}
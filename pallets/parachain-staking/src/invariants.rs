@@ -0,0 +1,252 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Invariants of the values derived from the staking state, and their repair
+
+#[cfg(any(feature = "try-runtime", test))]
+use crate::pallet::{LiquidUnbondingPoints, LiquidUnbondings, PoolMembers, PoolUnbondings, Pools};
+use crate::{
+    pallet::{
+        BalanceOf, BottomNominations, CandidateInfo, CandidatePoolNodes, Config, Error, Event,
+        NominationScheduledRequests, NominatorState, Pallet, TopNominations, Total,
+    },
+    types::{Bond, BondAdjust},
+};
+use frame_support::ensure;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError,
+};
+use sp_std::prelude::*;
+
+/// Returns the sum of the amounts of `bonds`.
+fn sum_of<'a, AccountId: 'a, Balance: 'a + Copy + Saturating + Zero>(
+    bonds: impl IntoIterator<Item = &'a Bond<AccountId, Balance>>,
+) -> Balance {
+    bonds
+        .into_iter()
+        .fold(Zero::zero(), |total, bond| total.saturating_add(bond.amount))
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the sum of the pending requests of `nominator` to revoke or decrease its
+    /// `nominations`.
+    fn nominator_less_total(
+        nominator: &T::AccountId,
+        nominations: &[Bond<T::AccountId, BalanceOf<T>>],
+    ) -> BalanceOf<T> {
+        nominations
+            .iter()
+            .flat_map(|bond| <NominationScheduledRequests<T>>::get(&bond.owner))
+            .filter(|request| &request.nominator == nominator)
+            .fold(Zero::zero(), |total, request| total.saturating_add(request.action.amount()))
+    }
+
+    /// Checks that the values derived from the staking state agree with it:
+    /// * `Total` is the sum of the candidate bonds and nominations
+    /// * the candidate pool holds the active candidates, backed by their `total_counted`
+    /// * the `total` and `less_total` of each nominator are the sums of its nominations and of
+    /// its pending requests
+    /// * the top and bottom nominations of each candidate add up to its `nomination_count`
+    /// * the points and unbonding points of each nomination pool are the sums of the points of
+    /// its members and of their unbondings
+    /// * `LiquidUnbondingPoints` is the sum of the points of the liquid unbondings
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn do_try_state() -> Result<(), &'static str> {
+        let mut total = BalanceOf::<T>::zero();
+        for (candidate, state) in <CandidateInfo<T>>::iter() {
+            let top =
+                <TopNominations<T>>::get(&candidate).ok_or("candidate has no top nominations")?;
+            let bottom = <BottomNominations<T>>::get(&candidate)
+                .ok_or("candidate has no bottom nominations")?;
+            let top_total = sum_of(top.nominations.iter());
+            let bottom_total = sum_of(bottom.nominations.iter());
+            ensure!(
+                top.total == top_total && bottom.total == bottom_total,
+                "nominations total is not the sum of the nominations"
+            );
+            ensure!(
+                top.nominations.len().saturating_add(bottom.nominations.len()) ==
+                    state.nomination_count as usize,
+                "nomination_count does not count the top and bottom nominations"
+            );
+            ensure!(
+                state.total_counted == state.bond.saturating_add(top_total),
+                "total_counted is not the bond plus the top nominations"
+            );
+            match <CandidatePoolNodes<T>>::get(&candidate) {
+                Some(node) => {
+                    ensure!(state.is_active(), "inactive candidate is in the candidate pool");
+                    ensure!(
+                        node.amount == state.total_counted,
+                        "candidate is not in the candidate pool with its total_counted"
+                    );
+                },
+                None =>
+                    ensure!(!state.is_active(), "active candidate is not in the candidate pool"),
+            }
+            total = total
+                .saturating_add(state.bond)
+                .saturating_add(top_total)
+                .saturating_add(bottom_total);
+        }
+        ensure!(
+            <Total<T>>::get() == total,
+            "Total is not the sum of the candidate bonds and nominations"
+        );
+        ensure!(
+            <CandidatePoolNodes<T>>::iter_keys().all(|candidate| Self::is_candidate(&candidate)),
+            "candidate pool holds an account which is not a candidate"
+        );
+        Self::check_candidate_pool()?;
+
+        for (nominator, state) in <NominatorState<T>>::iter() {
+            ensure!(
+                state.total == sum_of(state.nominations.0.iter()),
+                "nominator total is not the sum of its nominations"
+            );
+            ensure!(
+                state.less_total == Self::nominator_less_total(&nominator, &state.nominations.0),
+                "nominator less_total is not the sum of its pending requests"
+            );
+        }
+
+        for (pool_id, pool) in <Pools<T>>::iter() {
+            let points = <PoolMembers<T>>::iter_prefix_values(pool_id)
+                .fold(BalanceOf::<T>::zero(), |total, member| total.saturating_add(member.points));
+            ensure!(pool.points == points, "pool points are not the sum of its members points");
+            let unbonding_points = <PoolUnbondings<T>>::iter_prefix_values(pool_id)
+                .flatten()
+                .fold(BalanceOf::<T>::zero(), |total, unbonding| {
+                    total.saturating_add(unbonding.points)
+                });
+            ensure!(
+                pool.unbonding_points == unbonding_points,
+                "pool unbonding points are not the sum of its unbondings points"
+            );
+        }
+
+        let liquid_unbonding_points = <LiquidUnbondings<T>>::iter_values()
+            .flatten()
+            .fold(BalanceOf::<T>::zero(), |total, unbonding| {
+                total.saturating_add(unbonding.points)
+            });
+        ensure!(
+            <LiquidUnbondingPoints<T>>::get() == liquid_unbonding_points,
+            "LiquidUnbondingPoints is not the sum of the points of the liquid unbondings"
+        );
+        Ok(())
+    }
+
+    /// Recomputes the values derived from the staking state of each of `accounts`, as a
+    /// candidate and as a nominator, then `Total`. `candidate_count` must be at least the number
+    /// of candidates.
+    pub(crate) fn repair_accounts_staking_state(
+        accounts: Vec<T::AccountId>,
+        candidate_count: u32,
+    ) -> Result<(), DispatchError> {
+        ensure!(
+            <CandidateInfo<T>>::iter_keys().count() as u32 <= candidate_count,
+            <Error<T>>::TooLowCandidateCountWeightHintRepairStakingState
+        );
+        for account in accounts {
+            let candidate_repaired = Self::repair_candidate(&account);
+            if Self::repair_nominator(&account)? || candidate_repaired {
+                Self::deposit_event(Event::StakingStateRepaired { account });
+            }
+        }
+        Self::repair_total();
+        Ok(())
+    }
+
+    /// Recomputes the nomination totals and count, the counted stake and the candidate pool
+    /// entry of `candidate`. An account which is not a candidate is removed from the pool.
+    /// Returns true if any was repaired.
+    fn repair_candidate(candidate: &T::AccountId) -> bool {
+        let mut state = match <CandidateInfo<T>>::get(candidate) {
+            Some(state) => state,
+            None => return Self::candidate_pool_remove(candidate),
+        };
+        let mut top = <TopNominations<T>>::get(candidate).unwrap_or_default();
+        let mut bottom = <BottomNominations<T>>::get(candidate).unwrap_or_default();
+        let old = (state.nomination_count, state.total_counted, top.total, bottom.total);
+
+        top.sort_greatest_to_least();
+        top.total = sum_of(top.nominations.iter());
+        bottom.sort_greatest_to_least();
+        bottom.total = sum_of(bottom.nominations.iter());
+        state.nomination_count =
+            top.nominations.len().saturating_add(bottom.nominations.len()) as u32;
+        // updates the candidate pool if the counted stake changes
+        state.reset_top_data::<T>(candidate.clone(), &top);
+        state.reset_bottom_data::<T>(&bottom);
+        let pool_repaired = if state.is_active() {
+            match <CandidatePoolNodes<T>>::get(candidate) {
+                Some(node) if node.amount == state.total_counted => false,
+                _ => {
+                    Self::update_active(candidate.clone(), state.total_counted);
+                    true
+                },
+            }
+        } else {
+            Self::candidate_pool_remove(candidate)
+        };
+        let repaired =
+            old != (state.nomination_count, state.total_counted, top.total, bottom.total);
+
+        <TopNominations<T>>::insert(candidate, top);
+        <BottomNominations<T>>::insert(candidate, bottom);
+        <CandidateInfo<T>>::insert(candidate, state);
+        repaired || pool_repaired
+    }
+
+    /// Recomputes the `total` and `less_total` of `nominator`, and its lock.
+    /// Returns true if any was repaired.
+    fn repair_nominator(nominator: &T::AccountId) -> Result<bool, DispatchError> {
+        let mut state = match <NominatorState<T>>::get(nominator) {
+            Some(state) => state,
+            None => return Ok(false),
+        };
+        let total = sum_of(state.nominations.0.iter());
+        let less_total = Self::nominator_less_total(nominator, &state.nominations.0);
+        if state.total == total && state.less_total == less_total {
+            return Ok(false)
+        }
+        state.total = total;
+        state.less_total = less_total;
+        // sets the lock to the repaired total
+        state.adjust_bond_lock::<T>(BondAdjust::Decrease)?;
+        <NominatorState<T>>::insert(nominator, state);
+        Ok(true)
+    }
+
+    /// Recomputes `Total` from the candidate bonds and nominations.
+    fn repair_total() {
+        let old = <Total<T>>::get();
+        let new =
+            <CandidateInfo<T>>::iter().fold(BalanceOf::<T>::zero(), |total, (candidate, state)| {
+                let top =
+                    <TopNominations<T>>::get(&candidate).map_or(Zero::zero(), |top| top.total);
+                let bottom = <BottomNominations<T>>::get(&candidate)
+                    .map_or(Zero::zero(), |bottom| bottom.total);
+                total.saturating_add(state.bond).saturating_add(top).saturating_add(bottom)
+            });
+        if old != new {
+            <Total<T>>::put(new);
+            Self::deposit_event(Event::TotalRepaired { old, new });
+        }
+    }
+}
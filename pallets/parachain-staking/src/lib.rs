@@ -25,30 +25,114 @@
 //!
 //! At the start of every era,
 //! * issuance is calculated for collators (and their nominators) for block authoring
-//! `T::RewardPaymentDelay` eras ago
+//! `RewardPaymentDelay` eras ago, from the reward pot which holds the fees collected for staking
+//! rewards
+//! * `ParachainBondInfo.percent` of the era reward is transferred to the parachain bond account
 //! * a new set of collators is chosen from the candidates
+//! * the inflation of the new era, if `InflationConfig` is set, is minted into the reward pot. It
+//! scales with the staked ratio, the share of the total issuance at stake, between the `min`,
+//! `ideal` and `max` staking expectations.
 //!
-//! Immediately following a era change, payments are made once-per-block until all payments have
-//! been made. In each such block, one collator is chosen for a rewards payment and is paid along
-//! with each of its top `T::MaxTopNominationsPerCandidate` nominators.
+//! Immediately following a era change, payments are made every block until all payments have
+//! been made. In each such block, as many collators as `T::RewardPayoutWeight` allows, at least
+//! one, are paid along with each of their top `T::MaxTopNominationsPerCandidate` nominators.
+//! Eras are paid out oldest first, and eras not fully paid out by the next era change carry over.
 //!
+//! A collator's commission is taken out of its reward first and the remainder is shared pro-rata
+//! with its nominators. Commission changes requested with `set_commission` apply after
+//! `CommissionChangeDelay` eras, and the commission is capped at `MaxCommission`.
+//!
+//! Rewards are paid to the bonded account unless another destination is set with `set_payee`.
+//! When the payout mode is set to `PayoutMode::Claim`, rewards are credited instead and stakers
+//! collect them with `claim_rewards`. Reward payments that fail are retried in later blocks until
+//! `T::MaxRewardPaymentAttempts` is reached, and by anyone calling `retry_failed_reward_payment`.
+//!
+//! ### Slashing
+//! Collators and their nominators are slashed with `Pallet::slash_collator`, or through
+//! `ReportOffence::report_offence` which also chills the offenders out of the candidate pool.
+//! Offences can be slashed up to `T::SlashableEras` eras after they were committed. Slashes are
+//! applied `T::SlashDeferDuration` eras later, unless cancelled by `T::SlashCancelOrigin`.
+//!
+//! Selected collators authoring less than `T::MinAuthoredBlocksRatio` of their share of an era's
+//! blocks for `T::MaxUnderperformingEras` consecutive eras are idled until they call `go_online`.
+//!
+//! ### Authoring and sessions
+//! The pallet implements the nimbus `CanAuthor` filter: the selected candidates may author blocks,
+//! or only a random `EligibleRatio` of them in each slot when it is set.
+//!
+//! It also implements `pallet_session::SessionManager`, handing the selected candidates to
+//! `pallet_session`. Eras can then last `T::SessionsPerEra` sessions instead of `Era.length`
+//! blocks.
+//!
+//! ### Parameters
+//! The minimum stakes, the exit and request delays and the reward payment delay are kept in
+//! `StakingParams`, seeded from the `Config` constants. `T::MonetaryGovernanceOrigin` can change
+//! them with `set_staking_parameter`, taking effect from the next era.
+//!
+//! ### Staking
 //! To join the set of candidates, call `join_candidates` with `bond >= MinCandidateStk`.
 //! To leave the set of candidates, call `schedule_leave_candidates`. If the call succeeds,
 //! the collator is removed from the pool of candidates so they cannot be selected for future
 //! collator sets, but they are not unbonded until their exit request is executed. Any signed
-//! account may trigger the exit `T::LeaveCandidatesDelay` eras after the era in which the
+//! account may trigger the exit `LeaveCandidatesDelay` eras after the era in which the
 //! original request was made.
 //!
+//! A candidate or nominator can set a controller account with `set_controller`, to sign the
+//! operational calls in place of the account holding the bonded funds: going offline or online,
+//! scheduling and cancelling requests, and setting the commission, the reward destination or
+//! the nomination policy.
+//! Bonding more, nominating and paying out rewards still need the bonded account.
+//!
+//! Candidates can publish a profile with `set_operator_profile`, reserving a deposit which is
+//! returned when it is cleared or the candidate leaves. With `set_nomination_policy`, they can
+//! require a higher minimum for new nominations, cap their number of nominators or only accept
+//! the nominators of an allowlist.
+//!
 //! To join the set of nominators, call `nominate` and pass in an account that is
 //! already a collator candidate and `bond >= MinNominatorStk`. Each nominator can nominate up to
 //! `T::MaxNominationsPerNominator` collator candidates by calling `nominate`.
+//! Nominators can have a share of their rewards restaked into the nomination automatically by
+//! calling `nominate_with_auto_compound` or `set_auto_compound`.
+//!
+//! To nominate several candidates at once, call `nominate_many` with the amount for each
+//! candidate, or with a total amount and a `SplitStrategy` to split it across them.
 //!
 //! To revoke a nomination, call `revoke_nomination` with the collator candidate's account.
+//! To move stake of a nomination to another candidate without waiting for the revoke delay, call
+//! `switch_nomination`, at most once every `NominationSwitchCooldown` eras.
 //! To leave the set of nominators and revoke all nominations, call `leave_nominators`.
+//!
+//! Holders of less than `MinNominatorStk` can stake through nomination pools. `create_pool` makes
+//! a pool account nominate a candidate, `join_pool` adds at least `MinPoolJoin` to it in exchange
+//! for points, and the rewards reaching the pool account are shared by points. Members unbond
+//! with `unbond_from_pool` through the scheduled requests of the pool account, then withdraw with
+//! `withdraw_unbonded_from_pool` once the request is due.
+//!
+//! When `LiquidStakingAssetId` is set, stake can also be deposited with `liquid_stake` in
+//! exchange for a fungible derivative, which the liquid staking account bonds with the selected
+//! candidates according to the `LiquidStrategy`. Its rewards are restaked, raising the exchange
+//! rate of the derivative. `liquid_unstake` burns the derivative and unbonds the stake it is
+//! worth, withdrawn with `withdraw_liquid_unbonded` once the request is due.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod author_filter;
+mod auto_compound;
+mod candidate_pool;
+mod controllers;
+pub mod inflation;
+mod invariants;
+mod liquid_staking;
+mod liveness;
+pub mod migrations;
+mod nomination_policy;
 mod nomination_requests;
+mod nomination_split;
+mod nomination_switch;
+mod operator_profile;
+mod pools;
+mod queries;
+mod slashing;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -57,10 +141,41 @@ pub mod weights;
 mod benchmarks;
 #[cfg(test)]
 mod mock;
+mod session;
 mod set;
 #[cfg(test)]
+mod test_author_filter;
+#[cfg(test)]
+mod test_candidate_pool;
+#[cfg(test)]
+mod test_controllers;
+#[cfg(test)]
+mod test_inflation;
+#[cfg(test)]
+mod test_invariants;
+#[cfg(test)]
+mod test_liquid_staking;
+#[cfg(test)]
+mod test_liveness;
+#[cfg(test)]
+mod test_nomination_policy;
+#[cfg(test)]
+mod test_nomination_split;
+#[cfg(test)]
+mod test_nomination_switch;
+#[cfg(test)]
+mod test_operator_profile;
+#[cfg(test)]
+mod test_pools;
+#[cfg(test)]
+mod test_queries;
+#[cfg(test)]
 mod test_reward_payout;
 #[cfg(test)]
+mod test_session;
+#[cfg(test)]
+mod test_slashing;
+#[cfg(test)]
 mod test_staking_pot;
 #[cfg(test)]
 mod tests;
@@ -68,8 +183,17 @@ mod tests;
 use frame_support::pallet;
 use weights::WeightInfo;
 
+pub use auto_compound::AutoCompoundConfig;
+pub use inflation::{InflationInfo, Range};
+pub use liquid_staking::{LiquidStakingStrategy, LiquidUnbonding};
+pub use nomination_policy::NominationPolicy;
 pub use nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest};
+pub use nomination_split::{NominationTargets, SplitStrategy};
+pub use operator_profile::OperatorProfile;
 pub use pallet::*;
+pub use pools::{PoolId, PoolInfo, PoolMember, PoolUnbonding};
+pub use queries::{CandidateProfile, NominationPlacement, NominationPosition, StakingRewards};
+pub use slashing::UnappliedSlash;
 pub use traits::*;
 pub use types::*;
 pub use EraIndex;
@@ -77,8 +201,13 @@ pub use EraIndex;
 #[pallet]
 pub mod pallet {
     use crate::{
+        auto_compound::AutoCompoundConfig,
+        candidate_pool::{Bag, BagIndex, PoolNode},
+        inflation::{InflationInfo, Range},
+        liquid_staking::{LiquidStakingStrategy, LiquidUnbonding},
         nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest},
-        set::OrderedSet,
+        pools::{PoolId, PoolInfo, PoolMember, PoolUnbonding},
+        slashing::UnappliedSlash,
         traits::*,
         types::*,
         WeightInfo,
@@ -86,27 +215,40 @@ pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
         traits::{
-            tokens::WithdrawReasons, Currency, ExistenceRequirement, Get, LockIdentifier,
-            LockableCurrency, ReservableCurrency,
+            tokens::{fungibles, WithdrawReasons},
+            Currency, EstimateNextSessionRotation, ExistenceRequirement, Get, LockIdentifier,
+            LockableCurrency, OnUnbalanced, Randomness, ReservableCurrency, StorageVersion,
         },
         PalletId,
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::{
-        traits::{AccountIdConversion, Bounded, CheckedAdd, CheckedSub, Saturating, Zero},
-        Perbill,
+        traits::{
+            AccountIdConversion, Bounded, CheckedAdd, CheckedSub, SaturatedConversion, Saturating,
+            Zero,
+        },
+        Perbill, Percent,
     };
     use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
     /// Pallet for parachain staking
     #[pallet::pallet]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     pub type EraIndex = u32;
-    type RewardPoint = u32;
+    pub type RewardPoint = u32;
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+    pub type LiquidAssetIdOf<T> = <<T as Config>::LiquidStakingAssets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::AssetId;
 
     pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
     pub const NOMINATOR_LOCK_ID: LockIdentifier = *b"stkngdel";
@@ -128,27 +270,79 @@ pub mod pallet {
         /// Default number of blocks per era at genesis
         #[pallet::constant]
         type DefaultBlocksPerEra: Get<u32>;
-        /// Number of eras that candidates remain bonded before exit request is executable
+        /// Initial number of eras that candidates remain bonded before exit request is executable
         #[pallet::constant]
         type LeaveCandidatesDelay: Get<EraIndex>;
-        /// Number of eras candidate requests to decrease self-bond must wait to be executable
+        /// Initial number of eras candidate requests to decrease self-bond must wait to be
+        /// executable
         #[pallet::constant]
         type CandidateBondLessDelay: Get<EraIndex>;
-        /// Number of eras that nominators remain bonded before exit request is executable
+        /// Initial number of eras a candidate commission change must wait before it is applied
+        #[pallet::constant]
+        type CommissionChangeDelay: Get<EraIndex>;
+        /// Maximum commission a candidate can take from its era reward
+        #[pallet::constant]
+        type MaxCommission: Get<Perbill>;
+        /// Initial number of eras that nominators remain bonded before exit request is executable
         #[pallet::constant]
         type LeaveNominatorsDelay: Get<EraIndex>;
-        /// Number of eras that nominations remain bonded before revocation request is executable
+        /// Initial number of eras that nominations remain bonded before revocation request is
+        /// executable
         #[pallet::constant]
         type RevokeNominationDelay: Get<EraIndex>;
-        /// Number of eras that nomination less requests must wait before executable
+        /// Initial number of eras that nomination less requests must wait before executable
         #[pallet::constant]
         type NominationBondLessDelay: Get<EraIndex>;
-        /// Number of eras after which block authors are rewarded
+        /// Number of eras a nominator must wait between two nomination switches
+        #[pallet::constant]
+        type NominationSwitchCooldown: Get<EraIndex>;
+        /// Initial number of eras after which block authors are rewarded
         #[pallet::constant]
         type RewardPaymentDelay: Get<EraIndex>;
+        /// Maximum number of attempts, including the original payout, to pay a reward before it
+        /// is abandoned and left in the reward pot
+        #[pallet::constant]
+        type MaxRewardPaymentAttempts: Get<u32>;
+        /// Maximum weight spent retrying failed reward payments in each block
+        #[pallet::constant]
+        type RewardPaymentRetryWeight: Get<Weight>;
+        /// Maximum weight spent paying collators and their nominators in each block. At least
+        /// one collator is paid per block while payouts are due.
+        #[pallet::constant]
+        type RewardPayoutWeight: Get<Weight>;
+        /// Number of eras a slash is deferred by, during which it can be cancelled. The exit and
+        /// request delays cannot be shorter.
+        #[pallet::constant]
+        type SlashDeferDuration: Get<EraIndex>;
+        /// Number of eras after an era during which offences committed in it can still be slashed
+        #[pallet::constant]
+        type SlashableEras: Get<EraIndex>;
+        /// The origin which can cancel a deferred slash
+        type SlashCancelOrigin: EnsureOrigin<Self::Origin>;
+        /// Handler for the funds slashed from collators and nominators
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// Minimum share of its expected blocks a selected collator must author in an era
+        #[pallet::constant]
+        type MinAuthoredBlocksRatio: Get<Perbill>;
+        /// Number of consecutive eras a selected collator can author less than
+        /// `MinAuthoredBlocksRatio` of its expected blocks before it is idled, zero to never idle
+        #[pallet::constant]
+        type MaxUnderperformingEras: Get<u32>;
+        /// Source of randomness for the subset of selected candidates eligible to author a slot
+        type RandomnessSource: Randomness<Self::Hash, Self::BlockNumber>;
+        /// Number of `pallet_session` sessions per era, zero for eras of `Era.length` blocks
+        #[pallet::constant]
+        type SessionsPerEra: Get<u32>;
+        /// Estimates the length of the `pallet_session` sessions, for the inflation of eras made of
+        /// sessions
+        type NextSessionRotation: EstimateNextSessionRotation<Self::BlockNumber>;
         /// Minimum number of selected candidates every era
         #[pallet::constant]
         type MinSelectedCandidates: Get<u32>;
+        /// Maximum number of candidates in the candidate pool, and of candidates selected every
+        /// era
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
         /// Maximum top nominations counted per candidate
         #[pallet::constant]
         type MaxTopNominationsPerCandidate: Get<u32>;
@@ -158,20 +352,50 @@ pub mod pallet {
         /// Maximum nominations per nominator
         #[pallet::constant]
         type MaxNominationsPerNominator: Get<u32>;
-        /// Minimum stake required for any candidate to be in `SelectedCandidates` for the era
+        /// Initial minimum stake required for any candidate to be in `SelectedCandidates` for the
+        /// era
         #[pallet::constant]
         type MinCollatorStk: Get<BalanceOf<Self>>;
-        /// Minimum stake required for any account to be a collator candidate
+        /// Initial minimum stake required for any account to be a collator candidate
         #[pallet::constant]
         type MinCandidateStk: Get<BalanceOf<Self>>;
-        /// Minimum stake for any registered on-chain account to nominate
+        /// Initial minimum stake for any registered on-chain account to nominate
         #[pallet::constant]
         type MinNomination: Get<BalanceOf<Self>>;
-        /// Minimum stake for any registered on-chain account to be a nominator
+        /// Initial minimum stake for any registered on-chain account to be a nominator
         #[pallet::constant]
         type MinNominatorStk: Get<BalanceOf<Self>>;
         /// Id of the account that will hold funds to be paid as staking reward
         type RewardPotId: Get<PalletId>;
+        /// Id from which the accounts of the nomination pools are derived
+        type PoolPalletId: Get<PalletId>;
+        /// Minimum stake to join a nomination pool
+        #[pallet::constant]
+        type MinPoolJoin: Get<BalanceOf<Self>>;
+        /// Maximum unbondings of a nomination pool member waiting to be withdrawn
+        #[pallet::constant]
+        type MaxPoolUnbonding: Get<u32>;
+        /// The fungible assets the liquid staking derivative is minted in
+        type LiquidStakingAssets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+        /// The asset of the liquid staking derivative, None to disable liquid staking
+        type LiquidStakingAssetId: Get<Option<LiquidAssetIdOf<Self>>>;
+        /// Id of the account holding the stake of liquid staking
+        type LiquidStakingPalletId: Get<PalletId>;
+        /// Maximum unbondings of a liquid staker waiting to be withdrawn
+        #[pallet::constant]
+        type MaxLiquidUnbonding: Get<u32>;
+        /// Deposit reserved from a candidate for storing its operator profile
+        #[pallet::constant]
+        type ProfileDepositBase: Get<BalanceOf<Self>>;
+        /// Deposit reserved from a candidate for each byte of its operator profile
+        #[pallet::constant]
+        type ProfileDepositPerByte: Get<BalanceOf<Self>>;
+        /// Maximum length of each field of an operator profile
+        #[pallet::constant]
+        type MaxProfileFieldLength: Get<u32>;
+        /// Maximum number of nominators on the allowlist of a candidate
+        #[pallet::constant]
+        type MaxNominationAllowlist: Get<u32>;
         /// Handler to notify the runtime when a collator is paid.
         /// If you don't need it, you can specify the type `()`.
         type OnCollatorPayout: OnCollatorPayout<Self::AccountId, BalanceOf<Self>>;
@@ -206,6 +430,7 @@ pub mod pallet {
         CandidateCannotLeaveYet,
         CannotGoOnlineIfLeaving,
         ExceedMaxNominationsPerNominator,
+        ExceedMaxNominationsPerCandidate,
         AlreadyNominatedCandidate,
         InvalidSchedule,
         CannotSetBelowMin,
@@ -226,6 +451,58 @@ pub mod pallet {
         PendingNominationRequestNotDueYet,
         CannotNominateLessThanOrEqualToLowestBottomWhenFull,
         PendingNominationRevoke,
+        TooLowCandidateAutoCompoundingNominationCountToNominate,
+        TooLowCandidateAutoCompoundingNominationCountToAutoCompound,
+        TooLowNominationCountToAutoCompound,
+        CannotSetPayeeIfNotStaker,
+        NoRewardsToClaim,
+        FailedRewardPaymentDNE,
+        CollatorSnapshotDNE,
+        OffenceTooOld,
+        NoSlashesToCancel,
+        SlashIndicesNotSortedAndUnique,
+        InvalidSlashIndex,
+        EligibleRatioCannotBeZero,
+        MinNominationAboveMinNominatorStk,
+        DelayShorterThanSlashDeferDuration,
+        RewardPaymentDelayCannotDecrease,
+        CandidateLimitReached,
+        CannotSetAboveMaxCandidates,
+        CommissionAboveMax,
+        TooManyUnappliedSlashes,
+        TooLowCandidateCountWeightHintRepairStakingState,
+        PoolDNE,
+        PoolMemberDNE,
+        NotPoolOperator,
+        PoolJoinBelowMin,
+        NotEnoughPoolPoints,
+        PoolUnbondingUnavailable,
+        TooManyPoolUnbondings,
+        NoPoolUnbondingDue,
+        LiquidStakingDisabled,
+        LiquidAmountTooLow,
+        LiquidUnbondingUnavailable,
+        TooManyLiquidUnbondings,
+        NoLiquidUnbondingDue,
+        CannotSwitchToSameCandidate,
+        NominationSwitchCooldown,
+        InvalidSwitchAmount,
+        NoNominationTargets,
+        DuplicateNominationTarget,
+        CannotSetControllerIfNotStaker,
+        InvalidController,
+        ControllerAlreadyPaired,
+        NoController,
+        ControllerCanOnlySetBondedPayee,
+        ControllerCannotAutoCompound,
+        ControllerCannotStake,
+        ProfileFieldTooLong,
+        NoOperatorProfile,
+        AllowlistTooLong,
+        NominatorNotAllowed,
+        CandidateNominatorsFull,
+        NominationBelowCandidateMin,
+        CandidateNotAcceptingNominations,
     }
 
     #[pallet::event]
@@ -268,6 +545,14 @@ pub mod pallet {
             amount: BalanceOf<T>,
             new_bond: BalanceOf<T>,
         },
+        /// Candidate requested to change its commission.
+        CandidateCommissionChangeRequested {
+            candidate: T::AccountId,
+            commission: Perbill,
+            execute_era: EraIndex,
+        },
+        /// Candidate commission change has been applied.
+        CandidateCommissionSet { candidate: T::AccountId, old: Perbill, new: Perbill },
         /// Candidate temporarily leave the set of collator candidates without unbonding.
         CandidateWentOffline { candidate: T::AccountId },
         /// Candidate rejoins the set of collator candidates.
@@ -358,16 +643,154 @@ pub mod pallet {
             unstaked_amount: BalanceOf<T>,
             total_candidate_staked: BalanceOf<T>,
         },
-        /// Paid the account (nominator or collator) the balance as liquid rewards.
-        Rewarded { account: T::AccountId, rewards: BalanceOf<T> },
+        /// Auto-compounding reward percent was set for a nomination.
+        AutoCompoundSet { candidate: T::AccountId, nominator: T::AccountId, value: Percent },
+        /// Compounded a portion of rewards towards a nomination.
+        Compounded { candidate: T::AccountId, nominator: T::AccountId, amount: BalanceOf<T> },
+        /// Paid the rewards of the account (nominator or collator) to its reward destination.
+        Rewarded {
+            account: T::AccountId,
+            rewards: BalanceOf<T>,
+            destination: RewardDestination<T::AccountId>,
+        },
+        /// Credited the account (nominator or collator) the balance as claimable rewards earned
+        /// through the candidate.
+        RewardCredited { account: T::AccountId, candidate: T::AccountId, rewards: BalanceOf<T> },
         /// There was an error attempting to pay the nominator their staking reward.
         ErrorPayingStakingReward { payee: T::AccountId, rewards: BalanceOf<T> },
+        /// Paid the account (nominator or collator) a reward of the era that failed to be paid
+        /// before.
+        RewardPaymentRetried {
+            era: EraIndex,
+            account: T::AccountId,
+            rewards: BalanceOf<T>,
+            destination: RewardDestination<T::AccountId>,
+        },
+        /// Gave up paying the account (nominator or collator) a reward of the era after too many
+        /// failed attempts. The balance is left in the reward pot.
+        RewardPaymentAbandoned { era: EraIndex, account: T::AccountId, rewards: BalanceOf<T> },
         /// Set total selected candidates to this value.
         TotalSelectedSet { old: u32, new: u32 },
         /// Set blocks per era
         BlocksPerEraSet { current_era: EraIndex, first_block: T::BlockNumber, old: u32, new: u32 },
+        /// Set the reward destination of the account (nominator or collator).
+        PayeeSet { account: T::AccountId, payee: RewardDestination<T::AccountId> },
+        /// Set the reward payout mode.
+        PayoutModeSet { old: PayoutMode, new: PayoutMode },
+        /// Set staking expectations.
+        StakeExpectationsSet { expect_min: Perbill, expect_ideal: Perbill, expect_max: Perbill },
+        /// Set annual inflation rate (and the resulting per-era rate).
+        InflationSet {
+            annual_min: Perbill,
+            annual_ideal: Perbill,
+            annual_max: Perbill,
+            era_min: Perbill,
+            era_ideal: Perbill,
+            era_max: Perbill,
+        },
+        /// Transferred to account which holds funds reserved for parachain bond.
+        ReservedForParachainBond { account: T::AccountId, value: BalanceOf<T> },
+        /// Failed to transfer the parachain bond reserve, which stays in the reward pot to be paid
+        /// out in a later era.
+        ParachainBondReserveDeferred { account: T::AccountId, value: BalanceOf<T> },
+        /// Account (re)set for parachain bond treasury.
+        ParachainBondAccountSet { old: T::AccountId, new: T::AccountId },
+        /// Percent of the era reward reserved for parachain bond (re)set.
+        ParachainBondReservePercentSet { old: Percent, new: Percent },
+        /// Minted the inflation of an era into the reward pot at its start.
+        InflationMinted { era: EraIndex, amount: BalanceOf<T> },
+        /// Set the share of selected candidates eligible to author each slot, all if None.
+        EligibleRatioSet { old: Option<Percent>, new: Option<Percent> },
+        /// Set a staking parameter, applied from the start of the next era.
+        StakingParameterSet {
+            old: StakingParameter<BalanceOf<T>>,
+            new: StakingParameter<BalanceOf<T>>,
+        },
         /// Not enough fund to cover the staking reward payment.
         NotEnoughFundsForEraPayment { reward_pot_balance: BalanceOf<T> },
+        /// A slash of the collator and its nominators was reported for the era, to be applied
+        /// at the start of `apply_era`.
+        SlashReported {
+            collator: T::AccountId,
+            era: EraIndex,
+            fraction: Perbill,
+            apply_era: EraIndex,
+        },
+        /// A deferred slash of the collator for the era was cancelled.
+        SlashCancelled { collator: T::AccountId, era: EraIndex },
+        /// Slashed the account (nominator or collator) the balance.
+        Slashed { account: T::AccountId, amount: BalanceOf<T> },
+        /// Candidate was forced offline for an offence, until it calls `go_online`.
+        CandidateChilled { candidate: T::AccountId },
+        /// Candidate was idled for authoring too few blocks, until it calls `go_online`.
+        CandidateIdled {
+            candidate: T::AccountId,
+            era: EraIndex,
+            authored_blocks: u32,
+            expected_blocks: u32,
+        },
+        /// Repaired the values derived from the staking state of the account.
+        StakingStateRepaired { account: T::AccountId },
+        /// Repaired the total stake from the candidate bonds and nominations.
+        TotalRepaired { old: BalanceOf<T>, new: BalanceOf<T> },
+        /// Created a nomination pool nominating the candidate with the stake of its operator.
+        PoolCreated {
+            pool_id: PoolId,
+            operator: T::AccountId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The nomination pool nominated another candidate.
+        PoolNominated { pool_id: PoolId, candidate: T::AccountId, amount: BalanceOf<T> },
+        /// Member added stake to the nomination pool in exchange for points.
+        PoolJoined {
+            pool_id: PoolId,
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+            points: BalanceOf<T>,
+        },
+        /// Member unbonded the stake its points were worth from the nomination pool.
+        PoolMemberUnbonded {
+            pool_id: PoolId,
+            member: T::AccountId,
+            points: BalanceOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// Member withdrew unbonded stake from the nomination pool.
+        PoolMemberWithdrawn { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// Paid the member its share of the rewards of the nomination pool.
+        PoolRewardPaid { pool_id: PoolId, member: T::AccountId, rewards: BalanceOf<T> },
+        /// Deposited stake into liquid staking, minting the derivative.
+        LiquidStaked { staker: T::AccountId, amount: BalanceOf<T>, minted: BalanceOf<T> },
+        /// Burnt derivative, unbonding the stake it was worth from liquid staking.
+        LiquidUnstaked { staker: T::AccountId, burnt: BalanceOf<T>, amount: BalanceOf<T> },
+        /// Withdrew stake unbonded from liquid staking.
+        LiquidWithdrawn { staker: T::AccountId, amount: BalanceOf<T> },
+        /// Set the strategy the liquid staking account bonds new stake with.
+        LiquidStrategySet { old: LiquidStakingStrategy, new: LiquidStakingStrategy },
+        /// Moved stake of a nomination to another candidate without unbonding it.
+        NominationSwitched {
+            nominator: T::AccountId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Set the controller signing the operational calls of the stash.
+        ControllerSet { stash: T::AccountId, old: Option<T::AccountId>, new: T::AccountId },
+        /// Removed the controller of the stash.
+        ControllerCleared { stash: T::AccountId, controller: T::AccountId },
+        /// Set the operator profile of the candidate, reserving the deposit.
+        OperatorProfileSet { candidate: T::AccountId, deposit: BalanceOf<T> },
+        /// Removed the operator profile of the candidate, unreserving the deposit.
+        OperatorProfileCleared { candidate: T::AccountId, deposit: BalanceOf<T> },
+        /// Set the policy of the candidate on the nominations it accepts, None for no
+        /// restriction.
+        NominationPolicySet {
+            candidate: T::AccountId,
+            min_nomination: Option<BalanceOf<T>>,
+            max_nominators: Option<u32>,
+            allowlist_len: Option<u32>,
+        },
     }
 
     #[pallet::hooks]
@@ -376,33 +799,14 @@ pub mod pallet {
             let mut weight = T::WeightInfo::base_on_initialize();
 
             let mut era = <Era<T>>::get();
-            if era.should_update(n) {
-                // mutate era
-                era.update(n);
-                // notify that new era begin
-                weight = weight.saturating_add(T::OnNewEra::on_new_era(era.current));
-                // pay all stakers for T::RewardPaymentDelay eras ago
-                Self::prepare_staking_payouts(era.current);
-                // select top collator candidates for next era
-                let (collator_count, nomination_count, total_staked) =
-                    Self::select_top_candidates(era.current);
-                // start next era
-                <Era<T>>::put(era);
-                // snapshot total stake
-                <Staked<T>>::insert(era.current, <Total<T>>::get());
-                Self::deposit_event(Event::NewEra {
-                    starting_block: era.first,
-                    era: era.current,
-                    selected_collators_number: collator_count,
-                    total_balance: total_staked,
-                });
-                weight = weight.saturating_add(T::WeightInfo::era_transition_on_initialize(
-                    collator_count,
-                    nomination_count,
-                ));
+            // eras made of sessions are started by `SessionManager::start_session`
+            if T::SessionsPerEra::get().is_zero() && era.should_update(n) {
+                weight = weight.saturating_add(Self::start_new_era(&mut era, n));
             }
 
-            weight = weight.saturating_add(Self::handle_delayed_payouts(era.current));
+            // retry failed payments before new ones can fail in this block
+            weight = weight.saturating_add(Self::retry_failed_reward_payments());
+            weight = weight.saturating_add(Self::handle_delayed_payouts());
 
             // add on_finalize weight
             weight = weight.saturating_add(
@@ -412,6 +816,22 @@ pub mod pallet {
             );
             weight
         }
+
+        fn integrity_test() {
+            let slash_defer_duration = T::SlashDeferDuration::get();
+            assert!(
+                [
+                    T::LeaveCandidatesDelay::get(),
+                    T::CandidateBondLessDelay::get(),
+                    T::LeaveNominatorsDelay::get(),
+                    T::RevokeNominationDelay::get(),
+                    T::NominationBondLessDelay::get(),
+                ]
+                .iter()
+                .all(|delay| *delay >= slash_defer_duration),
+                "exit delays cannot be shorter than `SlashDeferDuration`"
+            );
+        }
     }
 
     #[pallet::storage]
@@ -431,7 +851,7 @@ pub mod pallet {
         _,
         Twox64Concat,
         T::AccountId,
-        Nominator<T::AccountId, BalanceOf<T>>,
+        Nominator<T::AccountId, BalanceOf<T>, T::MaxNominationsPerNominator>,
         OptionQuery,
     >;
 
@@ -448,10 +868,33 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,
-        Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
+        BoundedVec<
+            ScheduledRequest<T::AccountId, BalanceOf<T>>,
+            AddGet<T::MaxTopNominationsPerCandidate, T::MaxBottomNominationsPerCandidate>,
+        >,
+        ValueQuery,
+    >;
+
+    /// Stores auto-compounding configuration per collator, sorted by nominator.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_compounding_nominations)]
+    pub(crate) type AutoCompoundingNominations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<
+            AutoCompoundConfig<T::AccountId>,
+            AddGet<T::MaxTopNominationsPerCandidate, T::MaxBottomNominationsPerCandidate>,
+        >,
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn payee)]
+    /// Where the rewards of a collator or nominator are paid, the bonded account if not set
+    pub(crate) type Payee<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn top_nominations)]
     /// Top nominations for collator candidate
@@ -459,7 +902,7 @@ pub mod pallet {
         _,
         Twox64Concat,
         T::AccountId,
-        Nominations<T::AccountId, BalanceOf<T>>,
+        Nominations<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
         OptionQuery,
     >;
 
@@ -470,14 +913,21 @@ pub mod pallet {
         _,
         Twox64Concat,
         T::AccountId,
-        Nominations<T::AccountId, BalanceOf<T>>,
+        Nominations<T::AccountId, BalanceOf<T>, T::MaxBottomNominationsPerCandidate>,
         OptionQuery,
     >;
 
     #[pallet::storage]
     #[pallet::getter(fn selected_candidates)]
     /// The collator candidates selected for the current era
-    type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    pub(crate) type SelectedCandidates<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn queued_candidates)]
+    /// The collator candidates planned for the next era, when eras are made of sessions
+    pub(crate) type QueuedCandidates<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn total)]
@@ -485,10 +935,33 @@ pub mod pallet {
     pub(crate) type Total<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn candidate_pool)]
-    /// The pool of collator candidates, each with their total backing stake
-    pub(crate) type CandidatePool<T: Config> =
-        StorageValue<_, OrderedSet<Bond<T::AccountId, BalanceOf<T>>>, ValueQuery>;
+    #[pallet::getter(fn candidate_pool_node)]
+    /// The active collator candidates, each with their total backing stake and neighbours in
+    /// their bag of the candidate pool
+    pub(crate) type CandidatePoolNodes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        PoolNode<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_pool_bag)]
+    /// The bags of the candidate pool, by bag index
+    pub(crate) type CandidatePoolBags<T: Config> =
+        StorageMap<_, Twox64Concat, BagIndex, Bag<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn non_empty_candidate_pool_bags)]
+    /// The indices of the bags of the candidate pool holding candidates, sorted
+    pub(crate) type NonEmptyCandidatePoolBags<T: Config> =
+        StorageValue<_, BoundedVec<BagIndex, T::MaxCandidates>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_pool_count)]
+    /// The number of candidates in the candidate pool
+    pub(crate) type CandidatePoolCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn at_stake)]
@@ -499,13 +972,13 @@ pub mod pallet {
         EraIndex,
         Twox64Concat,
         T::AccountId,
-        CollatorSnapshot<T::AccountId, BalanceOf<T>>,
+        CollatorSnapshot<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
         ValueQuery,
     >;
 
     #[pallet::storage]
     #[pallet::getter(fn delayed_payouts)]
-    /// Delayed payouts
+    /// Payouts of the eras due, until all their collators are paid
     pub type DelayedPayouts<T: Config> =
         StorageMap<_, Twox64Concat, EraIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
 
@@ -516,7 +989,8 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn points)]
-    /// Total points awarded to collators for block production in the era
+    /// Total points awarded to collators for block production in the era, until the era is due
+    /// to be paid out and they move to `DelayedPayouts`
     pub type Points<T: Config> = StorageMap<_, Twox64Concat, EraIndex, RewardPoint, ValueQuery>;
 
     #[pallet::storage]
@@ -539,10 +1013,209 @@ pub mod pallet {
     pub type LockedEraPayout<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
-    #[pallet::getter(fn faild_payments)]
-    /// Storage value that holds any failed payments
-    pub type FailedRewardPayments<T: Config> =
-        StorageMap<_, Twox64Concat, BalanceOf<T>, bool, ValueQuery>;
+    #[pallet::getter(fn parachain_bond_info)]
+    /// Parachain bond config info { account, percent_of_era_reward }
+    pub type ParachainBondInfo<T: Config> =
+        StorageValue<_, ParachainBondConfig<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn inflation_config)]
+    /// Inflation configuration, no inflation until it is set
+    pub type InflationConfig<T: Config> = StorageValue<_, InflationInfo, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn payout_mode)]
+    /// Whether era rewards are pushed to stakers or credited for them to claim
+    pub type RewardPayoutMode<T: Config> = StorageValue<_, PayoutMode, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn eligible_ratio)]
+    /// Share of the selected candidates eligible to author each slot, all of them if None
+    pub type EligibleRatio<T: Config> = StorageValue<_, Percent, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultStakingParams<T: Config>() -> StakingParameters<BalanceOf<T>> {
+        <Pallet<T>>::initial_staking_params()
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn staking_params)]
+    /// Staking parameters in effect for the current era
+    pub type StakingParams<T: Config> =
+        StorageValue<_, StakingParameters<BalanceOf<T>>, ValueQuery, DefaultStakingParams<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_staking_params)]
+    /// Staking parameters to apply at the start of the next era
+    pub type PendingStakingParams<T: Config> =
+        StorageValue<_, StakingParameters<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn claimable_rewards)]
+    /// Rewards credited to a staker through a candidate, waiting to be claimed
+    pub type ClaimableRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unapplied_slashes)]
+    /// Slashes to be applied at the start of an era, by era
+    pub type UnappliedSlashes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        BoundedVec<
+            UnappliedSlash<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
+            T::MaxCandidates,
+        >,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    /// The offences already reported, by era and offender, while their era can be slashed
+    pub type ProcessedOffences<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn failed_reward_payments)]
+    /// Reward payments that failed, by staker and era, waiting to be retried
+    pub type FailedRewardPayments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        EraIndex,
+        FailedRewardPayment<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn authored_blocks)]
+    /// Blocks authored by each collator per era, until the era is checked for liveness
+    pub type AuthoredBlocks<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn underperforming_eras)]
+    /// Consecutive eras in which a selected collator authored too few blocks
+    pub type UnderperformingEras<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    /// Id of the next nomination pool created
+    pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool)]
+    /// Nomination pools, by id
+    pub type Pools<T: Config> =
+        StorageMap<_, Twox64Concat, PoolId, PoolInfo<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_member)]
+    /// Members of each nomination pool
+    pub type PoolMembers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        T::AccountId,
+        PoolMember<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_unbondings)]
+    /// Stake of each nomination pool member unbonding, or unbonded and not withdrawn yet
+    pub type PoolUnbondings<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<PoolUnbonding<T::AccountId, BalanceOf<T>>, T::MaxPoolUnbonding>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn liquid_strategy)]
+    /// The strategy the liquid staking account bonds new stake with
+    pub type LiquidStrategy<T: Config> = StorageValue<_, LiquidStakingStrategy, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn liquid_unbondings)]
+    /// Stake of each liquid staker unbonding, or unbonded and not withdrawn yet
+    pub type LiquidUnbondings<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<LiquidUnbonding<T::AccountId, BalanceOf<T>>, T::MaxLiquidUnbonding>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn liquid_unbonding_total)]
+    /// Stake of all the liquid stakers unbonding, or unbonded and not withdrawn yet
+    pub type LiquidUnbondingTotal<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn liquid_unbonding_points)]
+    /// Points of all the liquid unbondings, sharing `LiquidUnbondingTotal` so that they bear
+    /// slashes too
+    pub type LiquidUnbondingPoints<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn liquid_staked_value)]
+    /// Stake owned by the holders of the liquid staking derivative, bonded or idle. The stake
+    /// unbonding for burnt derivative is owned by its unbonders.
+    pub type LiquidStakedValue<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_nomination_switch)]
+    /// Era of the last nomination switch of each nominator
+    pub type LastNominationSwitch<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, EraIndex, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn controller)]
+    /// Controller of each stash, the account holding the bonded funds of a candidate or nominator
+    pub type Controllers<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn stash)]
+    /// Stash each controller acts for
+    pub type Stashes<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn operator_profile)]
+    /// Profile published by each candidate and its operator
+    pub type OperatorProfiles<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        OperatorProfile<BalanceOf<T>, T::MaxProfileFieldLength>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn nomination_policy)]
+    /// Policy of each candidate restricting the nominations it accepts
+    pub type NominationPolicies<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        NominationPolicy<T::AccountId, BalanceOf<T>, T::MaxNominationAllowlist>,
+        OptionQuery,
+    >;
 
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
@@ -550,18 +1223,23 @@ pub mod pallet {
         /// Vec of tuples of the format (nominator AccountId, collator AccountId, nomination
         /// Amount)
         pub nominations: Vec<(T::AccountId, T::AccountId, BalanceOf<T>)>,
+        /// Inflation configuration
+        pub inflation_config: InflationInfo,
     }
 
     #[cfg(feature = "std")]
     impl<T: Config> Default for GenesisConfig<T> {
         fn default() -> Self {
-            Self { candidates: vec![], nominations: vec![] }
+            Self { candidates: vec![], nominations: vec![], inflation_config: Default::default() }
         }
     }
 
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
+            // Seed the staking parameters from the config constants
+            <StakingParams<T>>::put(<Pallet<T>>::initial_staking_params());
+            <InflationConfig<T>>::put(self.inflation_config.clone());
             let mut candidate_count = 0u32;
             // Initialize the candidates
             for &(ref candidate, balance) in &self.candidates {
@@ -614,6 +1292,10 @@ pub mod pallet {
                 }
             }
             // Set total selected candidates to minimum config
+            assert!(
+                T::MinSelectedCandidates::get() <= T::MaxCandidates::get(),
+                "MinSelectedCandidates cannot be above MaxCandidates."
+            );
             <TotalSelected<T>>::put(T::MinSelectedCandidates::get());
             // Choose top TotalSelected collator candidates
             let (v_count, _, total_staked) = <Pallet<T>>::select_top_candidates(1u32);
@@ -634,12 +1316,88 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::set_staking_expectations())]
+        /// Set the expectations for total staked. These expectations determine the issuance for
+        /// the era according to logic in `fn compute_issuance`
+        pub fn set_staking_expectations(
+            origin: OriginFor<T>,
+            expectations: Range<Perbill>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(expectations.is_valid(), Error::<T>::InvalidSchedule);
+            let mut config = <InflationConfig<T>>::get();
+            ensure!(config.expect != expectations, Error::<T>::NoWritingSameValue);
+            config.set_expectations(expectations);
+            Self::deposit_event(Event::StakeExpectationsSet {
+                expect_min: config.expect.min,
+                expect_ideal: config.expect.ideal,
+                expect_max: config.expect.max,
+            });
+            <InflationConfig<T>>::put(config);
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_inflation())]
+        /// Set the annual inflation rate to derive per-era inflation
+        /// - a zero schedule stops inflation, leaving the reward pot as the only source of rewards
+        pub fn set_inflation(
+            origin: OriginFor<T>,
+            schedule: Range<Perbill>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(schedule.is_valid(), Error::<T>::InvalidSchedule);
+            let mut config = <InflationConfig<T>>::get();
+            ensure!(config.annual != schedule, Error::<T>::NoWritingSameValue);
+            config.annual = schedule;
+            config.set_era_from_annual::<T>(schedule);
+            Self::deposit_event(Event::InflationSet {
+                annual_min: config.annual.min,
+                annual_ideal: config.annual.ideal,
+                annual_max: config.annual.max,
+                era_min: config.era.min,
+                era_ideal: config.era.ideal,
+                era_max: config.era.max,
+            });
+            <InflationConfig<T>>::put(config);
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_account())]
+        /// Set the account that will hold funds set aside for parachain bond
+        pub fn set_parachain_bond_account(
+            origin: OriginFor<T>,
+            new: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let ParachainBondConfig { account: old, percent } = <ParachainBondInfo<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <ParachainBondInfo<T>>::put(ParachainBondConfig { account: new.clone(), percent });
+            Self::deposit_event(Event::ParachainBondAccountSet { old, new });
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_reserve_percent())]
+        /// Set the percent of each era reward set aside for parachain bond
+        /// - the reserve is taken when the era payout is prepared, before stakers are paid
+        pub fn set_parachain_bond_reserve_percent(
+            origin: OriginFor<T>,
+            new: Percent,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let ParachainBondConfig { account, percent: old } = <ParachainBondInfo<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <ParachainBondInfo<T>>::put(ParachainBondConfig { account, percent: new });
+            Self::deposit_event(Event::ParachainBondReservePercentSet { old, new });
+            Ok(().into())
+        }
+
         #[pallet::weight(<T as Config>::WeightInfo::set_total_selected())]
         /// Set the total number of collator candidates selected per era
         /// - changes are not applied until the start of the next era
         pub fn set_total_selected(origin: OriginFor<T>, new: u32) -> DispatchResultWithPostInfo {
             frame_system::ensure_root(origin)?;
             ensure!(new >= T::MinSelectedCandidates::get(), Error::<T>::CannotSetBelowMin);
+            ensure!(new <= T::MaxCandidates::get(), Error::<T>::CannotSetAboveMaxCandidates);
             let old = <TotalSelected<T>>::get();
             ensure!(old != new, Error::<T>::NoWritingSameValue);
             ensure!(
@@ -668,6 +1426,10 @@ pub mod pallet {
             );
             era.length = new;
             <Era<T>>::put(era);
+            // update per-era inflation given new blocks per era
+            let mut inflation_config = <InflationConfig<T>>::get();
+            inflation_config.reset_era::<T>();
+            <InflationConfig<T>>::put(inflation_config);
             Self::deposit_event(Event::BlocksPerEraSet {
                 current_era: now,
                 first_block: first,
@@ -677,47 +1439,139 @@ pub mod pallet {
 
             Ok(().into())
         }
-        #[pallet::weight(<T as Config>::WeightInfo::join_candidates(*candidate_count))]
-        /// Join the set of collator candidates
-        pub fn join_candidates(
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_payout_mode())]
+        /// Set whether era rewards are transferred to stakers as they are processed, or
+        /// credited for them to claim with `claim_rewards`
+        /// - rewards already credited remain claimable after switching back to `Push`
+        pub fn set_payout_mode(
             origin: OriginFor<T>,
-            bond: BalanceOf<T>,
-            candidate_count: u32,
+            new: PayoutMode,
         ) -> DispatchResultWithPostInfo {
-            let acc = ensure_signed(origin)?;
-            ensure!(!Self::is_candidate(&acc), Error::<T>::CandidateExists);
-            ensure!(!Self::is_nominator(&acc), Error::<T>::NominatorExists);
-            ensure!(bond >= T::MinCandidateStk::get(), Error::<T>::CandidateBondBelowMin);
-            let mut candidates = <CandidatePool<T>>::get();
-            let old_count = candidates.0.len() as u32;
-            ensure!(
-                candidate_count >= old_count,
-                Error::<T>::TooLowCandidateCountWeightHintJoinCandidates
-            );
-            ensure!(
-                candidates.insert(Bond { owner: acc.clone(), amount: bond }),
-                Error::<T>::CandidateExists
-            );
-            ensure!(
-                Self::get_collator_stakable_free_balance(&acc) >= bond,
-                Error::<T>::InsufficientBalance,
-            );
-            T::Currency::set_lock(COLLATOR_LOCK_ID, &acc, bond, WithdrawReasons::all());
-            let candidate = CandidateMetadata::new(bond);
-            <CandidateInfo<T>>::insert(&acc, candidate);
-            let empty_nominations: Nominations<T::AccountId, BalanceOf<T>> = Default::default();
-            // insert empty top nominations
-            <TopNominations<T>>::insert(&acc, empty_nominations.clone());
-            // insert empty bottom nominations
-            <BottomNominations<T>>::insert(&acc, empty_nominations);
-            <CandidatePool<T>>::put(candidates);
-            let new_total = <Total<T>>::get().saturating_add(bond);
-            <Total<T>>::put(new_total);
-            Self::deposit_event(Event::JoinedCollatorCandidates {
-                account: acc,
-                amount_locked: bond,
-                new_total_amt_locked: new_total,
-            });
+            frame_system::ensure_root(origin)?;
+            let old = <RewardPayoutMode<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <RewardPayoutMode<T>>::put(new);
+            Self::deposit_event(Event::PayoutModeSet { old, new });
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_eligible_ratio())]
+        /// Set the share of selected candidates, chosen at random for each slot, eligible to
+        /// author it
+        /// - `None` makes all the selected candidates eligible
+        /// - at least one selected candidate is always eligible
+        pub fn set_eligible_ratio(
+            origin: OriginFor<T>,
+            new: Option<Percent>,
+        ) -> DispatchResultWithPostInfo {
+            frame_system::ensure_root(origin)?;
+            ensure!(new != Some(Percent::zero()), Error::<T>::EligibleRatioCannotBeZero);
+            let old = <EligibleRatio<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <EligibleRatio<T>>::set(new);
+            Self::deposit_event(Event::EligibleRatioSet { old, new });
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+        /// Set one of the staking parameters
+        /// - changes are not applied until the start of the next era
+        /// - minimum stakes cannot be zero and `MinNomination` cannot exceed `MinNominatorStk`
+        /// - exit delays cannot be shorter than `T::SlashDeferDuration`, so that stake cannot
+        /// leave before the slashes it is liable for are applied
+        /// - `RewardPaymentDelay` cannot be zero or decrease, which would skip eras to pay
+        pub fn set_staking_parameter(
+            origin: OriginFor<T>,
+            new: StakingParameter<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let current = <StakingParams<T>>::get();
+            let mut params = <PendingStakingParams<T>>::get().unwrap_or(current);
+            let old = params.get(&new);
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            params.set(new);
+            Self::ensure_valid_staking_params(&params, &current)?;
+            <PendingStakingParams<T>>::put(params);
+            Self::deposit_event(Event::StakingParameterSet { old, new });
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_deferred_slash(slash_indices.len() as u32))]
+        /// Cancel the slashes deferred to be applied at the start of `era`, given by their index
+        /// in `UnappliedSlashes`
+        /// - `slash_indices` must be sorted and unique
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            era: EraIndex,
+            slash_indices: Vec<u32>,
+        ) -> DispatchResultWithPostInfo {
+            T::SlashCancelOrigin::ensure_origin(origin)?;
+            Self::cancel_unapplied_slashes(era, slash_indices)
+        }
+        #[pallet::weight(
+			<T as Config>::WeightInfo::repair_staking_state(accounts.len() as u32, *candidate_count)
+		)]
+        /// Recompute the values derived from the staking state of `accounts`, as candidates and
+        /// nominators, then `Total`, and repair those which disagree with it
+        /// - `candidate_count` must be at least the number of candidates
+        pub fn repair_staking_state(
+            origin: OriginFor<T>,
+            accounts: Vec<T::AccountId>,
+            candidate_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            frame_system::ensure_root(origin)?;
+            Self::repair_accounts_staking_state(accounts, candidate_count)?;
+            Ok(().into())
+        }
+        #[pallet::weight(<T as Config>::WeightInfo::join_candidates(*candidate_count))]
+        /// Join the set of collator candidates
+        pub fn join_candidates(
+            origin: OriginFor<T>,
+            bond: BalanceOf<T>,
+            candidate_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let acc = ensure_signed(origin)?;
+            ensure!(!Self::is_candidate(&acc), Error::<T>::CandidateExists);
+            ensure!(!Self::is_nominator(&acc), Error::<T>::NominatorExists);
+            ensure!(!Self::is_controller(&acc), Error::<T>::ControllerCannotStake);
+            ensure!(
+                bond >= <StakingParams<T>>::get().min_candidate_stk,
+                Error::<T>::CandidateBondBelowMin
+            );
+            ensure!(
+                candidate_count >= <CandidatePoolCount<T>>::get(),
+                Error::<T>::TooLowCandidateCountWeightHintJoinCandidates
+            );
+            ensure!(
+                Self::get_collator_stakable_free_balance(&acc) >= bond,
+                Error::<T>::InsufficientBalance,
+            );
+            ensure!(Self::candidate_pool_insert(&acc, bond)?, Error::<T>::CandidateExists);
+            T::Currency::set_lock(COLLATOR_LOCK_ID, &acc, bond, WithdrawReasons::all());
+            let candidate = CandidateMetadata::new(bond);
+            <CandidateInfo<T>>::insert(&acc, candidate);
+            let empty_top_nominations: Nominations<
+                T::AccountId,
+                BalanceOf<T>,
+                T::MaxTopNominationsPerCandidate,
+            > = Default::default();
+            let empty_bottom_nominations: Nominations<
+                T::AccountId,
+                BalanceOf<T>,
+                T::MaxBottomNominationsPerCandidate,
+            > = Default::default();
+            // insert empty top nominations
+            <TopNominations<T>>::insert(&acc, empty_top_nominations);
+            // insert empty bottom nominations
+            <BottomNominations<T>>::insert(&acc, empty_bottom_nominations);
+            let new_total = <Total<T>>::get().saturating_add(bond);
+            <Total<T>>::put(new_total);
+            Self::deposit_event(Event::JoinedCollatorCandidates {
+                account: acc,
+                amount_locked: bond,
+                new_total_amt_locked: new_total,
+            });
             Ok(().into())
         }
         #[pallet::weight(<T as Config>::WeightInfo::schedule_leave_candidates(*candidate_count))]
@@ -727,17 +1581,14 @@ pub mod pallet {
             origin: OriginFor<T>,
             candidate_count: u32,
         ) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             let (now, when) = state.schedule_leave::<T>()?;
-            let mut candidates = <CandidatePool<T>>::get();
             ensure!(
-                candidate_count >= candidates.0.len() as u32,
+                candidate_count >= <CandidatePoolCount<T>>::get(),
                 Error::<T>::TooLowCandidateCountToLeaveCandidates
             );
-            if candidates.remove(&Bond::from_owner(collator.clone())) {
-                <CandidatePool<T>>::put(candidates);
-            }
+            Self::candidate_pool_remove(&collator);
             <CandidateInfo<T>>::insert(&collator, state);
             Self::deposit_event(Event::CandidateScheduledExit {
                 exit_allowed_era: now,
@@ -784,6 +1635,7 @@ pub mod pallet {
                         // last nomination was left.
                         <NominatorState<T>>::remove(&bond.owner);
                         T::Currency::remove_lock(NOMINATOR_LOCK_ID, &bond.owner);
+                        Self::staker_exited(&bond.owner);
                     } else {
                         <NominatorState<T>>::insert(&bond.owner, nominator);
                     }
@@ -814,8 +1666,13 @@ pub mod pallet {
             T::Currency::remove_lock(COLLATOR_LOCK_ID, &candidate);
             <CandidateInfo<T>>::remove(&candidate);
             <NominationScheduledRequests<T>>::remove(&candidate);
+            <AutoCompoundingNominations<T>>::remove(&candidate);
             <TopNominations<T>>::remove(&candidate);
             <BottomNominations<T>>::remove(&candidate);
+            <UnderperformingEras<T>>::remove(&candidate);
+            Self::operator_profile_remove(&candidate);
+            <NominationPolicies<T>>::remove(&candidate);
+            Self::staker_exited(&candidate);
             let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
             <Total<T>>::put(new_total_staked);
             Self::deposit_event(Event::CandidateLeft {
@@ -833,20 +1690,18 @@ pub mod pallet {
             origin: OriginFor<T>,
             candidate_count: u32,
         ) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
             state.go_online();
-            let mut candidates = <CandidatePool<T>>::get();
             ensure!(
-                candidates.0.len() as u32 <= candidate_count,
+                <CandidatePoolCount<T>>::get() <= candidate_count,
                 Error::<T>::TooLowCandidateCountWeightHintCancelLeaveCandidates
             );
             ensure!(
-                candidates.insert(Bond { owner: collator.clone(), amount: state.total_counted }),
+                Self::candidate_pool_insert(&collator, state.total_counted)?,
                 Error::<T>::AlreadyActive
             );
-            <CandidatePool<T>>::put(candidates);
             <CandidateInfo<T>>::insert(&collator, state);
             Self::deposit_event(Event::CancelledCandidateExit { candidate: collator });
             Ok(().into())
@@ -854,14 +1709,11 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::go_offline())]
         /// Temporarily leave the set of collator candidates without unbonding
         pub fn go_offline(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(state.is_active(), Error::<T>::AlreadyOffline);
             state.go_offline();
-            let mut candidates = <CandidatePool<T>>::get();
-            if candidates.remove(&Bond::from_owner(collator.clone())) {
-                <CandidatePool<T>>::put(candidates);
-            }
+            Self::candidate_pool_remove(&collator);
             <CandidateInfo<T>>::insert(&collator, state);
             Self::deposit_event(Event::CandidateWentOffline { candidate: collator });
             Ok(().into())
@@ -869,17 +1721,15 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::go_online())]
         /// Rejoin the set of collator candidates if previously had called `go_offline`
         pub fn go_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(!state.is_active(), Error::<T>::AlreadyActive);
             ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
             state.go_online();
-            let mut candidates = <CandidatePool<T>>::get();
             ensure!(
-                candidates.insert(Bond { owner: collator.clone(), amount: state.total_counted }),
+                Self::candidate_pool_insert(&collator, state.total_counted)?,
                 Error::<T>::AlreadyActive
             );
-            <CandidatePool<T>>::put(candidates);
             <CandidateInfo<T>>::insert(&collator, state);
             Self::deposit_event(Event::CandidateBackOnline { candidate: collator });
             Ok(().into())
@@ -906,7 +1756,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             less: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             let when = state.schedule_bond_less::<T>(less)?;
             <CandidateInfo<T>>::insert(&collator, state);
@@ -932,12 +1782,32 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::cancel_candidate_bond_less())]
         /// Cancel pending request to adjust the collator candidate self bond
         pub fn cancel_candidate_bond_less(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            let collator = ensure_signed(origin)?;
+            let collator = Self::ensure_stash(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             state.cancel_bond_less::<T>(collator.clone())?;
             <CandidateInfo<T>>::insert(&collator, state);
             Ok(().into())
         }
+        #[pallet::weight(<T as Config>::WeightInfo::set_commission())]
+        /// Request by collator candidate to change the commission taken from its era reward
+        /// - the new commission applies from the first era the candidate is selected after
+        /// `T::CommissionChangeDelay` eras
+        /// - the commission cannot exceed `T::MaxCommission`
+        pub fn set_commission(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let collator = Self::ensure_stash(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            let when = state.schedule_commission_change::<T>(commission)?;
+            <CandidateInfo<T>>::insert(&collator, state);
+            Self::deposit_event(Event::CandidateCommissionChangeRequested {
+                candidate: collator,
+                commission,
+                execute_era: when,
+            });
+            Ok(().into())
+        }
         #[pallet::weight(
 			<T as Config>::WeightInfo::nominate(
 				*candidate_nomination_count,
@@ -954,60 +1824,105 @@ pub mod pallet {
             nomination_count: u32,
         ) -> DispatchResultWithPostInfo {
             let nominator = ensure_signed(origin)?;
-            // check that caller can reserve the amount before any changes to storage
+            Self::nominator_add_nomination(
+                nominator,
+                candidate,
+                amount,
+                candidate_nomination_count,
+                nomination_count,
+            )
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::nominate_many(targets.count()))]
+        /// Nominate several candidates at once, with the amount given for each of them or with
+        /// a total amount split across them. Emits a `Nomination` event for each candidate.
+        pub fn nominate_many(
+            origin: OriginFor<T>,
+            targets: NominationTargets<T::AccountId, BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+            Self::nominator_add_nominations(nominator, targets)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::nominate_with_auto_compound(
+				*candidate_nomination_count,
+				*candidate_auto_compounding_nomination_count,
+				*nomination_count,
+			)
+		)]
+        /// Nominate a collator candidate and set the percent of the rewards of this nomination
+        /// that is automatically restaked into it.
+        pub fn nominate_with_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            auto_compound: Percent,
+            candidate_nomination_count: u32,
+            candidate_auto_compounding_nomination_count: u32,
+            nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
             ensure!(
-                Self::get_nominator_stakable_free_balance(&nominator) >= amount,
-                Error::<T>::InsufficientBalance
+                <AutoCompoundingNominations<T>>::decode_len(&candidate).unwrap_or_default() as u32 <=
+                    candidate_auto_compounding_nomination_count,
+                Error::<T>::TooLowCandidateAutoCompoundingNominationCountToNominate,
             );
-            let mut nominator_state = if let Some(mut state) = <NominatorState<T>>::get(&nominator)
-            {
-                // nomination after first
-                ensure!(amount >= T::MinNomination::get(), Error::<T>::NominationBelowMin);
-                ensure!(
-                    nomination_count >= state.nominations.0.len() as u32,
-                    Error::<T>::TooLowNominationCountToNominate
-                );
-                ensure!(
-                    (state.nominations.0.len() as u32) < T::MaxNominationsPerNominator::get(),
-                    Error::<T>::ExceedMaxNominationsPerNominator
-                );
-                ensure!(
-                    state.add_nomination(Bond { owner: candidate.clone(), amount }),
-                    Error::<T>::AlreadyNominatedCandidate
-                );
-                state
-            } else {
-                // first nomination
-                ensure!(amount >= T::MinNominatorStk::get(), Error::<T>::NominatorBondBelowMin);
-                ensure!(!Self::is_candidate(&nominator), Error::<T>::CandidateExists);
-                Nominator::new(nominator.clone(), candidate.clone(), amount)
-            };
-            let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            Self::nominator_add_nomination(
+                nominator.clone(),
+                candidate.clone(),
+                amount,
+                candidate_nomination_count,
+                nomination_count,
+            )?;
+            if !auto_compound.is_zero() {
+                // read after the nomination is added, which may have kicked another nominator
+                let mut auto_compounding = <AutoCompoundingNominations<T>>::get(&candidate);
+                Self::set_auto_compound_config(
+                    &mut auto_compounding,
+                    nominator.clone(),
+                    auto_compound,
+                )?;
+                <AutoCompoundingNominations<T>>::insert(candidate.clone(), auto_compounding);
+                Self::deposit_event(Event::AutoCompoundSet {
+                    candidate,
+                    nominator,
+                    value: auto_compound,
+                });
+            }
+            Ok(().into())
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::set_auto_compound(
+				*candidate_auto_compounding_nomination_count,
+				*nomination_count,
+			)
+		)]
+        /// Set the percent of the rewards of an existing nomination that is automatically
+        /// restaked into it. Setting zero disables auto-compounding.
+        /// - the controller of the nominator can only disable it
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            value: Percent,
+            candidate_auto_compounding_nomination_count: u32,
+            nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            let nominator = <Stashes<T>>::get(&signer).unwrap_or_else(|| signer.clone());
+            // a controller cannot bond more of the funds of its stash
             ensure!(
-                candidate_nomination_count >= state.nomination_count,
-                Error::<T>::TooLowCandidateNominationCountToNominate
+                signer == nominator || value.is_zero(),
+                Error::<T>::ControllerCannotAutoCompound
             );
-            let (nominator_position, less_total_staked) =
-                state.add_nomination::<T>(&candidate, Bond { owner: nominator.clone(), amount })?;
-            // TODO: causes redundant free_balance check
-            nominator_state.adjust_bond_lock::<T>(BondAdjust::Increase(amount))?;
-            // only is_some if kicked the lowest bottom as a consequence of this new nomination
-            let net_total_increase = if let Some(less) = less_total_staked {
-                amount.saturating_sub(less)
-            } else {
-                amount
-            };
-            let new_total_locked = <Total<T>>::get().saturating_add(net_total_increase);
-            <Total<T>>::put(new_total_locked);
-            <CandidateInfo<T>>::insert(&candidate, state);
-            <NominatorState<T>>::insert(&nominator, nominator_state);
-            Self::deposit_event(Event::Nomination {
-                nominator,
-                locked_amount: amount,
+            Self::nomination_set_auto_compound(
                 candidate,
-                nominator_position,
-            });
-            Ok(().into())
+                nominator,
+                value,
+                candidate_auto_compounding_nomination_count,
+                nomination_count,
+            )
         }
 
         /// DEPRECATED use batch util with schedule_revoke_nomination for all nominations
@@ -1016,7 +1931,7 @@ pub mod pallet {
         /// Success forbids future nomination requests until the request is invoked or cancelled.
         #[pallet::weight(<T as Config>::WeightInfo::schedule_leave_nominators())]
         pub fn schedule_leave_nominators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            let nominator = ensure_signed(origin)?;
+            let nominator = Self::ensure_stash(origin)?;
             Self::nominator_schedule_revoke_all(nominator)
         }
 
@@ -1037,7 +1952,7 @@ pub mod pallet {
         /// request (thereby resetting the delay upon another `leave_nominators` call).
         #[pallet::weight(<T as Config>::WeightInfo::cancel_leave_nominators())]
         pub fn cancel_leave_nominators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-            let nominator = ensure_signed(origin)?;
+            let nominator = Self::ensure_stash(origin)?;
             Self::nominator_cancel_scheduled_revoke_all(nominator)
         }
 
@@ -1048,7 +1963,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             collator: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            let nominator = ensure_signed(origin)?;
+            let nominator = Self::ensure_stash(origin)?;
             Self::nomination_schedule_revoke(collator, nominator)
         }
 
@@ -1076,7 +1991,7 @@ pub mod pallet {
             candidate: T::AccountId,
             less: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            let nominator = ensure_signed(origin)?;
+            let nominator = Self::ensure_stash(origin)?;
             Self::nomination_schedule_bond_decrease(candidate, nominator, less)
         }
 
@@ -1097,10 +2012,312 @@ pub mod pallet {
             origin: OriginFor<T>,
             candidate: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            let nominator = ensure_signed(origin)?;
+            let nominator = Self::ensure_stash(origin)?;
             Self::nomination_cancel_request(candidate, nominator)
         }
 
+        #[pallet::weight(<T as Config>::WeightInfo::set_payee())]
+        /// Set where the staking rewards of the caller (collator or nominator) are paid.
+        /// - `Restake` bonds collator rewards and restakes all of a nominator's rewards into the
+        /// nomination that earned them, in place of any auto-compounding
+        /// - auto-compounding only applies to nominator rewards paid to the bonded account
+        /// - the setting is kept after leaving so that rewards still due are paid accordingly
+        /// - the controller of the account can only set `Bonded`
+        pub fn set_payee(
+            origin: OriginFor<T>,
+            payee: RewardDestination<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            let account = <Stashes<T>>::get(&signer).unwrap_or_else(|| signer.clone());
+            ensure!(
+                Self::is_candidate(&account) || Self::is_nominator(&account),
+                Error::<T>::CannotSetPayeeIfNotStaker
+            );
+            // a controller can neither send the rewards of its stash away nor bond them
+            ensure!(
+                signer == account || payee == RewardDestination::Bonded,
+                Error::<T>::ControllerCanOnlySetBondedPayee
+            );
+            ensure!(<Payee<T>>::get(&account) != payee, Error::<T>::NoWritingSameValue);
+            if payee == RewardDestination::Bonded {
+                <Payee<T>>::remove(&account);
+            } else {
+                <Payee<T>>::insert(&account, payee.clone());
+            }
+            Self::deposit_event(Event::PayeeSet { account, payee });
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::claim_rewards(*candidate_count))]
+        /// Claim the rewards credited to the caller, across all eras, through at most
+        /// `candidate_count` of the candidates they were earned through
+        pub fn claim_rewards(
+            origin: OriginFor<T>,
+            candidate_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let staker = ensure_signed(origin)?;
+            Self::staker_claim_rewards(staker, candidate_count)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::retry_failed_reward_payment())]
+        /// Retry the failed payment of the reward earned by `staker` in `era`
+        /// - any signed account can retry a payment
+        /// - a failed retry does not count toward `T::MaxRewardPaymentAttempts`
+        pub fn retry_failed_reward_payment(
+            origin: OriginFor<T>,
+            era: EraIndex,
+            staker: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let payment = <FailedRewardPayments<T>>::get(&staker, era)
+                .ok_or(Error::<T>::FailedRewardPaymentDNE)?;
+            let reward_pot_account_id = Self::compute_reward_pot_account_id();
+            // failing on purpose must not get the payment of another staker abandoned
+            Self::pay_failed_reward(&reward_pot_account_id, era, &staker, payment.amount)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::create_pool(*candidate_nomination_count))]
+        /// Create a nomination pool nominating `candidate` with `amount` of the stake of the
+        /// caller, which becomes its operator
+        /// - `amount` must be at least `MinNominatorStk`
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            candidate_nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let operator = ensure_signed(origin)?;
+            Self::pool_create(operator, candidate, amount, candidate_nomination_count)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::pool_nominate(
+				*candidate_nomination_count,
+				*nomination_count
+			)
+		)]
+        /// Nominate another candidate for the pool, with its idle stake first and the stake of
+        /// the caller for the rest, in exchange for points
+        /// - only the operator of the pool can nominate for it
+        pub fn pool_nominate(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            candidate_nomination_count: u32,
+            nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let operator = ensure_signed(origin)?;
+            Self::pool_nominate_candidate(
+                operator,
+                pool_id,
+                candidate,
+                amount,
+                candidate_nomination_count,
+                nomination_count,
+            )
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::join_pool())]
+        /// Add `amount` of stake to the pool in exchange for points, paying the caller its
+        /// rewards so far
+        /// - `amount` must be at least `MinPoolJoin`
+        pub fn join_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::pool_join(member, pool_id, amount)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::unbond_from_pool())]
+        /// Unbond the stake `points` of the caller are worth from the pool, paying the caller its
+        /// rewards so far. The stake is withdrawn with `withdraw_unbonded_from_pool` once the
+        /// request of the pool to unbond it is due.
+        pub fn unbond_from_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            points: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::pool_unbond(member, pool_id, points)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::withdraw_unbonded_from_pool(T::MaxPoolUnbonding::get())
+		)]
+        /// Withdraw the stake of the caller unbonded from the pool which is due
+        pub fn withdraw_unbonded_from_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::pool_withdraw_unbonded(member, pool_id)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::claim_pool_rewards()
+				.saturating_add(<T as Config>::WeightInfo::claim_rewards(
+					T::MaxNominationsPerNominator::get()
+				))
+		)]
+        /// Pay the caller its share of the rewards of the pool
+        pub fn claim_pool_rewards(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::pool_claim_rewards(member, pool_id)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::liquid_stake(T::MaxCandidates::get())
+				.saturating_add(<T as Config>::WeightInfo::claim_rewards(
+					T::MaxNominationsPerNominator::get()
+				))
+		)]
+        /// Deposit `amount` of stake into liquid staking, minting the derivative it is worth at
+        /// the current exchange rate
+        pub fn liquid_stake(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let staker = ensure_signed(origin)?;
+            Self::liquid_stake_deposit(staker, amount)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::liquid_unstake()
+				.saturating_add(<T as Config>::WeightInfo::claim_rewards(
+					T::MaxNominationsPerNominator::get()
+				))
+		)]
+        /// Burn `amount` of the derivative and unbond the stake it is worth. The stake is
+        /// withdrawn with `withdraw_liquid_unbonded` once the request of the liquid staking
+        /// account to unbond it is due.
+        pub fn liquid_unstake(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let staker = ensure_signed(origin)?;
+            Self::liquid_unstake_burn(staker, amount)
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::withdraw_liquid_unbonded(T::MaxLiquidUnbonding::get())
+		)]
+        /// Withdraw the stake of the caller unbonded from liquid staking which is due
+        pub fn withdraw_liquid_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let staker = ensure_signed(origin)?;
+            Self::liquid_withdraw_unbonded(staker)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_liquid_strategy())]
+        /// Set the strategy the liquid staking account bonds new stake with
+        pub fn set_liquid_strategy(
+            origin: OriginFor<T>,
+            new: LiquidStakingStrategy,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let old = <LiquidStrategy<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <LiquidStrategy<T>>::put(new);
+            Self::deposit_event(Event::LiquidStrategySet { old, new });
+            Ok(().into())
+        }
+
+        #[pallet::weight(
+			<T as Config>::WeightInfo::switch_nomination(
+				*candidate_nomination_count,
+				*nomination_count
+			)
+		)]
+        /// Move `amount` of the nomination of the caller towards `from` to `to`, without waiting
+        /// for the revoke delay. The stake stays locked and keeps earning rewards.
+        pub fn switch_nomination(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            candidate_nomination_count: u32,
+            nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+            Self::nomination_switch(
+                nominator,
+                from,
+                to,
+                amount,
+                candidate_nomination_count,
+                nomination_count,
+            )
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_controller())]
+        /// Set the account signing the operational calls of the caller (candidate or nominator),
+        /// replacing its previous controller
+        pub fn set_controller(
+            origin: OriginFor<T>,
+            controller: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let stash = ensure_signed(origin)?;
+            Self::controller_set(stash, controller)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::clear_controller())]
+        /// Remove the controller of the caller
+        pub fn clear_controller(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let stash = ensure_signed(origin)?;
+            Self::controller_clear(stash)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_operator_profile())]
+        /// Set the profile of the caller (candidate) for nominators to tell it apart, reserving
+        /// a deposit for its length. Each field is at most `MaxProfileFieldLength` bytes.
+        pub fn set_operator_profile(
+            origin: OriginFor<T>,
+            display_name: Vec<u8>,
+            website: Vec<u8>,
+            contact: Vec<u8>,
+            node_hint: Vec<u8>,
+            accepting_nominations: Option<bool>,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+            Self::operator_profile_set(
+                candidate,
+                display_name,
+                website,
+                contact,
+                node_hint,
+                accepting_nominations,
+            )
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::clear_operator_profile())]
+        /// Remove the profile of the caller, unreserving its deposit
+        pub fn clear_operator_profile(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+            Self::operator_profile_remove(&candidate).ok_or(Error::<T>::NoOperatorProfile)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_nomination_policy())]
+        /// Set the policy of the caller (candidate) on the nominations it accepts: its own
+        /// minimum nomination, a maximum number of nominators and an allowlist of nominators,
+        /// each of them unrestricted if None. It only applies to new nominations: existing ones
+        /// are kept and can still be increased.
+        pub fn set_nomination_policy(
+            origin: OriginFor<T>,
+            min_nomination: Option<BalanceOf<T>>,
+            max_nominators: Option<u32>,
+            allowlist: Option<Vec<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = Self::ensure_stash(origin)?;
+            Self::nomination_policy_set(candidate, min_nomination, max_nominators, allowlist)
+        }
+
         /// Hotfix to remove existing empty entries for candidates that have left.
         #[pallet::weight(
 			T::DbWeight::get().reads_writes(2 * candidates.len() as u64, candidates.len() as u64)
@@ -1158,10 +2375,13 @@ pub mod pallet {
         }
         /// Caller must ensure candidate is active before calling
         pub(crate) fn update_active(candidate: T::AccountId, total: BalanceOf<T>) {
-            let mut candidates = <CandidatePool<T>>::get();
-            candidates.remove(&Bond::from_owner(candidate.clone()));
-            candidates.insert(Bond { owner: candidate, amount: total });
-            <CandidatePool<T>>::put(candidates);
+            if let Err(error) = Self::candidate_pool_update(&candidate, total) {
+                log::error!(
+                    "LOGIC ERROR: candidate {:?} dropped from the candidate pool with error: {:?}",
+                    candidate,
+                    error
+                );
+            }
         }
 
         /// Compute total reward for era based on the amount in the reward pot
@@ -1192,6 +2412,81 @@ pub mod pallet {
             return payout
         }
 
+        /// Add a nomination of `amount` from `nominator` towards `candidate`, joining the set of
+        /// nominators if `nominator` is not a nominator yet
+        pub(crate) fn nominator_add_nomination(
+            nominator: T::AccountId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            candidate_nomination_count: u32,
+            nomination_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            // check that caller can reserve the amount before any changes to storage
+            ensure!(
+                Self::get_nominator_stakable_free_balance(&nominator) >= amount,
+                Error::<T>::InsufficientBalance
+            );
+            let mut nominator_state = if let Some(mut state) = <NominatorState<T>>::get(&nominator)
+            {
+                // nomination after first
+                ensure!(
+                    amount >= <StakingParams<T>>::get().min_nomination,
+                    Error::<T>::NominationBelowMin
+                );
+                ensure!(
+                    nomination_count >= state.nominations.0.len() as u32,
+                    Error::<T>::TooLowNominationCountToNominate
+                );
+                ensure!(
+                    (state.nominations.0.len() as u32) < T::MaxNominationsPerNominator::get(),
+                    Error::<T>::ExceedMaxNominationsPerNominator
+                );
+                ensure!(
+                    state
+                        .add_nomination(Bond { owner: candidate.clone(), amount })
+                        .map_err(|_| Error::<T>::ExceedMaxNominationsPerNominator)?,
+                    Error::<T>::AlreadyNominatedCandidate
+                );
+                state
+            } else {
+                // first nomination
+                ensure!(
+                    amount >= <StakingParams<T>>::get().min_nominator_stk,
+                    Error::<T>::NominatorBondBelowMin
+                );
+                ensure!(!Self::is_candidate(&nominator), Error::<T>::CandidateExists);
+                ensure!(!Self::is_controller(&nominator), Error::<T>::ControllerCannotStake);
+                Nominator::new(nominator.clone(), candidate.clone(), amount)
+                    .ok_or(Error::<T>::ExceedMaxNominationsPerNominator)?
+            };
+            let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            ensure!(
+                candidate_nomination_count >= state.nomination_count,
+                Error::<T>::TooLowCandidateNominationCountToNominate
+            );
+            let (nominator_position, less_total_staked) =
+                state.add_nomination::<T>(&candidate, Bond { owner: nominator.clone(), amount })?;
+            // TODO: causes redundant free_balance check
+            nominator_state.adjust_bond_lock::<T>(BondAdjust::Increase(amount))?;
+            // only is_some if kicked the lowest bottom as a consequence of this new nomination
+            let net_total_increase = if let Some(less) = less_total_staked {
+                amount.saturating_sub(less)
+            } else {
+                amount
+            };
+            let new_total_locked = <Total<T>>::get().saturating_add(net_total_increase);
+            <Total<T>>::put(new_total_locked);
+            <CandidateInfo<T>>::insert(&candidate, state);
+            <NominatorState<T>>::insert(&nominator, nominator_state);
+            Self::deposit_event(Event::Nomination {
+                nominator,
+                locked_amount: amount,
+                candidate,
+                nominator_position,
+            });
+            Ok(().into())
+        }
+
         /// Remove nomination from candidate state
         /// Amount input should be retrieved from nominator and it informs the storage lookups
         pub(crate) fn nominator_leaves_candidate(
@@ -1201,6 +2496,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
             state.rm_nomination_if_exists::<T>(&candidate, nominator.clone(), amount)?;
+            Self::nomination_remove_auto_compound(&candidate, &nominator);
             let new_total_locked = <Total<T>>::get().saturating_sub(amount);
             <Total<T>>::put(new_total_locked);
             let new_total = state.total_counted;
@@ -1215,55 +2511,98 @@ pub mod pallet {
         }
         fn prepare_staking_payouts(now: EraIndex) {
             // payout is now - delay eras ago => now - delay > 0 else return early
-            let delay = T::RewardPaymentDelay::get();
+            let delay = <StakingParams<T>>::get().reward_payment_delay;
             if now <= delay {
                 return
             }
             let era_to_payout = now.saturating_sub(delay);
-            let total_points = <Points<T>>::get(era_to_payout);
+            // an increased delay comes back to an era which may still be paid out
+            if <DelayedPayouts<T>>::contains_key(era_to_payout) {
+                return
+            }
+            let total_points = <Points<T>>::take(era_to_payout);
             if total_points.is_zero() {
                 return
             }
             // Remove stake because it has been processed.
-            <Staked<T>>::take(era_to_payout);
+            <Staked<T>>::remove(era_to_payout);
 
             let total_reward_to_pay = Self::compute_total_reward_to_pay();
+            let mut left_reward = total_reward_to_pay;
+            // reserve portion of the era reward for parachain bond account
+            let bond_config = <ParachainBondInfo<T>>::get();
+            let parachain_bond_reserve = bond_config.percent * total_reward_to_pay;
+            if !parachain_bond_reserve.is_zero() {
+                // the reserve is not paid to the stakers either way, and is released from
+                // `LockedEraPayout` as it is transferred or left in the reward pot
+                left_reward = left_reward.saturating_sub(parachain_bond_reserve);
+                <LockedEraPayout<T>>::mutate(|p| {
+                    *p = p.saturating_sub(parachain_bond_reserve);
+                });
+                match T::Currency::transfer(
+                    &Self::compute_reward_pot_account_id(),
+                    &bond_config.account,
+                    parachain_bond_reserve,
+                    ExistenceRequirement::KeepAlive,
+                ) {
+                    Ok(()) => Self::deposit_event(Event::ReservedForParachainBond {
+                        account: bond_config.account,
+                        value: parachain_bond_reserve,
+                    }),
+                    Err(error) => {
+                        log::warn!(
+                            "Failed to reserve {:?} for parachain bond with error {:?}",
+                            parachain_bond_reserve,
+                            error
+                        );
+                        Self::deposit_event(Event::ParachainBondReserveDeferred {
+                            account: bond_config.account,
+                            value: parachain_bond_reserve,
+                        });
+                    },
+                }
+            }
 
             let payout = DelayedPayout {
                 era_issuance: total_reward_to_pay,
-                total_staking_reward: total_reward_to_pay, /* TODO: Remove one of the duplicated
-                                                            * fields */
+                total_staking_reward: left_reward,
+                total_points,
             };
 
             <DelayedPayouts<T>>::insert(era_to_payout, payout);
         }
 
-        /// Wrapper around pay_one_collator_reward which handles the following logic:
-        /// * whether or not a payout needs to be made
-        /// * cleaning up when payouts are done
-        /// * returns the weight consumed by pay_one_collator_reward if applicable
-        fn handle_delayed_payouts(now: EraIndex) -> Weight {
-            let delay = T::RewardPaymentDelay::get();
-
-            // don't underflow uint
-            if now < delay {
-                return 0u64.into()
-            }
-
-            let paid_for_era = now.saturating_sub(delay);
-
-            if let Some(payout_info) = <DelayedPayouts<T>>::get(paid_for_era) {
-                let result = Self::pay_one_collator_reward(paid_for_era, payout_info);
-                if result.0.is_none() {
-                    // result.0 indicates whether or not a payout was made
-                    // clean up storage items that we no longer need
-                    <DelayedPayouts<T>>::remove(paid_for_era);
-                    <Points<T>>::remove(paid_for_era);
+        /// Pays as many collators of the eras due as fit in `T::RewardPayoutWeight`, and at least
+        /// one, oldest era first. Eras are cleaned up once all their collators are paid.
+        /// Returns the weight consumed.
+        fn handle_delayed_payouts() -> Weight {
+            let mut payouts = <DelayedPayouts<T>>::iter().collect::<Vec<_>>();
+            let mut weight = T::DbWeight::get().reads(payouts.len().saturating_add(1) as u64);
+            payouts.sort_by_key(|(era, _)| *era);
+
+            let budget = T::RewardPayoutWeight::get();
+            let max_collator_weight =
+                T::WeightInfo::pay_one_collator_reward(T::MaxTopNominationsPerCandidate::get());
+            let mut paid_any = false;
+            for (era, payout_info) in payouts {
+                loop {
+                    if paid_any && weight.saturating_add(max_collator_weight) > budget {
+                        return weight
+                    }
+                    let (paid, consumed) = Self::pay_one_collator_reward(era, &payout_info);
+                    weight = weight.saturating_add(consumed);
+                    if paid.is_none() {
+                        // all collators of the era are paid, clean up
+                        <DelayedPayouts<T>>::remove(era);
+                        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                        weight = weight
+                            .saturating_add(Self::prune_at_stake(era, <Era<T>>::get().current));
+                        break
+                    }
+                    paid_any = true;
                 }
-                result.1 // weight consumed by pay_one_collator_reward
-            } else {
-                0u64.into()
             }
+            weight
         }
 
         /// Payout a single collator from the given era.
@@ -1272,44 +2611,16 @@ pub mod pallet {
         /// or None if there were no more payouts to be made for the era.
         pub(crate) fn pay_one_collator_reward(
             paid_for_era: EraIndex,
-            payout_info: DelayedPayout<BalanceOf<T>>,
+            payout_info: &DelayedPayout<BalanceOf<T>>,
         ) -> (Option<(T::AccountId, BalanceOf<T>)>, Weight) {
-            // TODO: it would probably be optimal to roll Points into the DelayedPayouts storage
-            // item so that we do fewer reads each block
-            let total_points = <Points<T>>::get(paid_for_era);
+            let total_points = payout_info.total_points;
             if total_points.is_zero() {
-                // TODO: this case is obnoxious... it's a value query, so it could mean one of two
-                // different logic errors:
-                // 1. we removed it before we should have
-                // 2. we called pay_one_collator_reward when we were actually done with deferred
-                //    payouts
-                log::warn!("pay_one_collator_reward called with no <Points<T>> for the era!");
+                log::warn!("pay_one_collator_reward called with no points for the era!");
                 return (None, 0u64.into())
             }
 
             let reward_pot_account_id = Self::compute_reward_pot_account_id();
-            let pay_reward = |amount: BalanceOf<T>, to: T::AccountId| {
-                let result = T::Currency::transfer(
-                    &reward_pot_account_id,
-                    &to,
-                    amount,
-                    ExistenceRequirement::KeepAlive,
-                );
-                if let Ok(_) = result {
-                    Self::deposit_event(Event::Rewarded { account: to.clone(), rewards: amount });
-
-                    // Update storage with the amount we paid
-                    <LockedEraPayout<T>>::mutate(|p| {
-                        *p = p.saturating_sub(amount.into());
-                    });
-                } else {
-                    log::error!("💔 Error paying staking reward: {:?}", result);
-                    Self::deposit_event(Event::ErrorPayingStakingReward {
-                        payee: to.clone(),
-                        rewards: amount,
-                    });
-                }
-            };
+            let payout_mode = <RewardPayoutMode<T>>::get();
 
             if let Some((collator, pts)) = <AwardedPts<T>>::iter_prefix(paid_for_era).drain().next()
             {
@@ -1317,14 +2628,26 @@ pub mod pallet {
                 let pct_due = Perbill::from_rational(pts, total_points);
                 let total_reward_for_collator = pct_due * payout_info.total_staking_reward;
 
-                // Take the snapshot of block author and nominations
-                let state = <AtStake<T>>::take(paid_for_era, &collator);
+                // Read the snapshot of block author and nominations, kept to slash the era
+                let state = <AtStake<T>>::get(paid_for_era, &collator);
                 let num_nominators = state.nominations.len();
 
+                // commission is taken first, the rest is shared pro-rata by stake
+                let commission = state.commission * total_reward_for_collator;
+                let reward_to_share = total_reward_for_collator.saturating_sub(commission);
+
                 // pay collator's due portion first
                 let collator_pct = Perbill::from_rational(state.bond, state.total);
-                let collator_reward = collator_pct * total_reward_for_collator;
-                pay_reward(collator_reward, collator.clone());
+                let collator_reward = commission.saturating_add(collator_pct * reward_to_share);
+                extra_weight += Self::distribute_reward(
+                    payout_mode,
+                    paid_for_era,
+                    &reward_pot_account_id,
+                    &collator,
+                    collator.clone(),
+                    collator_reward,
+                    Percent::zero(),
+                );
 
                 // TODO: do we need this?
                 extra_weight += T::OnCollatorPayout::on_collator_payout(
@@ -1334,12 +2657,26 @@ pub mod pallet {
                 );
 
                 // pay nominators due portion, if there are any
+                let auto_compounding = <AutoCompoundingNominations<T>>::get(&collator);
                 for Bond { owner, amount } in state.nominations {
                     let percent = Perbill::from_rational(amount, state.total);
-                    let nominator_reward = percent * total_reward_for_collator;
-                    if !nominator_reward.is_zero() {
-                        pay_reward(nominator_reward, owner.clone());
+                    let nominator_reward = percent * reward_to_share;
+                    if nominator_reward.is_zero() {
+                        continue
                     }
+                    let auto_compound = auto_compounding
+                        .binary_search_by(|c| c.nominator.cmp(&owner))
+                        .map(|idx| auto_compounding[idx].value)
+                        .unwrap_or_else(|_| Percent::zero());
+                    extra_weight += Self::distribute_reward(
+                        payout_mode,
+                        paid_for_era,
+                        &reward_pot_account_id,
+                        &collator,
+                        owner,
+                        nominator_reward,
+                        auto_compound,
+                    );
                 }
 
                 (
@@ -1353,19 +2690,253 @@ pub mod pallet {
             }
         }
 
-        /// Compute the top `TotalSelected` candidates in the CandidatePool and return
-        /// a vec of their AccountIds (in the order of selection)
+        /// Pays the reward of `staker` earned through `candidate` in `era`, or credits it to the
+        /// claimable rewards of `staker` in [PayoutMode::Claim]. Payments that fail are queued to
+        /// be retried.
+        /// Returns the extra weight consumed by restaking.
+        fn distribute_reward(
+            payout_mode: PayoutMode,
+            era: EraIndex,
+            reward_pot: &T::AccountId,
+            candidate: &T::AccountId,
+            staker: T::AccountId,
+            amount: BalanceOf<T>,
+            auto_compound: Percent,
+        ) -> Weight {
+            match payout_mode {
+                PayoutMode::Push =>
+                    Self::pay_reward(reward_pot, candidate, staker.clone(), amount, auto_compound)
+                        .unwrap_or_else(|| {
+                            Self::record_failed_reward_payment(era, staker, amount);
+                            0
+                        }),
+                PayoutMode::Claim => {
+                    Self::credit_reward(candidate, staker, amount);
+                    0
+                },
+            }
+        }
+
+        /// Pays `amount` of reward earned by `staker` through `candidate` from the reward pot to
+        /// the reward destination of `staker`. Then restakes all of it if the destination is
+        /// `Restake`, or the `auto_compound` share of a nomination reward paid to the bonded
+        /// account.
+        /// Returns the extra weight consumed by restaking, or None if the transfer failed.
+        pub(crate) fn pay_reward(
+            reward_pot: &T::AccountId,
+            candidate: &T::AccountId,
+            staker: T::AccountId,
+            amount: BalanceOf<T>,
+            auto_compound: Percent,
+        ) -> Option<Weight> {
+            let destination = match Self::transfer_reward(reward_pot, &staker, amount) {
+                Ok(destination) => destination,
+                Err(error) => {
+                    log::error!("💔 Error paying staking reward: {:?}", error);
+                    Self::deposit_event(Event::ErrorPayingStakingReward {
+                        payee: staker,
+                        rewards: amount,
+                    });
+                    return None
+                },
+            };
+            Self::deposit_event(Event::Rewarded {
+                account: staker.clone(),
+                rewards: amount,
+                destination: destination.clone(),
+            });
+
+            let mut extra_weight = 0;
+            if &staker == candidate {
+                if destination == RewardDestination::Restake &&
+                    Self::candidate_compound(staker, amount)
+                {
+                    extra_weight += T::WeightInfo::candidate_bond_more();
+                }
+            } else {
+                let compound_amount = match destination {
+                    RewardDestination::Bonded => auto_compound * amount,
+                    RewardDestination::Restake => amount,
+                    RewardDestination::Account(_) => BalanceOf::<T>::zero(),
+                };
+                if Self::nomination_compound(candidate.clone(), staker, compound_amount) {
+                    extra_weight += T::WeightInfo::nominator_bond_more();
+                }
+            }
+            Some(extra_weight)
+        }
+
+        /// Transfers `amount` of reward earned by `staker` from the reward pot to the reward
+        /// destination of `staker` and releases it from `LockedEraPayout`.
+        /// Returns the destination it was paid to.
+        fn transfer_reward(
+            reward_pot: &T::AccountId,
+            staker: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<RewardDestination<T::AccountId>, DispatchError> {
+            let destination = <Payee<T>>::get(staker);
+            let payee = match &destination {
+                RewardDestination::Account(account) => account.clone(),
+                RewardDestination::Bonded | RewardDestination::Restake => staker.clone(),
+            };
+            T::Currency::transfer(reward_pot, &payee, amount, ExistenceRequirement::KeepAlive)?;
+            Self::liquid_note_reward(&payee, amount);
+
+            // Update storage with the amount we paid
+            <LockedEraPayout<T>>::mutate(|p| {
+                *p = p.saturating_sub(amount.into());
+            });
+            Ok(destination)
+        }
+
+        /// Queues the failed payment of `amount` of reward earned by `staker` in `era` to be
+        /// retried. The amount stays accounted in `LockedEraPayout` until it is resolved.
+        fn record_failed_reward_payment(era: EraIndex, staker: T::AccountId, amount: BalanceOf<T>) {
+            <FailedRewardPayments<T>>::mutate(staker, era, |payment| match payment {
+                Some(payment) => payment.amount = payment.amount.saturating_add(amount),
+                None => *payment = Some(FailedRewardPayment { amount, attempts: 1 }),
+            });
+        }
+
+        /// Retries as many failed reward payments as fit in `T::RewardPaymentRetryWeight`.
+        /// Returns the weight consumed.
+        fn retry_failed_reward_payments() -> Weight {
+            let retry_weight = T::WeightInfo::retry_failed_reward_payment();
+            let max_retries = T::RewardPaymentRetryWeight::get() / retry_weight.max(1);
+            // read the first failed payment
+            let mut weight = T::DbWeight::get().reads(1);
+            let payments =
+                <FailedRewardPayments<T>>::iter().take(max_retries as usize).collect::<Vec<_>>();
+            if payments.is_empty() {
+                return weight
+            }
+            let reward_pot_account_id = Self::compute_reward_pot_account_id();
+            for (staker, era, payment) in payments {
+                Self::retry_reward_payment(&reward_pot_account_id, era, staker, payment);
+                weight = weight.saturating_add(retry_weight);
+            }
+            weight
+        }
+
+        /// Retries the failed `payment` of reward earned by `staker` in `era`. Once
+        /// `T::MaxRewardPaymentAttempts` is reached the payment is abandoned and its amount is
+        /// released from `LockedEraPayout` to be paid out in a later era.
+        /// Retried payments are not restaked.
+        pub(crate) fn retry_reward_payment(
+            reward_pot: &T::AccountId,
+            era: EraIndex,
+            staker: T::AccountId,
+            mut payment: FailedRewardPayment<BalanceOf<T>>,
+        ) {
+            if let Err(error) = Self::pay_failed_reward(reward_pot, era, &staker, payment.amount) {
+                payment.attempts = payment.attempts.saturating_add(1);
+                if payment.attempts >= T::MaxRewardPaymentAttempts::get() {
+                    log::error!("💔 Abandoned staking reward payment: {:?}", error);
+                    <FailedRewardPayments<T>>::remove(&staker, era);
+                    <LockedEraPayout<T>>::mutate(|p| {
+                        *p = p.saturating_sub(payment.amount);
+                    });
+                    Self::deposit_event(Event::RewardPaymentAbandoned {
+                        era,
+                        account: staker,
+                        rewards: payment.amount,
+                    });
+                } else {
+                    log::error!("💔 Error retrying staking reward payment: {:?}", error);
+                    Self::deposit_event(Event::ErrorPayingStakingReward {
+                        payee: staker.clone(),
+                        rewards: payment.amount,
+                    });
+                    <FailedRewardPayments<T>>::insert(&staker, era, payment);
+                }
+            }
+        }
+
+        /// Pays the failed payment of `amount` of reward earned by `staker` in `era` and removes
+        /// it from the queue.
+        fn pay_failed_reward(
+            reward_pot: &T::AccountId,
+            era: EraIndex,
+            staker: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let destination = Self::transfer_reward(reward_pot, staker, amount)?;
+            <FailedRewardPayments<T>>::remove(staker, era);
+            Self::deposit_event(Event::RewardPaymentRetried {
+                era,
+                account: staker.clone(),
+                rewards: amount,
+                destination,
+            });
+            Ok(())
+        }
+
+        /// Credits `amount` of reward earned by `staker` through `candidate` to the claimable
+        /// rewards of `staker`. The amount stays accounted in `LockedEraPayout` until claimed.
+        fn credit_reward(candidate: &T::AccountId, staker: T::AccountId, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return
+            }
+            <ClaimableRewards<T>>::mutate(&staker, candidate, |credit| {
+                *credit = credit.saturating_add(amount)
+            });
+            Self::deposit_event(Event::RewardCredited {
+                account: staker,
+                candidate: candidate.clone(),
+                rewards: amount,
+            });
+        }
+
+        /// Pays the rewards credited to `staker`, through at most `candidate_count` candidates.
+        /// Credits that fail to be paid are kept to be claimed again.
+        pub(crate) fn staker_claim_rewards(
+            staker: T::AccountId,
+            candidate_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let credits = <ClaimableRewards<T>>::iter_prefix(&staker)
+                .take(candidate_count as usize)
+                .collect::<Vec<_>>();
+            ensure!(!credits.is_empty(), Error::<T>::NoRewardsToClaim);
+            let reward_pot_account_id = Self::compute_reward_pot_account_id();
+            for (candidate, amount) in credits {
+                let auto_compound = Self::nomination_auto_compound(&candidate, &staker);
+                if Self::pay_reward(
+                    &reward_pot_account_id,
+                    &candidate,
+                    staker.clone(),
+                    amount,
+                    auto_compound,
+                )
+                .is_some()
+                {
+                    <ClaimableRewards<T>>::remove(&staker, &candidate);
+                }
+            }
+            Ok(().into())
+        }
+
+        /// Clears the data kept for `account` while it stakes, once it is neither a candidate
+        /// nor a nominator anymore.
+        pub(crate) fn staker_exited(account: &T::AccountId) {
+            Self::controller_remove(account);
+            <LastNominationSwitch<T>>::remove(account);
+        }
+
+        /// Total rewards credited to `staker` that can be claimed
+        pub fn total_claimable_rewards(staker: &T::AccountId) -> BalanceOf<T> {
+            <ClaimableRewards<T>>::iter_prefix_values(staker)
+                .fold(BalanceOf::<T>::zero(), |total, credit| total.saturating_add(credit))
+        }
+
+        /// Compute the top `TotalSelected` candidates in the candidate pool and return
+        /// a vec of their AccountIds (sorted by account)
         pub fn compute_top_candidates() -> Vec<T::AccountId> {
-            let mut candidates = <CandidatePool<T>>::get().0;
-            // order candidates by stake (least to greatest so requires `rev()`)
-            candidates.sort_by(|a, b| a.amount.cmp(&b.amount));
             let top_n = <TotalSelected<T>>::get() as usize;
+            let min_collator_stk = <StakingParams<T>>::get().min_collator_stk;
             // choose the top TotalSelected qualified candidates, ordered by stake
-            let mut collators = candidates
+            let mut collators = Self::top_candidate_pool(top_n)
                 .into_iter()
-                .rev()
-                .take(top_n)
-                .filter(|x| x.amount >= T::MinCollatorStk::get())
+                .filter(|x| x.amount >= min_collator_stk)
                 .map(|x| x.owner)
                 .collect::<Vec<T::AccountId>>();
             collators.sort();
@@ -1376,8 +2947,15 @@ pub mod pallet {
         fn select_top_candidates(now: EraIndex) -> (u32, u32, BalanceOf<T>) {
             let (mut collator_count, mut nomination_count, mut total) =
                 (0u32, 0u32, BalanceOf::<T>::zero());
-            // choose the top TotalSelected qualified candidates, ordered by stake
-            let collators = Self::compute_top_candidates();
+            // choose the top TotalSelected qualified candidates, ordered by stake, unless the
+            // collators were planned with the session starting the era
+            let collators: Vec<T::AccountId> = match <QueuedCandidates<T>>::take() {
+                Some(queued) => queued
+                    .into_iter()
+                    .filter(|account| <CandidateInfo<T>>::contains_key(account))
+                    .collect(),
+                None => Self::compute_top_candidates(),
+            };
             if collators.is_empty() {
                 // SELECTION FAILED TO SELECT >=1 COLLATOR => select collators from previous era
                 let last_era = now.saturating_sub(1u32);
@@ -1408,8 +2986,16 @@ pub mod pallet {
 
             // snapshot exposure for era for weighting reward distribution
             for account in collators.iter() {
-                let state = <CandidateInfo<T>>::get(account)
+                let mut state = <CandidateInfo<T>>::get(account)
                     .expect("all members of CandidateQ must be candidates");
+                if let Some((old, new)) = state.apply_due_commission_change(now) {
+                    <CandidateInfo<T>>::insert(account, &state);
+                    Self::deposit_event(Event::CandidateCommissionSet {
+                        candidate: account.clone(),
+                        old,
+                        new,
+                    });
+                }
 
                 collator_count = collator_count.saturating_add(1u32);
                 nomination_count = nomination_count.saturating_add(state.nomination_count);
@@ -1422,6 +3008,7 @@ pub mod pallet {
                     bond: state.bond,
                     nominations: rewardable_nominations,
                     total: total_counted,
+                    commission: state.commission,
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
                 Self::deposit_event(Event::CollatorChosen {
@@ -1431,7 +3018,10 @@ pub mod pallet {
                 });
             }
             // insert canonical collator set
-            <SelectedCandidates<T>>::put(collators);
+            <SelectedCandidates<T>>::put(
+                BoundedVec::try_from(collators)
+                    .expect("no more collators than candidates in the bounded pool; qed"),
+            );
             (collator_count, nomination_count, total)
         }
 
@@ -1450,36 +3040,32 @@ pub mod pallet {
                 .map(|x| (x.nominator, x.action))
                 .collect::<BTreeMap<_, _>>();
             let mut uncounted_stake = BalanceOf::<T>::zero();
-            let rewardable_nominations = <TopNominations<T>>::get(collator)
+            let mut rewardable_nominations = <TopNominations<T>>::get(collator)
                 .expect("all members of CandidateQ must be candidates")
-                .nominations
-                .into_iter()
-                .map(|mut bond| {
-                    bond.amount = match requests.get(&bond.owner) {
-                        None => bond.amount,
-                        Some(NominationAction::Revoke(_)) => {
-                            log::warn!(
-                                "reward for nominator '{:?}' set to zero due to pending \
+                .nominations;
+            rewardable_nominations.iter_mut().for_each(|bond| {
+                bond.amount = match requests.get(&bond.owner) {
+                    None => bond.amount,
+                    Some(NominationAction::Revoke(_)) => {
+                        log::warn!(
+                            "reward for nominator '{:?}' set to zero due to pending \
 								revoke request",
-                                bond.owner
-                            );
-                            uncounted_stake = uncounted_stake.saturating_add(bond.amount);
-                            BalanceOf::<T>::zero()
-                        },
-                        Some(NominationAction::Decrease(amount)) => {
-                            log::warn!(
-                                "reward for nominator '{:?}' reduced by set amount due to pending \
+                            bond.owner
+                        );
+                        uncounted_stake = uncounted_stake.saturating_add(bond.amount);
+                        BalanceOf::<T>::zero()
+                    },
+                    Some(NominationAction::Decrease(amount)) => {
+                        log::warn!(
+                            "reward for nominator '{:?}' reduced by set amount due to pending \
 								decrease request",
-                                bond.owner
-                            );
-                            uncounted_stake = uncounted_stake.saturating_add(*amount);
-                            bond.amount.saturating_sub(*amount)
-                        },
-                    };
-
-                    bond
-                })
-                .collect();
+                            bond.owner
+                        );
+                        uncounted_stake = uncounted_stake.saturating_add(*amount);
+                        bond.amount.saturating_sub(*amount)
+                    },
+                };
+            });
             CountedNominations { uncounted_stake, rewardable_nominations }
         }
 
@@ -1496,17 +3082,115 @@ pub mod pallet {
             T::Currency::free_balance(&Self::compute_reward_pot_account_id())
                 .saturating_sub(T::Currency::minimum_balance())
         }
-    }
 
-    /// Keep track of number of authored blocks per authority, uncles are counted as well since
-    /// they're a valid proof of being online.
-    impl<T: Config + pallet_authorship::Config>
-        pallet_authorship::EventHandler<T::AccountId, T::BlockNumber> for Pallet<T>
-    {
+        /// The staking parameters given by the `Config` constants, in effect until governance
+        /// changes them.
+        pub fn initial_staking_params() -> StakingParameters<BalanceOf<T>> {
+            StakingParameters {
+                min_collator_stk: T::MinCollatorStk::get(),
+                min_candidate_stk: T::MinCandidateStk::get(),
+                min_nominator_stk: T::MinNominatorStk::get(),
+                min_nomination: T::MinNomination::get(),
+                leave_candidates_delay: T::LeaveCandidatesDelay::get(),
+                candidate_bond_less_delay: T::CandidateBondLessDelay::get(),
+                commission_change_delay: T::CommissionChangeDelay::get(),
+                leave_nominators_delay: T::LeaveNominatorsDelay::get(),
+                revoke_nomination_delay: T::RevokeNominationDelay::get(),
+                nomination_bond_less_delay: T::NominationBondLessDelay::get(),
+                reward_payment_delay: T::RewardPaymentDelay::get(),
+            }
+        }
+
+        /// Checks the sanity bounds of the staking parameters `params` replacing `current`.
+        fn ensure_valid_staking_params(
+            params: &StakingParameters<BalanceOf<T>>,
+            current: &StakingParameters<BalanceOf<T>>,
+        ) -> DispatchResult {
+            ensure!(
+                !params.min_collator_stk.is_zero() &&
+                    !params.min_candidate_stk.is_zero() &&
+                    !params.min_nominator_stk.is_zero() &&
+                    !params.min_nomination.is_zero() &&
+                    !params.reward_payment_delay.is_zero(),
+                Error::<T>::CannotSetBelowMin
+            );
+            ensure!(
+                params.min_nomination <= params.min_nominator_stk,
+                Error::<T>::MinNominationAboveMinNominatorStk
+            );
+            let slash_defer_duration = T::SlashDeferDuration::get();
+            ensure!(
+                [
+                    params.leave_candidates_delay,
+                    params.candidate_bond_less_delay,
+                    params.leave_nominators_delay,
+                    params.revoke_nomination_delay,
+                    params.nomination_bond_less_delay,
+                ]
+                .iter()
+                .all(|delay| *delay >= slash_defer_duration),
+                Error::<T>::DelayShorterThanSlashDeferDuration
+            );
+            ensure!(
+                params.reward_payment_delay >= current.reward_payment_delay,
+                Error::<T>::RewardPaymentDelayCannotDecrease
+            );
+            Ok(())
+        }
+
+        /// Ends the current era and starts the next one at block `now`: collators are paid and
+        /// selected for the new era.
+        /// Returns the weight consumed.
+        pub(crate) fn start_new_era(
+            era: &mut EraInfo<T::BlockNumber>,
+            now: T::BlockNumber,
+        ) -> Weight {
+            let (ended_era, ended_era_length) =
+                (era.current, now.saturating_sub(era.first).saturated_into::<u32>());
+            // mutate era
+            era.update(now);
+            // apply the staking parameters set during the ended era
+            if let Some(params) = <PendingStakingParams<T>>::take() {
+                <StakingParams<T>>::put(params);
+            }
+            // notify that new era begin
+            let mut weight = T::OnNewEra::on_new_era(era.current);
+            // idle the collators of the ended era that did not author enough blocks
+            weight =
+                weight.saturating_add(Self::check_collator_liveness(ended_era, ended_era_length));
+            // apply slashes deferred to this era before the stake is snapshot
+            weight = weight.saturating_add(Self::apply_unapplied_slashes(era.current));
+            // pay all stakers for T::RewardPaymentDelay eras ago
+            Self::prepare_staking_payouts(era.current);
+            // drop the snapshots no longer needed to pay or slash their era
+            weight = weight.saturating_add(Self::prune_expired_at_stake(era.current));
+            weight = weight.saturating_add(Self::prune_processed_offences(era.current));
+            // select top collator candidates for next era
+            let (collator_count, nomination_count, total_staked) =
+                Self::select_top_candidates(era.current);
+            // start next era
+            <Era<T>>::put(*era);
+            // snapshot total stake
+            <Staked<T>>::insert(era.current, <Total<T>>::get());
+            // mint the era inflation into the pot, alongside the fees collected there
+            Self::mint_era_inflation(era.current);
+            Self::deposit_event(Event::NewEra {
+                starting_block: era.first,
+                era: era.current,
+                selected_collators_number: collator_count,
+                total_balance: total_staked,
+            });
+            weight.saturating_add(T::WeightInfo::era_transition_on_initialize(
+                collator_count,
+                nomination_count,
+            ))
+        }
+
         /// Add reward points to block authors:
         /// * 20 points to the block producer for producing a block in the chain
-        fn note_author(author: T::AccountId) {
+        pub(crate) fn award_points_to_block_author(author: T::AccountId) {
             let now = <Era<T>>::get().current;
+            Self::note_authored_block(now, &author);
             let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
             <AwardedPts<T>>::insert(now, author, score_plus_20);
             <Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
@@ -1516,6 +3200,16 @@ pub mod pallet {
                 DispatchClass::Mandatory,
             );
         }
+    }
+
+    /// Keep track of number of authored blocks per authority, uncles are counted as well since
+    /// they're a valid proof of being online.
+    impl<T: Config + pallet_authorship::Config>
+        pallet_authorship::EventHandler<T::AccountId, T::BlockNumber> for Pallet<T>
+    {
+        fn note_author(author: T::AccountId) {
+            Self::award_points_to_block_author(author);
+        }
 
         fn note_uncle(_author: T::AccountId, _age: T::BlockNumber) {
             //TODO: can we ignore this?
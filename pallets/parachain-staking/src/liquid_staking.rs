@@ -0,0 +1,322 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Liquid staking, through which stake is represented by a fungible derivative
+//!
+//! The liquid staking account nominates the selected candidates like any nominator, with the
+//! stake deposited in exchange for the derivative asset `LiquidStakingAssetId`. Its rewards are
+//! restaked, so the stake each unit of the derivative is worth rises over time. Burning the
+//! derivative unbonds the stake it is worth through the scheduled requests of the liquid staking
+//! account, withdrawn once the request is due.
+//!
+//! The stake the derivative is worth is tracked in `LiquidStakedValue` as it is deposited,
+//! rewarded, slashed and unbonded. Funds sent to the liquid staking account by other means do not
+//! change the exchange rate. The stake unbonding is tracked in `LiquidUnbondingTotal`, shared by
+//! the points of the unbondings, and a slash of the liquid staking account is taken out of both
+//! pro rata.
+
+use crate::{
+    pallet::{
+        BalanceOf, CandidateInfo, Config, Era, EraIndex, Error, Event, LiquidAssetIdOf,
+        LiquidStakedValue, LiquidStrategy, LiquidUnbondingPoints, LiquidUnbondingTotal,
+        LiquidUnbondings, NominatorState, Pallet, Payee, SelectedCandidates, StakingParams,
+    },
+    pools::pro_rata,
+    types::RewardDestination,
+};
+use frame_support::{
+    dispatch::DispatchResultWithPostInfo,
+    ensure,
+    traits::{
+        tokens::fungibles::{Inspect, Mutate},
+        Currency, ExistenceRequirement, Get,
+    },
+    RuntimeDebug,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+    DispatchError, FixedPointNumber, FixedU128,
+};
+use sp_std::prelude::*;
+
+/// How the liquid staking account picks the selected candidate to bond new stake with
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum LiquidStakingStrategy {
+    /// The selected candidate it nominates with the least stake, spreading its stake equally
+    Equal,
+    /// The selected candidate with the least counted stake
+    LeastBacked,
+}
+
+impl Default for LiquidStakingStrategy {
+    fn default() -> LiquidStakingStrategy {
+        LiquidStakingStrategy::Equal
+    }
+}
+
+/// Stake of a liquid staker being unbonded from a candidate
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidUnbonding<AccountId, Balance> {
+    pub candidate: AccountId,
+    /// Stake unbonded, before any slash
+    pub amount: Balance,
+    /// Share of the stake of all the liquid stakers unbonding
+    pub points: Balance,
+    /// The era the request of the liquid staking account to unbond it is executable
+    pub when_executable: EraIndex,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account holding the stake of liquid staking.
+    pub fn liquid_staking_account() -> T::AccountId {
+        T::LiquidStakingPalletId::get().into_account_truncating()
+    }
+
+    /// Returns the asset of the derivative, or an error if liquid staking is disabled.
+    fn liquid_asset() -> Result<LiquidAssetIdOf<T>, DispatchError> {
+        T::LiquidStakingAssetId::get().ok_or_else(|| <Error<T>>::LiquidStakingDisabled.into())
+    }
+
+    /// Adds `amount` of reward paid to `payee` to the stake of the holders of the derivative, if
+    /// it is the liquid staking account.
+    pub(crate) fn liquid_note_reward(payee: &T::AccountId, amount: BalanceOf<T>) {
+        if payee == &Self::liquid_staking_account() {
+            <LiquidStakedValue<T>>::mutate(|value| *value = value.saturating_add(amount));
+        }
+    }
+
+    /// Removes `amount` slashed from `nominator` from the stake of the holders of the derivative
+    /// and the stake unbonding pro rata, if it is the liquid staking account.
+    pub(crate) fn liquid_note_slash(nominator: &T::AccountId, amount: BalanceOf<T>) {
+        if nominator != &Self::liquid_staking_account() {
+            return
+        }
+        let value = <LiquidStakedValue<T>>::get();
+        let unbonding = <LiquidUnbondingTotal<T>>::get();
+        let staked = value.saturating_add(unbonding);
+        let amount = amount.min(staked);
+        let from_unbonding = pro_rata(amount, unbonding, staked);
+        <LiquidUnbondingTotal<T>>::put(unbonding.saturating_sub(from_unbonding));
+        <LiquidStakedValue<T>>::put(value.saturating_sub(amount.saturating_sub(from_unbonding)));
+    }
+
+    /// Returns the points worth `amount` of the stake of all the liquid stakers unbonding.
+    fn liquid_unbonding_points_of(amount: BalanceOf<T>) -> BalanceOf<T> {
+        let points = <LiquidUnbondingPoints<T>>::get();
+        let unbonding = <LiquidUnbondingTotal<T>>::get();
+        if points.is_zero() || unbonding.is_zero() {
+            amount
+        } else {
+            pro_rata(amount, points, unbonding)
+        }
+    }
+
+    /// Returns the stake of the holders of the derivative which is not bonded in the
+    /// nominations of the liquid staking account, such as restaked rewards which could not be
+    /// bonded or the stake of nominations kicked.
+    fn liquid_idle_stake(account: &T::AccountId) -> BalanceOf<T> {
+        let (total, less_total) = <NominatorState<T>>::get(account)
+            .map_or((Zero::zero(), Zero::zero()), |state| (state.total, state.less_total));
+        // unbonding stake not in the pending requests was unbonded already, or dropped
+        let unbonded = <LiquidUnbondingTotal<T>>::get().saturating_sub(less_total);
+        T::Currency::free_balance(account)
+            .saturating_sub(total)
+            .saturating_sub(unbonded)
+    }
+
+    /// Returns the stake one unit of the derivative is worth, one before any is minted.
+    pub fn liquid_exchange_rate() -> FixedU128 {
+        let asset = match T::LiquidStakingAssetId::get() {
+            Some(asset) => asset,
+            None => return FixedU128::one(),
+        };
+        let issuance = T::LiquidStakingAssets::total_issuance(asset);
+        if issuance.is_zero() {
+            return FixedU128::one()
+        }
+        FixedU128::saturating_from_rational(
+            <LiquidStakedValue<T>>::get().saturated_into::<u128>(),
+            issuance.saturated_into::<u128>(),
+        )
+    }
+
+    /// Collects the rewards credited to the liquid staking account, restaked as they are paid.
+    fn collect_liquid_rewards(account: &T::AccountId) -> Result<(), DispatchError> {
+        if !Self::total_claimable_rewards(account).is_zero() {
+            Self::staker_claim_rewards(account.clone(), T::MaxNominationsPerNominator::get())?;
+        }
+        Ok(())
+    }
+
+    /// Bonds the idle stake of the liquid staking account with the selected candidate picked by
+    /// the `LiquidStrategy`. The stake stays idle if it cannot bond it, i.e. no candidate is
+    /// selected, or it is below the minimum of a new nomination and no nomination can take it.
+    fn bond_liquid_idle_stake(account: &T::AccountId) -> Result<(), DispatchError> {
+        let amount = Self::liquid_idle_stake(account);
+        if amount.is_zero() {
+            return Ok(())
+        }
+        let state = <NominatorState<T>>::get(account);
+        let nomination_count = state.as_ref().map_or(0, |state| state.nominations.0.len() as u32);
+        let params = <StakingParams<T>>::get();
+        let can_add = nomination_count < T::MaxNominationsPerNominator::get() &&
+            amount >= params.min_nomination &&
+            (state.is_some() || amount >= params.min_nominator_stk);
+        let strategy = <LiquidStrategy<T>>::get();
+        let candidate = <SelectedCandidates<T>>::get()
+            .into_iter()
+            .filter_map(|candidate| {
+                let bond = state.as_ref().and_then(|state| state.get_bond_amount(&candidate));
+                let eligible = match bond {
+                    Some(_) => !Self::nomination_request_revoke_exists(&candidate, account),
+                    None => can_add,
+                };
+                if !eligible {
+                    return None
+                }
+                let key = match strategy {
+                    LiquidStakingStrategy::Equal => bond.unwrap_or_else(Zero::zero),
+                    LiquidStakingStrategy::LeastBacked => <CandidateInfo<T>>::get(&candidate)
+                        .map_or(Zero::zero(), |info| info.total_counted),
+                };
+                Some((key, bond.is_some(), candidate))
+            })
+            .min_by_key(|(key, _, _)| *key);
+
+        match (candidate, state) {
+            (Some((_, true, candidate)), Some(mut state)) => {
+                state.increase_nomination::<T>(candidate, amount)?;
+            },
+            (Some((_, false, candidate)), _) => {
+                let candidate_nomination_count =
+                    <CandidateInfo<T>>::get(&candidate).map_or(0, |info| info.nomination_count);
+                Self::nominator_add_nomination(
+                    account.clone(),
+                    candidate,
+                    amount,
+                    candidate_nomination_count,
+                    nomination_count,
+                )
+                .map_err(|error| error.error)?;
+                if <Payee<T>>::get(account) != RewardDestination::Restake {
+                    <Payee<T>>::insert(account, RewardDestination::Restake);
+                }
+            },
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Mints the derivative worth `amount` of stake of `staker` at the current exchange rate,
+    /// and bonds the stake.
+    pub(crate) fn liquid_stake_deposit(
+        staker: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let asset = Self::liquid_asset()?;
+        let account = Self::liquid_staking_account();
+        Self::collect_liquid_rewards(&account)?;
+        let issuance = T::LiquidStakingAssets::total_issuance(asset);
+        let value = <LiquidStakedValue<T>>::get();
+        let minted = if issuance.is_zero() || value.is_zero() {
+            amount
+        } else {
+            pro_rata(amount, issuance, value)
+        };
+        ensure!(!minted.is_zero(), <Error<T>>::LiquidAmountTooLow);
+        T::Currency::transfer(&staker, &account, amount, ExistenceRequirement::KeepAlive)?;
+        <LiquidStakedValue<T>>::mutate(|value| *value = value.saturating_add(amount));
+        T::LiquidStakingAssets::mint_into(asset, &staker, minted)?;
+        Self::bond_liquid_idle_stake(&account)?;
+        Self::deposit_event(Event::LiquidStaked { staker, amount, minted });
+        Ok(().into())
+    }
+
+    /// Burns `burnt` derivative of `staker` and unbonds the stake it is worth. Idle stake is
+    /// withdrawn at once, bonded stake once the request of the liquid staking account to unbond
+    /// it is due.
+    pub(crate) fn liquid_unstake_burn(
+        staker: T::AccountId,
+        burnt: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let asset = Self::liquid_asset()?;
+        let account = Self::liquid_staking_account();
+        Self::collect_liquid_rewards(&account)?;
+        let issuance = T::LiquidStakingAssets::total_issuance(asset);
+        let amount = pro_rata(<LiquidStakedValue<T>>::get(), burnt, issuance);
+        ensure!(!amount.is_zero(), <Error<T>>::LiquidAmountTooLow);
+        ensure!(
+            T::LiquidStakingAssets::balance(asset, &staker) >= burnt,
+            <Error<T>>::InsufficientBalance
+        );
+        T::LiquidStakingAssets::burn_from(asset, &staker, burnt)?;
+        <LiquidStakedValue<T>>::mutate(|value| *value = value.saturating_sub(amount));
+
+        if amount <= Self::liquid_idle_stake(&account) {
+            T::Currency::transfer(&account, &staker, amount, ExistenceRequirement::AllowDeath)?;
+            Self::deposit_event(Event::LiquidWithdrawn { staker: staker.clone(), amount });
+        } else {
+            let (candidate, when_executable) = Self::schedule_stake_unbond(&account, amount)?
+                .ok_or(<Error<T>>::LiquidUnbondingUnavailable)?;
+            let points = Self::liquid_unbonding_points_of(amount);
+            <LiquidUnbondings<T>>::try_mutate(&staker, |unbondings| {
+                unbondings.try_push(LiquidUnbonding { candidate, amount, points, when_executable })
+            })
+            .map_err(|_| <Error<T>>::TooManyLiquidUnbondings)?;
+            <LiquidUnbondingTotal<T>>::mutate(|total| *total = total.saturating_add(amount));
+            <LiquidUnbondingPoints<T>>::mutate(|total| *total = total.saturating_add(points));
+        }
+        Self::deposit_event(Event::LiquidUnstaked { staker, burnt, amount });
+        Ok(().into())
+    }
+
+    /// Withdraws the stake of `staker` unbonded from liquid staking, executing the requests of
+    /// the liquid staking account which are due. The stake is the share of the stake unbonding
+    /// the points of the unbondings are worth, less any slash since they were unbonded.
+    pub(crate) fn liquid_withdraw_unbonded(staker: T::AccountId) -> DispatchResultWithPostInfo {
+        let account = Self::liquid_staking_account();
+        let now = <Era<T>>::get().current;
+        let mut unbondings = <LiquidUnbondings<T>>::get(&staker);
+        let due = unbondings
+            .iter()
+            .filter(|unbonding| unbonding.when_executable <= now)
+            .cloned()
+            .collect::<Vec<_>>();
+        ensure!(!due.is_empty(), <Error<T>>::NoLiquidUnbondingDue);
+        unbondings.retain(|unbonding| unbonding.when_executable > now);
+
+        let mut points = BalanceOf::<T>::zero();
+        for unbonding in due {
+            Self::execute_stake_unbond_if_due(&unbonding.candidate, &account, now)?;
+            points = points.saturating_add(unbonding.points);
+        }
+        let unbonding_total = <LiquidUnbondingTotal<T>>::get();
+        let unbonding_points = <LiquidUnbondingPoints<T>>::get();
+        let amount = pro_rata(unbonding_total, points, unbonding_points);
+        T::Currency::transfer(&account, &staker, amount, ExistenceRequirement::AllowDeath)?;
+        <LiquidUnbondingTotal<T>>::put(unbonding_total.saturating_sub(amount));
+        <LiquidUnbondingPoints<T>>::put(unbonding_points.saturating_sub(points));
+        if unbondings.is_empty() {
+            <LiquidUnbondings<T>>::remove(&staker);
+        } else {
+            <LiquidUnbondings<T>>::insert(&staker, unbondings);
+        }
+        Self::deposit_event(Event::LiquidWithdrawn { staker, amount });
+        Ok(().into())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Liveness tracking of the selected collators
+
+use crate::{
+    pallet::{AuthoredBlocks, Config, EraIndex, Event, Pallet, UnderperformingEras},
+    WeightInfo,
+};
+use frame_support::{traits::Get, weights::Weight};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+    /// Counts a block authored by `author` in era `now`.
+    pub(crate) fn note_authored_block(now: EraIndex, author: &T::AccountId) {
+        <AuthoredBlocks<T>>::mutate(now, author, |blocks| *blocks = blocks.saturating_add(1));
+    }
+
+    /// Checks the blocks authored by each collator selected for the ended `era`, which lasted
+    /// `era_length` blocks, against its equal share of the era. A collator authoring less than
+    /// `T::MinAuthoredBlocksRatio` of its share for `T::MaxUnderperformingEras` consecutive eras
+    /// is moved to idle and out of the candidate pool, until it calls `go_online`.
+    /// Returns the weight consumed.
+    pub(crate) fn check_collator_liveness(era: EraIndex, era_length: u32) -> Weight {
+        let selected = Self::selected_candidates();
+        let weight = T::WeightInfo::check_collator_liveness(selected.len() as u32);
+        let max_underperforming_eras = T::MaxUnderperformingEras::get();
+        let expected_blocks = era_length.checked_div(selected.len() as u32).unwrap_or_default();
+        let min_blocks = T::MinAuthoredBlocksRatio::get() * expected_blocks;
+
+        for collator in selected {
+            let authored_blocks = <AuthoredBlocks<T>>::take(era, &collator);
+            if max_underperforming_eras.is_zero() {
+                continue
+            }
+            if authored_blocks >= min_blocks {
+                <UnderperformingEras<T>>::remove(&collator);
+                continue
+            }
+            let underperforming_eras = <UnderperformingEras<T>>::get(&collator).saturating_add(1);
+            if underperforming_eras < max_underperforming_eras {
+                <UnderperformingEras<T>>::insert(&collator, underperforming_eras);
+                continue
+            }
+            <UnderperformingEras<T>>::remove(&collator);
+            if Self::chill_candidate(&collator) {
+                Self::deposit_event(Event::CandidateIdled {
+                    candidate: collator,
+                    era,
+                    authored_blocks,
+                    expected_blocks,
+                });
+            }
+        }
+        weight
+    }
+}
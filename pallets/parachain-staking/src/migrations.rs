@@ -0,0 +1,458 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Migrations
+//!
+//! With `try-runtime`, each migration checks the storage it migrated once upgraded, and the last
+//! one all the invariants of `do_try_state`.
+
+use crate::{
+    auto_compound::AutoCompoundConfig,
+    nomination_requests::ScheduledRequest,
+    set::BoundedOrderedSet,
+    types::{Bond, CandidateBondLessRequest, CapacityStatus, CollatorStatus, NominatorStatus},
+    AtStake, AutoCompoundingNominations, BalanceOf, BottomNominations, CandidateInfo,
+    CandidateMetadata, CollatorSnapshot, Config, DelayedPayout, DelayedPayouts, EraIndex,
+    NominationScheduledRequests, Nominations, Nominator, NominatorState, Pallet, Points,
+    SelectedCandidates, StakingParams, TopNominations, UnappliedSlash, UnappliedSlashes,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::{
+    ensure,
+    storage::{IterableStorageDoubleMap, IterableStorageMap},
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    storage_alias,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+    BoundedVec,
+};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::FullCodec;
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::{traits::Saturating, Perbill, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// Candidate metadata layout before the collator commission was added
+#[derive(Encode, Decode, RuntimeDebug)]
+struct CandidateMetadataV0<Balance> {
+    bond: Balance,
+    nomination_count: u32,
+    total_counted: Balance,
+    lowest_top_nomination_amount: Balance,
+    highest_bottom_nomination_amount: Balance,
+    lowest_bottom_nomination_amount: Balance,
+    top_capacity: CapacityStatus,
+    bottom_capacity: CapacityStatus,
+    request: Option<CandidateBondLessRequest<Balance>>,
+    status: CollatorStatus,
+}
+
+/// Collator snapshot layout before the collator commission was added
+#[derive(Encode, Decode, RuntimeDebug)]
+struct CollatorSnapshotV0<AccountId, Balance> {
+    bond: Balance,
+    nominations: Vec<Bond<AccountId, Balance>>,
+    total: Balance,
+}
+
+/// Collator snapshot layout from the collator commission until storage was bounded
+#[derive(Encode, Decode, RuntimeDebug)]
+struct CollatorSnapshotV2<AccountId, Balance> {
+    bond: Balance,
+    nominations: Vec<Bond<AccountId, Balance>>,
+    total: Balance,
+    commission: Perbill,
+}
+
+/// The collator snapshots before storage was bounded
+mod v2 {
+    use super::*;
+    use frame_support::Twox64Concat;
+
+    #[storage_alias]
+    pub(super) type AtStake<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Twox64Concat,
+        EraIndex,
+        Twox64Concat,
+        <T as frame_system::Config>::AccountId,
+        CollatorSnapshotV2<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+    >;
+}
+
+/// Returns whether every value of the map `M` decodes, i.e. is in its layout and within its
+/// bounds.
+#[cfg(feature = "try-runtime")]
+fn map_decodes<K: FullCodec, V: FullCodec, M: IterableStorageMap<K, V>>() -> bool {
+    M::iter_keys().count() == M::iter().count()
+}
+
+/// Returns whether every value of the double map `M` decodes.
+#[cfg(feature = "try-runtime")]
+fn double_map_decodes<K1, K2, V, M>() -> bool
+where
+    K1: FullCodec,
+    K2: FullCodec,
+    V: FullCodec,
+    M: IterableStorageDoubleMap<K1, K2, V>,
+{
+    M::iter_keys().count() == M::iter().count()
+}
+
+/// Migration to add the collator commission to `CandidateInfo` and `AtStake`.
+/// Existing candidates and the snapshots of eras not yet paid out get a zero commission, so
+/// rewards are split exactly as before until candidates opt in.
+pub struct AddCollatorCommission<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AddCollatorCommission<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            log::info!("AddCollatorCommission migration already applied, skipping");
+            return T::DbWeight::get().reads(1)
+        }
+        log::info!("Running AddCollatorCommission migration");
+        let mut migrated = 0u64;
+        <CandidateInfo<T>>::translate(|_, old: CandidateMetadataV0<BalanceOf<T>>| {
+            migrated = migrated.saturating_add(1);
+            Some(CandidateMetadata {
+                bond: old.bond,
+                nomination_count: old.nomination_count,
+                total_counted: old.total_counted,
+                lowest_top_nomination_amount: old.lowest_top_nomination_amount,
+                highest_bottom_nomination_amount: old.highest_bottom_nomination_amount,
+                lowest_bottom_nomination_amount: old.lowest_bottom_nomination_amount,
+                top_capacity: old.top_capacity,
+                bottom_capacity: old.bottom_capacity,
+                request: old.request,
+                status: old.status,
+                commission: Perbill::zero(),
+                commission_request: None,
+            })
+        });
+        <v2::AtStake<T>>::translate(
+            |_: EraIndex, _, old: CollatorSnapshotV0<T::AccountId, BalanceOf<T>>| {
+                migrated = migrated.saturating_add(1);
+                Some(CollatorSnapshotV2 {
+                    bond: old.bond,
+                    nominations: old.nominations,
+                    total: old.total,
+                    commission: Perbill::zero(),
+                })
+            },
+        );
+        StorageVersion::new(1).put::<Pallet<T>>();
+        log::info!("AddCollatorCommission migrated {:?} entries", migrated);
+        // one read and one write per migrated entry + storage version
+        T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "storage version not updated");
+        ensure!(
+            map_decodes::<_, _, CandidateInfo<T>>(),
+            "candidate metadata not migrated to the commission layout"
+        );
+        ensure!(
+            double_map_decodes::<_, _, _, v2::AtStake<T>>(),
+            "collator snapshot not migrated to the commission layout"
+        );
+        Ok(())
+    }
+}
+
+/// Migration to store the staking parameters, which were `Config` constants, on chain.
+/// They are seeded from the same constants, so staking is unchanged until governance sets them.
+pub struct SeedStakingParameters<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for SeedStakingParameters<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 2 {
+            log::info!("SeedStakingParameters migration already applied, skipping");
+            return T::DbWeight::get().reads(1)
+        }
+        log::info!("Running SeedStakingParameters migration");
+        if !<StakingParams<T>>::exists() {
+            <StakingParams<T>>::put(Pallet::<T>::initial_staking_params());
+        }
+        StorageVersion::new(2).put::<Pallet<T>>();
+        // storage version and staking parameters
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "storage version not updated");
+        ensure!(<StakingParams<T>>::exists(), "staking parameters not seeded");
+        Ok(())
+    }
+}
+
+/// The candidate pool before it was split into bags, sorted by account
+#[storage_alias]
+type CandidatePool<T: Config> = StorageValue<
+    Pallet<T>,
+    Vec<Bond<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+    ValueQuery,
+>;
+
+/// Top or bottom nominations layout before storage was bounded
+#[derive(Encode, Decode, RuntimeDebug)]
+struct NominationsV2<AccountId, Balance> {
+    nominations: Vec<Bond<AccountId, Balance>>,
+    total: Balance,
+}
+
+/// Nominator state layout before storage was bounded
+#[derive(Encode, Decode, RuntimeDebug)]
+struct NominatorV2<AccountId, Balance> {
+    id: AccountId,
+    nominations: Vec<Bond<AccountId, Balance>>,
+    total: Balance,
+    less_total: Balance,
+    status: NominatorStatus,
+}
+
+/// Unapplied slash layout before storage was bounded
+#[derive(Encode, Decode, RuntimeDebug)]
+struct UnappliedSlashV2<AccountId, Balance> {
+    collator: AccountId,
+    era: EraIndex,
+    own: Balance,
+    others: Vec<(AccountId, Balance)>,
+}
+
+/// Keeps the first `S` items, counting in `truncated` the lists which did not fit.
+fn truncate<Item, S: Get<u32>>(mut items: Vec<Item>, truncated: &mut u64) -> BoundedVec<Item, S> {
+    if items.len() > S::get() as usize {
+        items.truncate(S::get() as usize);
+        *truncated = truncated.saturating_add(1);
+    }
+    BoundedVec::try_from(items).expect("truncated to the bound; qed")
+}
+
+/// Migration to bound the pallet storage. Bounded vectors encode as the vectors they replace, so
+/// values within the bounds are unchanged. The few that are not are truncated:
+/// - the candidate pool keeps the `MaxCandidates` most backed candidates, the others go offline
+/// - the other lists keep their first items, which must not happen since the nomination limits were
+///   already enforced, and is logged as an error
+pub struct BoundStorage<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for BoundStorage<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 3 {
+            log::info!("BoundStorage migration already applied, skipping");
+            return T::DbWeight::get().reads(1)
+        }
+        log::info!("Running BoundStorage migration");
+        let (mut migrated, mut truncated) = (0u64, 0u64);
+
+        let mut pool = <CandidatePool<T>>::take();
+        let mut chilled = Vec::new();
+        if pool.len() > T::MaxCandidates::get() as usize {
+            pool.sort_by(|a, b| b.amount.cmp(&a.amount));
+            chilled = pool.split_off(T::MaxCandidates::get() as usize);
+            pool.sort();
+        }
+        <CandidatePool<T>>::put(pool);
+        for bond in chilled {
+            migrated = migrated.saturating_add(1);
+            Pallet::<T>::chill_candidate(&bond.owner);
+            log::warn!("Candidate {:?} beyond MaxCandidates went offline", bond.owner);
+        }
+        let _ = <SelectedCandidates<T>>::translate(|old: Option<Vec<T::AccountId>>| {
+            old.map(|selected| truncate(selected, &mut truncated))
+        });
+        migrated = migrated.saturating_add(2);
+
+        <TopNominations<T>>::translate(|_, old: NominationsV2<T::AccountId, BalanceOf<T>>| {
+            migrated = migrated.saturating_add(1);
+            Some(Nominations {
+                nominations: truncate(old.nominations, &mut truncated),
+                total: old.total,
+            })
+        });
+        <BottomNominations<T>>::translate(|_, old: NominationsV2<T::AccountId, BalanceOf<T>>| {
+            migrated = migrated.saturating_add(1);
+            Some(Nominations {
+                nominations: truncate(old.nominations, &mut truncated),
+                total: old.total,
+            })
+        });
+        <NominatorState<T>>::translate(|_, old: NominatorV2<T::AccountId, BalanceOf<T>>| {
+            migrated = migrated.saturating_add(1);
+            Some(Nominator {
+                id: old.id,
+                nominations: BoundedOrderedSet(truncate(old.nominations, &mut truncated)),
+                total: old.total,
+                less_total: old.less_total,
+                status: old.status,
+            })
+        });
+        <NominationScheduledRequests<T>>::translate(
+            |_, old: Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>| {
+                migrated = migrated.saturating_add(1);
+                Some(truncate(old, &mut truncated))
+            },
+        );
+        <AutoCompoundingNominations<T>>::translate(
+            |_, old: Vec<AutoCompoundConfig<T::AccountId>>| {
+                migrated = migrated.saturating_add(1);
+                Some(truncate(old, &mut truncated))
+            },
+        );
+        <AtStake<T>>::translate(
+            |_: EraIndex, _, old: CollatorSnapshotV2<T::AccountId, BalanceOf<T>>| {
+                migrated = migrated.saturating_add(1);
+                Some(CollatorSnapshot {
+                    bond: old.bond,
+                    nominations: truncate(old.nominations, &mut truncated),
+                    total: old.total,
+                    commission: old.commission,
+                })
+            },
+        );
+        <UnappliedSlashes<T>>::translate(
+            |_, old: Vec<UnappliedSlashV2<T::AccountId, BalanceOf<T>>>| {
+                migrated = migrated.saturating_add(1);
+                let slashes = old
+                    .into_iter()
+                    .map(|slash| UnappliedSlash {
+                        collator: slash.collator,
+                        era: slash.era,
+                        own: slash.own,
+                        others: truncate(slash.others, &mut truncated),
+                    })
+                    .collect();
+                Some(truncate(slashes, &mut truncated))
+            },
+        );
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        if truncated > 0 {
+            log::error!("BoundStorage truncated {:?} lists beyond their bound", truncated);
+        }
+        log::info!("BoundStorage migrated {:?} entries", migrated);
+        // one read and one write per migrated entry + storage version
+        T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "storage version not updated");
+        ensure!(
+            <CandidatePool<T>>::decode_len().unwrap_or(0) <= T::MaxCandidates::get() as usize &&
+                <SelectedCandidates<T>>::decode_len().unwrap_or(0) <=
+                    T::MaxCandidates::get() as usize,
+            "candidate pool beyond MaxCandidates"
+        );
+        ensure!(
+            map_decodes::<_, _, TopNominations<T>>() &&
+                map_decodes::<_, _, BottomNominations<T>>() &&
+                map_decodes::<_, _, NominatorState<T>>() &&
+                map_decodes::<_, _, NominationScheduledRequests<T>>() &&
+                map_decodes::<_, _, AutoCompoundingNominations<T>>() &&
+                double_map_decodes::<_, _, _, AtStake<T>>() &&
+                map_decodes::<_, _, UnappliedSlashes<T>>(),
+            "list beyond its bound"
+        );
+        Ok(())
+    }
+}
+
+/// Migration to move the candidate pool, a single sorted value, into the bags list of
+/// `CandidatePoolNodes`, so that updating the backing stake of a candidate no longer rewrites the
+/// whole pool.
+pub struct SplitCandidatePoolIntoBags<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for SplitCandidatePoolIntoBags<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 4 {
+            log::info!("SplitCandidatePoolIntoBags migration already applied, skipping");
+            return T::DbWeight::get().reads(1)
+        }
+        log::info!("Running SplitCandidatePoolIntoBags migration");
+        let mut migrated = 0u64;
+        for bond in <CandidatePool<T>>::take() {
+            migrated = migrated.saturating_add(1);
+            if let Err(error) = Pallet::<T>::candidate_pool_insert(&bond.owner, bond.amount) {
+                log::error!(
+                    "Moving candidate {:?} into the candidate pool bags failed with error: {:?}",
+                    bond.owner,
+                    error
+                );
+            }
+        }
+        StorageVersion::new(4).put::<Pallet<T>>();
+        log::info!("SplitCandidatePoolIntoBags migrated {:?} candidates", migrated);
+        // about three reads and four writes per candidate + old pool + storage version
+        T::DbWeight::get().reads_writes(
+            migrated.saturating_mul(3).saturating_add(2),
+            migrated.saturating_mul(4).saturating_add(2),
+        )
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "storage version not updated");
+        ensure!(!<CandidatePool<T>>::exists(), "candidate pool not moved into its bags");
+        Pallet::<T>::check_candidate_pool()
+    }
+}
+
+/// Delayed payout layout before the era points were moved into it
+#[derive(Encode, Decode, RuntimeDebug)]
+struct DelayedPayoutV4<Balance> {
+    era_issuance: Balance,
+    total_staking_reward: Balance,
+}
+
+/// Migration to move the points of the eras being paid out from `Points` into
+/// `DelayedPayouts`, which payouts read instead.
+pub struct MergePointsIntoDelayedPayouts<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MergePointsIntoDelayedPayouts<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 5 {
+            log::info!("MergePointsIntoDelayedPayouts migration already applied, skipping");
+            return T::DbWeight::get().reads(1)
+        }
+        log::info!("Running MergePointsIntoDelayedPayouts migration");
+        let mut migrated = 0u64;
+        <DelayedPayouts<T>>::translate(|era: EraIndex, old: DelayedPayoutV4<BalanceOf<T>>| {
+            migrated = migrated.saturating_add(1);
+            Some(DelayedPayout {
+                era_issuance: old.era_issuance,
+                total_staking_reward: old.total_staking_reward,
+                total_points: <Points<T>>::take(era),
+            })
+        });
+        StorageVersion::new(5).put::<Pallet<T>>();
+        log::info!("MergePointsIntoDelayedPayouts migrated {:?} payouts", migrated);
+        // two reads and two writes per migrated payout + storage version
+        T::DbWeight::get().reads_writes(
+            migrated.saturating_mul(2).saturating_add(1),
+            migrated.saturating_mul(2).saturating_add(1),
+        )
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 5, "storage version not updated");
+        ensure!(
+            <DelayedPayouts<T>>::iter_keys().all(|era| !<Points<T>>::contains_key(era)),
+            "points of an era being paid out not merged into its payout"
+        );
+        Pallet::<T>::do_try_state()
+    }
+}
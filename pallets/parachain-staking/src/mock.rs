@@ -16,12 +16,14 @@
 
 //! Test utilities
 use crate as pallet_parachain_staking;
-use crate::{pallet, AwardedPts, Config, Points, COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID};
+use crate::{
+    pallet, AwardedPts, Config, InflationInfo, Points, COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID,
+};
 use frame_support::{
     assert_ok, construct_runtime, parameter_types,
     traits::{
-        ConstU8, Currency, Everything, FindAuthor, GenesisBuild, Imbalance, LockIdentifier,
-        OnFinalize, OnInitialize, OnUnbalanced,
+        ConstU128, ConstU32, ConstU8, Currency, Everything, FindAuthor, GenesisBuild, Imbalance,
+        LockIdentifier, OnFinalize, OnInitialize, OnUnbalanced, Randomness,
     },
     weights::{DispatchClass, DispatchInfo, PostDispatchInfo, Weight, WeightToFee as WeightToFeeT},
     PalletId,
@@ -31,8 +33,9 @@ use pallet_transaction_payment::{ChargeTransactionPayment, CurrencyAdapter};
 use sp_core::H256;
 use sp_io;
 use sp_runtime::{
-    testing::Header,
-    traits::{BlakeTwo256, IdentityLookup, SignedExtension},
+    impl_opaque_keys,
+    testing::{Header, UintAuthorityId},
+    traits::{BlakeTwo256, ConvertInto, Hash, IdentityLookup, SignedExtension},
     Perbill, SaturatedConversion,
 };
 
@@ -52,8 +55,10 @@ construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
         ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
         Authorship: pallet_authorship::{Pallet, Call, Storage, Inherent},
+        Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>, Config},
     }
 );
@@ -115,7 +120,7 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 parameter_types! {
-    pub const ExistentialDeposit: u128 = 0;
+    pub static ExistentialDeposit: u128 = 0;
 }
 impl pallet_balances::Config for Test {
     type MaxReserves = ();
@@ -129,6 +134,26 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+    type Event = Event;
+    type Balance = Balance;
+    type AssetId = u32;
+    type Currency = Balances;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+}
+
+/// Asset of the liquid staking derivative
+pub const LIQUID_ASSET: u32 = 7;
+
 pub struct Author4;
 impl FindAuthor<u64> for Author4 {
     fn find_author<'a, I>(_digests: I) -> Option<u64>
@@ -146,16 +171,50 @@ impl pallet_authorship::Config for Test {
     type EventHandler = ParachainStaking;
 }
 
+impl_opaque_keys! {
+    pub struct MockSessionKeys {
+        pub dummy: UintAuthorityId,
+    }
+}
+
+parameter_types! {
+    /// Sessions only rotate in the tests setting their length
+    pub static SessionPeriod: u64 = u64::MAX;
+    pub const SessionOffset: u64 = 0;
+}
+
+impl pallet_session::Config for Test {
+    type Event = Event;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type SessionManager = ParachainStaking;
+    type SessionHandler = pallet_session::TestSessionHandler;
+    type Keys = MockSessionKeys;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const MinBlocksPerEra: u32 = 3;
     pub const DefaultBlocksPerEra: u32 = 5;
     pub const LeaveCandidatesDelay: u32 = 2;
     pub const CandidateBondLessDelay: u32 = 2;
+    pub const CommissionChangeDelay: u32 = 2;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
     pub const LeaveNominatorsDelay: u32 = 2;
     pub const RevokeNominationDelay: u32 = 2;
     pub const NominationBondLessDelay: u32 = 2;
+    pub const NominationSwitchCooldown: u32 = 2;
     pub const RewardPaymentDelay: u32 = 2;
+    pub const MaxRewardPaymentAttempts: u32 = 3;
+    pub const SlashDeferDuration: u32 = 2;
+    pub const SlashableEras: u32 = 4;
+    pub const MinAuthoredBlocksRatio: Perbill = Perbill::from_percent(50);
+    pub static MaxUnderperformingEras: u32 = 0;
+    pub static SessionsPerEra: u32 = 0;
     pub const MinSelectedCandidates: u32 = 5;
+    pub static MaxCandidates: u32 = 20;
     pub const MaxTopNominationsPerCandidate: u32 = 4;
     pub const MaxBottomNominationsPerCandidate: u32 = 4;
     pub const MaxNominationsPerNominator: u32 = 4;
@@ -163,6 +222,20 @@ parameter_types! {
     pub const MinNominatorStk: u128 = 5;
     pub const MinNomination: u128 = 3;
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
+    pub const PoolPalletId: PalletId = PalletId(*b"av/pools");
+    pub const MinPoolJoin: u128 = 1;
+    pub const MaxPoolUnbonding: u32 = 4;
+    pub static LiquidStakingAssetId: Option<u32> = Some(LIQUID_ASSET);
+    pub const LiquidStakingPalletId: PalletId = PalletId(*b"av/lqstk");
+    pub const MaxLiquidUnbonding: u32 = 4;
+    pub const ProfileDepositBase: u128 = 5;
+    pub const ProfileDepositPerByte: u128 = 1;
+    pub const MaxProfileFieldLength: u32 = 16;
+    pub const MaxNominationAllowlist: u32 = 4;
+    pub static RewardPaymentRetryWeight: Weight =
+        2 * <() as crate::weights::WeightInfo>::retry_failed_reward_payment();
+    pub static RewardPayoutWeight: Weight =
+        <() as crate::weights::WeightInfo>::pay_one_collator_reward(4);
 }
 impl Config for Test {
     type Event = Event;
@@ -172,11 +245,27 @@ impl Config for Test {
     type DefaultBlocksPerEra = DefaultBlocksPerEra;
     type LeaveCandidatesDelay = LeaveCandidatesDelay;
     type CandidateBondLessDelay = CandidateBondLessDelay;
+    type CommissionChangeDelay = CommissionChangeDelay;
+    type MaxCommission = MaxCommission;
     type LeaveNominatorsDelay = LeaveNominatorsDelay;
     type RevokeNominationDelay = RevokeNominationDelay;
     type NominationBondLessDelay = NominationBondLessDelay;
+    type NominationSwitchCooldown = NominationSwitchCooldown;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type MaxRewardPaymentAttempts = MaxRewardPaymentAttempts;
+    type RewardPaymentRetryWeight = RewardPaymentRetryWeight;
+    type RewardPayoutWeight = RewardPayoutWeight;
+    type SlashDeferDuration = SlashDeferDuration;
+    type SlashableEras = SlashableEras;
+    type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type Slash = SlashToTreasury;
+    type MinAuthoredBlocksRatio = MinAuthoredBlocksRatio;
+    type MaxUnderperformingEras = MaxUnderperformingEras;
+    type RandomnessSource = BlockRandomness;
+    type SessionsPerEra = SessionsPerEra;
+    type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
     type MinSelectedCandidates = MinSelectedCandidates;
+    type MaxCandidates = MaxCandidates;
     type MaxTopNominationsPerCandidate = MaxTopNominationsPerCandidate;
     type MaxBottomNominationsPerCandidate = MaxBottomNominationsPerCandidate;
     type MaxNominationsPerNominator = MaxNominationsPerNominator;
@@ -185,6 +274,17 @@ impl Config for Test {
     type MinNominatorStk = MinNominatorStk;
     type MinNomination = MinNomination;
     type RewardPotId = RewardPotId;
+    type PoolPalletId = PoolPalletId;
+    type MinPoolJoin = MinPoolJoin;
+    type MaxPoolUnbonding = MaxPoolUnbonding;
+    type LiquidStakingAssets = Assets;
+    type LiquidStakingAssetId = LiquidStakingAssetId;
+    type LiquidStakingPalletId = LiquidStakingPalletId;
+    type MaxLiquidUnbonding = MaxLiquidUnbonding;
+    type ProfileDepositBase = ProfileDepositBase;
+    type ProfileDepositPerByte = ProfileDepositPerByte;
+    type MaxProfileFieldLength = MaxProfileFieldLength;
+    type MaxNominationAllowlist = MaxNominationAllowlist;
     type OnCollatorPayout = ();
     type OnNewEra = ();
     type WeightInfo = ();
@@ -195,6 +295,25 @@ parameter_types! {
     pub static TransactionByteFee: u128 = 0u128;
 }
 
+/// Randomness derived from the subject and the current block number
+pub struct BlockRandomness;
+impl Randomness<H256, BlockNumber> for BlockRandomness {
+    fn random(subject: &[u8]) -> (H256, BlockNumber) {
+        let block_number = System::block_number();
+        (BlakeTwo256::hash_of(&(subject, block_number)), block_number)
+    }
+}
+
+/// Account receiving the funds slashed from stakers
+pub const SLASH_TREASURY: AccountId = 999;
+
+pub struct SlashToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for SlashToTreasury {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+        Balances::resolve_creating(&SLASH_TREASURY, amount);
+    }
+}
+
 pub struct DealWithFees;
 impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for DealWithFees {
     fn on_unbalanceds<B>(
@@ -243,11 +362,21 @@ pub(crate) struct ExtBuilder {
     collators: Vec<(AccountId, Balance)>,
     // [nominator, collator, nomination_amount]
     nominations: Vec<(AccountId, AccountId, Balance)>,
+    // inflation config
+    inflation: InflationInfo,
+    // accounts with session keys
+    session_keys: Vec<AccountId>,
 }
 
 impl Default for ExtBuilder {
     fn default() -> ExtBuilder {
-        ExtBuilder { balances: vec![], nominations: vec![], collators: vec![] }
+        ExtBuilder {
+            balances: vec![],
+            nominations: vec![],
+            collators: vec![],
+            inflation: Default::default(),
+            session_keys: vec![],
+        }
     }
 }
 
@@ -270,6 +399,16 @@ impl ExtBuilder {
         self
     }
 
+    pub(crate) fn with_inflation(mut self, inflation: InflationInfo) -> Self {
+        self.inflation = inflation;
+        self
+    }
+
+    pub(crate) fn with_session_keys(mut self, session_keys: Vec<AccountId>) -> Self {
+        self.session_keys = session_keys;
+        self
+    }
+
     pub(crate) fn build(self) -> sp_io::TestExternalities {
         let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Test>()
@@ -278,12 +417,34 @@ impl ExtBuilder {
         pallet_balances::GenesisConfig::<Test> { balances: self.balances }
             .assimilate_storage(&mut t)
             .expect("Pallet balances storage can be assimilated");
+        pallet_assets::GenesisConfig::<Test> {
+            assets: vec![(LIQUID_ASSET, 0, true, 1)],
+            metadata: vec![],
+            accounts: vec![],
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet assets storage can be assimilated");
         pallet_parachain_staking::GenesisConfig::<Test> {
             candidates: self.collators,
             nominations: self.nominations,
+            inflation_config: self.inflation,
         }
         .assimilate_storage(&mut t)
         .expect("Parachain Staking's storage can be assimilated");
+        // the genesis sessions need collators, so the tests without keys skip them
+        if !self.session_keys.is_empty() {
+            pallet_session::GenesisConfig::<Test> {
+                keys: self
+                    .session_keys
+                    .into_iter()
+                    .map(|account| {
+                        (account, account, MockSessionKeys { dummy: UintAuthorityId(account) })
+                    })
+                    .collect(),
+            }
+            .assimilate_storage(&mut t)
+            .expect("Pallet session storage can be assimilated");
+        }
 
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
@@ -299,6 +460,7 @@ pub(crate) fn roll_one_block() -> u64 {
     System::on_initialize(System::block_number());
     Balances::on_initialize(System::block_number());
     ParachainStaking::on_initialize(System::block_number());
+    Session::on_initialize(System::block_number());
     System::block_number()
 }
 
@@ -462,6 +624,11 @@ pub(crate) fn set_author(era: u32, acc: u64, pts: u32) {
     <AwardedPts<Test>>::mutate(era, acc, |p| *p += pts);
 }
 
+/// fn to query the amount `nominator` nominates `candidate` with
+pub(crate) fn nomination_amount(nominator: AccountId, candidate: AccountId) -> Option<Balance> {
+    ParachainStaking::nominator_state(nominator).and_then(|state| state.get_bond_amount(&candidate))
+}
+
 /// fn to query the lock amount
 pub(crate) fn query_lock_amount(account_id: u64, id: LockIdentifier) -> Option<Balance> {
     for lock in Balances::locks(&account_id) {
@@ -0,0 +1,154 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Nomination policies set by candidates
+//!
+//! A candidate can restrict the nominations it accepts with its own minimum nomination, a
+//! maximum number of nominators and an allowlist of nominators. The policy is checked when a
+//! nomination is added to the candidate, along with the `accepting_nominations` of its operator
+//! profile. Nominations added before the policy are grandfathered: they are kept as they are and
+//! can still be increased.
+
+use crate::pallet::{
+    BalanceOf, Config, Error, Event, NominationPolicies, OperatorProfiles, Pallet,
+};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchResult;
+use sp_std::{fmt::Debug, prelude::*};
+
+/// Restrictions of a candidate on the nominations it accepts
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxAllowlist))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct NominationPolicy<AccountId, Balance, MaxAllowlist: Get<u32>> {
+    /// Minimum amount of a new nomination, on top of `MinNomination`
+    pub min_nomination: Option<Balance>,
+    /// Maximum number of nominations, top and bottom
+    pub max_nominators: Option<u32>,
+    /// Sorted accounts allowed to nominate, anyone if None
+    pub allowlist: Option<BoundedVec<AccountId, MaxAllowlist>>,
+}
+
+impl<A: Clone, B: Clone, S: Get<u32>> Clone for NominationPolicy<A, B, S> {
+    fn clone(&self) -> Self {
+        NominationPolicy {
+            min_nomination: self.min_nomination.clone(),
+            max_nominators: self.max_nominators,
+            allowlist: self.allowlist.clone(),
+        }
+    }
+}
+
+impl<A: PartialEq, B: PartialEq, S: Get<u32>> PartialEq for NominationPolicy<A, B, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.min_nomination == other.min_nomination &&
+            self.max_nominators == other.max_nominators &&
+            self.allowlist == other.allowlist
+    }
+}
+
+impl<A: Eq, B: Eq, S: Get<u32>> Eq for NominationPolicy<A, B, S> {}
+
+impl<A: Debug, B: Debug, S: Get<u32>> Debug for NominationPolicy<A, B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+        f.debug_struct("NominationPolicy")
+            .field("min_nomination", &self.min_nomination)
+            .field("max_nominators", &self.max_nominators)
+            .field("allowlist", &self.allowlist)
+            .finish()
+    }
+}
+
+impl<A: Ord, B, S: Get<u32>> NominationPolicy<A, B, S> {
+    /// Whether `nominator` is on the allowlist, or there is none
+    pub fn allows(&self, nominator: &A) -> bool {
+        self.allowlist
+            .as_ref()
+            .map_or(true, |allowlist| allowlist.binary_search(nominator).is_ok())
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks a new nomination of `amount` from `nominator` against the policy of `candidate`,
+    /// which has `nomination_count` nominations before it, and that its operator profile does not
+    /// turn new nominations away.
+    pub(crate) fn nomination_policy_check_new(
+        candidate: &T::AccountId,
+        nominator: &T::AccountId,
+        amount: BalanceOf<T>,
+        nomination_count: u32,
+    ) -> DispatchResult {
+        let accepting =
+            <OperatorProfiles<T>>::get(candidate).and_then(|profile| profile.accepting_nominations);
+        ensure!(accepting != Some(false), Error::<T>::CandidateNotAcceptingNominations);
+        if let Some(policy) = <NominationPolicies<T>>::get(candidate) {
+            ensure!(policy.allows(nominator), Error::<T>::NominatorNotAllowed);
+            ensure!(
+                policy.max_nominators.map_or(true, |max| nomination_count < max),
+                Error::<T>::CandidateNominatorsFull
+            );
+            ensure!(
+                policy.min_nomination.map_or(true, |min| amount >= min),
+                Error::<T>::NominationBelowCandidateMin
+            );
+        }
+        Ok(())
+    }
+
+    /// Sets the nomination policy of `candidate`, removing it if it sets nothing.
+    pub(crate) fn nomination_policy_set(
+        candidate: T::AccountId,
+        min_nomination: Option<BalanceOf<T>>,
+        max_nominators: Option<u32>,
+        allowlist: Option<Vec<T::AccountId>>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(Self::is_candidate(&candidate), Error::<T>::CandidateDNE);
+        let allowlist = allowlist
+            .map(|mut allowlist| {
+                allowlist.sort();
+                allowlist.dedup();
+                BoundedVec::try_from(allowlist).map_err(|_| Error::<T>::AllowlistTooLong)
+            })
+            .transpose()?;
+        let allowlist_len = allowlist.as_ref().map(|allowlist| allowlist.len() as u32);
+        let policy = NominationPolicy { min_nomination, max_nominators, allowlist };
+        ensure!(
+            <NominationPolicies<T>>::get(&candidate).as_ref() != Some(&policy),
+            Error::<T>::NoWritingSameValue
+        );
+        if policy.min_nomination.is_none() &&
+            policy.max_nominators.is_none() &&
+            policy.allowlist.is_none()
+        {
+            ensure!(
+                <NominationPolicies<T>>::contains_key(&candidate),
+                Error::<T>::NoWritingSameValue
+            );
+            <NominationPolicies<T>>::remove(&candidate);
+        } else {
+            <NominationPolicies<T>>::insert(&candidate, policy);
+        }
+        Self::deposit_event(Event::NominationPolicySet {
+            candidate,
+            min_nomination,
+            max_nominators,
+            allowlist_len,
+        });
+        Ok(().into())
+    }
+}
@@ -19,18 +19,21 @@
 use crate::{
     pallet::{
         BalanceOf, CandidateInfo, Config, Era, EraIndex, Error, Event, NominationScheduledRequests,
-        NominatorState, Pallet, Total,
+        NominatorState, Pallet, StakingParams, Total,
     },
+    types::AddGet,
     Nominator, NominatorStatus,
 };
-use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get, RuntimeDebug};
-use parity_scale_codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, BoundedVec, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::traits::Saturating;
 use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a nomination
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+#[derive(
+    Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord, MaxEncodedLen,
+)]
 pub enum NominationAction<Balance> {
     Revoke(Balance),
     Decrease(Balance),
@@ -48,7 +51,9 @@ impl<Balance: Copy> NominationAction<Balance> {
 
 /// Represents a scheduled request that define a [NominationAction]. The request is executable
 /// iff the provided [EraIndex] is achieved.
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
+#[derive(
+    Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord, MaxEncodedLen,
+)]
 pub struct ScheduledRequest<AccountId, Balance> {
     pub nominator: AccountId,
     pub when_executable: EraIndex,
@@ -87,12 +92,14 @@ impl<T: Config> Pallet<T> {
 
         let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::NominationDNE)?;
         let now = <Era<T>>::get().current;
-        let when = now.saturating_add(T::RevokeNominationDelay::get());
-        scheduled_requests.push(ScheduledRequest {
-            nominator: nominator.clone(),
-            action: NominationAction::Revoke(bonded_amount),
-            when_executable: when,
-        });
+        let when = now.saturating_add(<StakingParams<T>>::get().revoke_nomination_delay);
+        scheduled_requests
+            .try_push(ScheduledRequest {
+                nominator: nominator.clone(),
+                action: NominationAction::Revoke(bonded_amount),
+                when_executable: when,
+            })
+            .map_err(|_| <Error<T>>::ExceedMaxNominationsPerNominator)?;
         state.less_total = state.less_total.saturating_add(bonded_amount);
         <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
         <NominatorState<T>>::insert(nominator.clone(), state);
@@ -123,21 +130,27 @@ impl<T: Config> Pallet<T> {
         let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::NominationDNE)?;
         ensure!(bonded_amount > decrease_amount, <Error<T>>::NominatorBondBelowMin);
         let new_amount: BalanceOf<T> = (bonded_amount - decrease_amount).into();
-        ensure!(new_amount >= T::MinNomination::get(), <Error<T>>::NominationBelowMin);
+        ensure!(
+            new_amount >= <StakingParams<T>>::get().min_nomination,
+            <Error<T>>::NominationBelowMin
+        );
 
         // Net Total is total after pending orders are executed
         let net_total = state.total().saturating_sub(state.less_total);
         // Net Total is always >= MinNominatorStk
-        let max_subtracted_amount = net_total.saturating_sub(T::MinNominatorStk::get().into());
+        let max_subtracted_amount =
+            net_total.saturating_sub(<StakingParams<T>>::get().min_nominator_stk.into());
         ensure!(decrease_amount <= max_subtracted_amount, <Error<T>>::NominatorBondBelowMin);
 
         let now = <Era<T>>::get().current;
-        let when = now.saturating_add(T::RevokeNominationDelay::get());
-        scheduled_requests.push(ScheduledRequest {
-            nominator: nominator.clone(),
-            action: NominationAction::Decrease(decrease_amount),
-            when_executable: when,
-        });
+        let when = now.saturating_add(<StakingParams<T>>::get().nomination_bond_less_delay);
+        scheduled_requests
+            .try_push(ScheduledRequest {
+                nominator: nominator.clone(),
+                action: NominationAction::Decrease(decrease_amount),
+                when_executable: when,
+            })
+            .map_err(|_| <Error<T>>::ExceedMaxNominationsPerNominator)?;
         state.less_total = state.less_total.saturating_add(decrease_amount);
         <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
         <NominatorState<T>>::insert(nominator.clone(), state);
@@ -176,8 +189,11 @@ impl<T: Config> Pallet<T> {
 
     fn cancel_request_with_state(
         nominator: &T::AccountId,
-        state: &mut Nominator<T::AccountId, BalanceOf<T>>,
-        scheduled_requests: &mut Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
+        state: &mut Nominator<T::AccountId, BalanceOf<T>, T::MaxNominationsPerNominator>,
+        scheduled_requests: &mut BoundedVec<
+            ScheduledRequest<T::AccountId, BalanceOf<T>>,
+            AddGet<T::MaxTopNominationsPerCandidate, T::MaxBottomNominationsPerCandidate>,
+        >,
     ) -> Option<ScheduledRequest<T::AccountId, BalanceOf<T>>> {
         let request_idx = scheduled_requests.iter().position(|req| &req.nominator == nominator)?;
 
@@ -210,7 +226,10 @@ impl<T: Config> Pallet<T> {
                     true
                 } else {
                     ensure!(
-                        state.total().saturating_sub(T::MinNominatorStk::get().into()) >= amount,
+                        state
+                            .total()
+                            .saturating_sub(<StakingParams<T>>::get().min_nominator_stk.into()) >=
+                            amount,
                         <Error<T>>::NominatorBondBelowMin
                     );
                     false
@@ -234,6 +253,7 @@ impl<T: Config> Pallet<T> {
                 <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
                 if leaving {
                     <NominatorState<T>>::remove(&nominator);
+                    Self::staker_exited(&nominator);
                     Self::deposit_event(Event::NominatorLeft {
                         nominator,
                         unstaked_amount: amount,
@@ -249,7 +269,7 @@ impl<T: Config> Pallet<T> {
                 state.less_total = state.less_total.saturating_sub(amount);
 
                 // decrease nomination
-                for bond in &mut state.nominations.0 {
+                for bond in state.nominations.0.iter_mut() {
                     if bond.owner == collator {
                         return if bond.amount > amount {
                             let amount_before: BalanceOf<T> = bond.amount.into();
@@ -260,11 +280,11 @@ impl<T: Config> Pallet<T> {
                             state.total_sub_if::<T, _>(amount, |total| {
                                 let new_total: BalanceOf<T> = total.into();
                                 ensure!(
-                                    new_total >= T::MinNomination::get(),
+                                    new_total >= <StakingParams<T>>::get().min_nomination,
                                     <Error<T>>::NominationBelowMin
                                 );
                                 ensure!(
-                                    new_total >= T::MinNominatorStk::get(),
+                                    new_total >= <StakingParams<T>>::get().min_nominator_stk,
                                     <Error<T>>::NominatorBondBelowMin
                                 );
 
@@ -313,7 +333,7 @@ impl<T: Config> Pallet<T> {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
         let mut updated_scheduled_requests = vec![];
         let now = <Era<T>>::get().current;
-        let when = now.saturating_add(T::LeaveNominatorsDelay::get());
+        let when = now.saturating_add(<StakingParams<T>>::get().leave_nominators_delay);
 
         // lazy migration for NominatorStatus::Leaving
         #[allow(deprecated)]
@@ -345,7 +365,9 @@ impl<T: Config> Pallet<T> {
                 },
             };
 
-            scheduled_requests.push(request);
+            scheduled_requests
+                .try_push(request)
+                .map_err(|_| <Error<T>>::ExceedMaxNominationsPerNominator)?;
             state.less_total = state.less_total.saturating_add(bonded_amount);
             updated_scheduled_requests.push((collator, scheduled_requests));
         }
@@ -388,7 +410,7 @@ impl<T: Config> Pallet<T> {
         }
 
         // pre-validate that all nominations have a Revoke request.
-        for bond in &state.nominations.0 {
+        for bond in state.nominations.0.iter() {
             let collator = bond.owner.clone();
             let scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
             scheduled_requests
@@ -453,13 +475,14 @@ impl<T: Config> Pallet<T> {
                 Self::nomination_remove_request_with_state(&bond.owner, &nominator, &mut state);
             }
             <NominatorState<T>>::remove(&nominator);
+            Self::staker_exited(&nominator);
             Self::deposit_event(Event::NominatorLeft { nominator, unstaked_amount: state.total });
             return Ok(().into())
         }
 
         let mut validated_scheduled_requests = vec![];
         // pre-validate that all nominations have a Revoke request that can be executed now.
-        for bond in &state.nominations.0 {
+        for bond in state.nominations.0.iter() {
             let scheduled_requests = <NominationScheduledRequests<T>>::get(&bond.owner);
             let request_idx = scheduled_requests
                 .iter()
@@ -506,6 +529,7 @@ impl<T: Config> Pallet<T> {
 
         Self::deposit_event(Event::NominatorLeft { nominator: nominator.clone(), unstaked_amount });
         <NominatorState<T>>::remove(&nominator);
+        Self::staker_exited(&nominator);
 
         Ok(().into())
     }
@@ -515,7 +539,7 @@ impl<T: Config> Pallet<T> {
     pub(crate) fn nomination_remove_request_with_state(
         collator: &T::AccountId,
         nominator: &T::AccountId,
-        state: &mut Nominator<T::AccountId, BalanceOf<T>>,
+        state: &mut Nominator<T::AccountId, BalanceOf<T>, T::MaxNominationsPerNominator>,
     ) {
         let mut scheduled_requests = <NominationScheduledRequests<T>>::get(collator);
 
@@ -552,18 +576,20 @@ impl<T: Config> Pallet<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{mock::Test, set::OrderedSet, Bond};
+    use crate::{mock::Test, set::BoundedOrderedSet, Bond};
+    use frame_support::bounded_vec;
 
     #[test]
     fn test_cancel_request_with_state_removes_request_for_correct_nominator_and_updates_state() {
         let mut state = Nominator {
             id: 1,
-            nominations: OrderedSet::from(vec![Bond { amount: 100, owner: 2 }]),
+            nominations: BoundedOrderedSet::try_from_vec(vec![Bond { amount: 100, owner: 2 }])
+                .unwrap(),
             total: 100,
             less_total: 100,
             status: crate::NominatorStatus::Active,
         };
-        let mut scheduled_requests = vec![
+        let mut scheduled_requests = bounded_vec![
             ScheduledRequest {
                 nominator: 1,
                 when_executable: 1,
@@ -598,7 +624,8 @@ mod tests {
             state,
             Nominator {
                 id: 1,
-                nominations: OrderedSet::from(vec![Bond { amount: 100, owner: 2 }]),
+                nominations: BoundedOrderedSet::try_from_vec(vec![Bond { amount: 100, owner: 2 }])
+                    .unwrap(),
                 total: 100,
                 less_total: 0,
                 status: crate::NominatorStatus::Active,
@@ -610,12 +637,13 @@ mod tests {
     fn test_cancel_request_with_state_does_nothing_when_request_does_not_exist() {
         let mut state = Nominator {
             id: 1,
-            nominations: OrderedSet::from(vec![Bond { amount: 100, owner: 2 }]),
+            nominations: BoundedOrderedSet::try_from_vec(vec![Bond { amount: 100, owner: 2 }])
+                .unwrap(),
             total: 100,
             less_total: 100,
             status: crate::NominatorStatus::Active,
         };
-        let mut scheduled_requests = vec![ScheduledRequest {
+        let mut scheduled_requests = bounded_vec![ScheduledRequest {
             nominator: 2,
             when_executable: 1,
             action: NominationAction::Decrease(50),
@@ -636,7 +664,8 @@ mod tests {
             state,
             Nominator {
                 id: 1,
-                nominations: OrderedSet::from(vec![Bond { amount: 100, owner: 2 }]),
+                nominations: BoundedOrderedSet::try_from_vec(vec![Bond { amount: 100, owner: 2 }])
+                    .unwrap(),
                 total: 100,
                 less_total: 100,
                 status: crate::NominatorStatus::Active,
@@ -0,0 +1,163 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Nominating several candidates in a single call
+//!
+//! The stake of each nomination is either given, or split from a total amount by a
+//! `SplitStrategy`. All the nominations are checked against `MaxNominationsPerNominator` and the
+//! stakable balance of the nominator first, and are added like `nominate` would add them, with
+//! worst case hints, so that none of them is added if one fails.
+
+use crate::pallet::{BalanceOf, CandidateInfo, Config, Error, NominatorState, Pallet};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError,
+};
+use sp_std::prelude::*;
+
+/// How a total amount is split across the candidates nominated by `nominate_many`
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum SplitStrategy {
+    /// The same amount for each candidate, the remainder going to the first one
+    Equal,
+    /// Raise the counted stake of the least backed candidates to a common level, leaving out
+    /// the candidates already above it
+    FillUnderSubscribed,
+}
+
+/// The nominations added by `nominate_many`
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum NominationTargets<AccountId, Balance> {
+    /// Each candidate with the amount to nominate it with
+    Amounts(Vec<(AccountId, Balance)>),
+    /// A total amount split across the candidates by the strategy
+    Split { total: Balance, candidates: Vec<AccountId>, strategy: SplitStrategy },
+}
+
+impl<AccountId, Balance> NominationTargets<AccountId, Balance> {
+    /// Number of candidates to nominate, at most
+    pub fn count(&self) -> u32 {
+        match self {
+            NominationTargets::Amounts(amounts) => amounts.len() as u32,
+            NominationTargets::Split { candidates, .. } => candidates.len() as u32,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds a nomination of `nominator` for each of the targets, failing if any of them cannot
+    /// be added. A new nominator needs its first nomination to be at least `MinNominatorStk`.
+    pub(crate) fn nominator_add_nominations(
+        nominator: T::AccountId,
+        targets: NominationTargets<T::AccountId, BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        let amounts = match targets {
+            NominationTargets::Amounts(amounts) => amounts,
+            NominationTargets::Split { total, candidates, strategy } =>
+                Self::split_nomination(total, candidates, strategy)?,
+        };
+        ensure!(!amounts.is_empty(), Error::<T>::NoNominationTargets);
+        let nomination_count = <NominatorState<T>>::get(&nominator)
+            .map_or(0u32, |state| state.nominations.0.len() as u32);
+        let max_nominations = T::MaxNominationsPerNominator::get();
+        ensure!(
+            nomination_count.saturating_add(amounts.len() as u32) <= max_nominations,
+            Error::<T>::ExceedMaxNominationsPerNominator
+        );
+        let mut candidates: Vec<&T::AccountId> =
+            amounts.iter().map(|(candidate, _)| candidate).collect();
+        candidates.sort();
+        candidates.dedup();
+        ensure!(candidates.len() == amounts.len(), Error::<T>::DuplicateNominationTarget);
+        let total = amounts
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+        ensure!(
+            Self::get_nominator_stakable_free_balance(&nominator) >= total,
+            Error::<T>::InsufficientBalance
+        );
+
+        // the hints are not needed, the weight is charged for the worst case
+        let max_candidate_nominations = T::MaxTopNominationsPerCandidate::get()
+            .saturating_add(T::MaxBottomNominationsPerCandidate::get());
+        for (candidate, amount) in amounts {
+            Self::nominator_add_nomination(
+                nominator.clone(),
+                candidate,
+                amount,
+                max_candidate_nominations,
+                max_nominations,
+            )?;
+        }
+        Ok(().into())
+    }
+
+    /// Splits `total` across the candidates by the strategy, leaving out the candidates that
+    /// get nothing.
+    pub(crate) fn split_nomination(
+        total: BalanceOf<T>,
+        candidates: Vec<T::AccountId>,
+        strategy: SplitStrategy,
+    ) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+        ensure!(!candidates.is_empty(), Error::<T>::NoNominationTargets);
+        let mut amounts = match strategy {
+            SplitStrategy::Equal => {
+                let share = total / (candidates.len() as u32).into();
+                candidates.into_iter().map(|candidate| (candidate, share)).collect::<Vec<_>>()
+            },
+            SplitStrategy::FillUnderSubscribed => {
+                let mut counted = candidates
+                    .into_iter()
+                    .map(|candidate| {
+                        let info =
+                            <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+                        Ok((candidate, info.total_counted))
+                    })
+                    .collect::<Result<Vec<_>, DispatchError>>()?;
+                counted.sort_by(|a, b| a.1.cmp(&b.1));
+                // raise the least backed candidates to the next one, as long as it is affordable
+                let (mut level, mut left, mut filled) = (counted[0].1, total, 1usize);
+                while let Some((_, next)) = counted.get(filled) {
+                    let cost = next.saturating_sub(level).saturating_mul((filled as u32).into());
+                    if cost > left {
+                        break
+                    }
+                    left = left.saturating_sub(cost);
+                    level = *next;
+                    filled += 1;
+                }
+                level = level.saturating_add(left / (filled as u32).into());
+                counted.truncate(filled);
+                counted
+                    .into_iter()
+                    .map(|(candidate, stake)| (candidate, level.saturating_sub(stake)))
+                    .collect::<Vec<_>>()
+            },
+        };
+        // the remainder of the division goes to the first candidate
+        let split = amounts
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |split, (_, amount)| split.saturating_add(*amount));
+        if let Some((_, first)) = amounts.first_mut() {
+            *first = first.saturating_add(total.saturating_sub(split));
+        }
+        amounts.retain(|(_, amount)| !amount.is_zero());
+        Ok(amounts)
+    }
+}
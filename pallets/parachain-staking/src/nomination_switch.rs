@@ -0,0 +1,140 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Switching stake of a nomination to another candidate without unbonding it
+//!
+//! The stake leaves the top or bottom nominations of one candidate and enters those of the other
+//! in a single call, so it keeps earning rewards. It stays under the nominator lock, which is
+//! not changed. A nominator can switch once every `NominationSwitchCooldown` eras.
+
+use crate::{
+    pallet::{
+        AutoCompoundingNominations, BalanceOf, CandidateInfo, Config, Era, Error, Event,
+        LastNominationSwitch, NominatorState, Pallet, StakingParams, Total,
+    },
+    types::Bond,
+};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+    /// Moves `amount` of the nomination of `nominator` towards `from` to its nomination towards
+    /// `to`, adding it if it does not exist. Moving the whole nomination removes it, carrying
+    /// its auto-compounding value over to a new nomination.
+    pub(crate) fn nomination_switch(
+        nominator: T::AccountId,
+        from: T::AccountId,
+        to: T::AccountId,
+        amount: BalanceOf<T>,
+        candidate_nomination_count: u32,
+        nomination_count: u32,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(from != to, <Error<T>>::CannotSwitchToSameCandidate);
+        let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
+        ensure!(
+            nomination_count >= state.nominations.0.len() as u32,
+            <Error<T>>::TooLowNominationCountToNominate
+        );
+        let now = <Era<T>>::get().current;
+        if let Some(last) = <LastNominationSwitch<T>>::get(&nominator) {
+            ensure!(
+                now >= last.saturating_add(T::NominationSwitchCooldown::get()),
+                <Error<T>>::NominationSwitchCooldown
+            );
+        }
+        let from_bond = state.get_bond_amount(&from).ok_or(<Error<T>>::NominationDNE)?;
+        ensure!(!amount.is_zero() && amount <= from_bond, <Error<T>>::InvalidSwitchAmount);
+        // the stake of a pending request is bound to leave the nomination it was requested for
+        ensure!(
+            !Self::nomination_request_exists(&from, &nominator),
+            <Error<T>>::PendingNominationRequestAlreadyExists
+        );
+        let min_nomination = <StakingParams<T>>::get().min_nomination;
+        let remaining = from_bond.saturating_sub(amount);
+        ensure!(remaining.is_zero() || remaining >= min_nomination, <Error<T>>::NominationBelowMin);
+        let to_bond = state.get_bond_amount(&to);
+        let mut to_info = <CandidateInfo<T>>::get(&to).ok_or(<Error<T>>::CandidateDNE)?;
+        match to_bond {
+            Some(_) => ensure!(
+                !Self::nomination_request_revoke_exists(&to, &nominator),
+                <Error<T>>::PendingNominationRevoke
+            ),
+            None => {
+                ensure!(amount >= min_nomination, <Error<T>>::NominationBelowMin);
+                ensure!(
+                    candidate_nomination_count >= to_info.nomination_count,
+                    <Error<T>>::TooLowCandidateNominationCountToNominate
+                );
+            },
+        }
+
+        // leave `from`, the stake staying locked
+        let mut from_info = <CandidateInfo<T>>::get(&from).ok_or(<Error<T>>::CandidateDNE)?;
+        let auto_compound = Self::nomination_auto_compound(&from, &nominator);
+        if remaining.is_zero() {
+            state.nominations.0.retain(|bond| bond.owner != from);
+            from_info.rm_nomination_if_exists::<T>(&from, nominator.clone(), from_bond)?;
+            Self::nomination_remove_auto_compound(&from, &nominator);
+        } else {
+            for bond in state.nominations.0.iter_mut() {
+                if bond.owner == from {
+                    bond.amount = remaining;
+                }
+            }
+            from_info.decrease_nomination::<T>(&from, nominator.clone(), from_bond, amount)?;
+        }
+        <CandidateInfo<T>>::insert(&from, from_info);
+
+        // enter `to`
+        let mut kicked = BalanceOf::<T>::zero();
+        match to_bond {
+            Some(to_bond) => {
+                for bond in state.nominations.0.iter_mut() {
+                    if bond.owner == to {
+                        bond.amount = bond.amount.saturating_add(amount);
+                    }
+                }
+                to_info.increase_nomination::<T>(&to, nominator.clone(), to_bond, amount)?;
+            },
+            None => {
+                state
+                    .nominations
+                    .try_insert(Bond { owner: to.clone(), amount })
+                    .map_err(|_| <Error<T>>::ExceedMaxNominationsPerNominator)?;
+                let (_, less_total_staked) =
+                    to_info.add_nomination::<T>(&to, Bond { owner: nominator.clone(), amount })?;
+                kicked = less_total_staked.unwrap_or_else(Zero::zero);
+                if remaining.is_zero() && !auto_compound.is_zero() {
+                    let mut auto_compounding = <AutoCompoundingNominations<T>>::get(&to);
+                    Self::set_auto_compound_config(
+                        &mut auto_compounding,
+                        nominator.clone(),
+                        auto_compound,
+                    )?;
+                    <AutoCompoundingNominations<T>>::insert(&to, auto_compounding);
+                }
+            },
+        }
+        <CandidateInfo<T>>::insert(&to, to_info);
+
+        // only a nomination kicked out of the bottom of `to` leaves the total stake
+        <Total<T>>::mutate(|total| *total = total.saturating_sub(kicked));
+        <NominatorState<T>>::insert(&nominator, state);
+        <LastNominationSwitch<T>>::insert(&nominator, now);
+        Self::deposit_event(Event::NominationSwitched { nominator, from, to, amount });
+        Ok(().into())
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Profiles of candidates and their operators
+//!
+//! A candidate can publish who runs it and how to reach its node, so that nominators can tell
+//! candidates apart. Storing the profile reserves a deposit from the candidate, growing with its
+//! length, which is returned when the profile is cleared or the candidate leaves.
+
+use crate::pallet::{BalanceOf, Config, Error, Event, OperatorProfiles, Pallet};
+use frame_support::{
+    dispatch::DispatchResultWithPostInfo,
+    ensure,
+    traits::{Get, ReservableCurrency},
+    BoundedVec,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{fmt::Debug, prelude::*};
+
+/// Description of a candidate and its operator, published for nominators
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxLength))]
+#[codec(mel_bound(Balance: MaxEncodedLen))]
+pub struct OperatorProfile<Balance, MaxLength: Get<u32>> {
+    pub display_name: BoundedVec<u8, MaxLength>,
+    pub website: BoundedVec<u8, MaxLength>,
+    pub contact: BoundedVec<u8, MaxLength>,
+    /// Endpoint or peer id of the node of the candidate
+    pub node_hint: BoundedVec<u8, MaxLength>,
+    /// Whether the candidate welcomes new nominations, None if it does not say. New nominations
+    /// are rejected while it is false.
+    pub accepting_nominations: Option<bool>,
+    /// Reserved from the candidate for storing the profile
+    pub deposit: Balance,
+}
+
+impl<B: Clone, S: Get<u32>> Clone for OperatorProfile<B, S> {
+    fn clone(&self) -> Self {
+        OperatorProfile {
+            display_name: self.display_name.clone(),
+            website: self.website.clone(),
+            contact: self.contact.clone(),
+            node_hint: self.node_hint.clone(),
+            accepting_nominations: self.accepting_nominations,
+            deposit: self.deposit.clone(),
+        }
+    }
+}
+
+impl<B: PartialEq, S: Get<u32>> PartialEq for OperatorProfile<B, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.display_name == other.display_name &&
+            self.website == other.website &&
+            self.contact == other.contact &&
+            self.node_hint == other.node_hint &&
+            self.accepting_nominations == other.accepting_nominations &&
+            self.deposit == other.deposit
+    }
+}
+
+impl<B: Eq, S: Get<u32>> Eq for OperatorProfile<B, S> {}
+
+impl<B: Debug, S: Get<u32>> Debug for OperatorProfile<B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+        f.debug_struct("OperatorProfile")
+            .field("display_name", &self.display_name)
+            .field("website", &self.website)
+            .field("contact", &self.contact)
+            .field("node_hint", &self.node_hint)
+            .field("accepting_nominations", &self.accepting_nominations)
+            .field("deposit", &self.deposit)
+            .finish()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Sets the profile of `candidate`, reserving the difference with the deposit of its
+    /// previous profile or returning the excess.
+    pub(crate) fn operator_profile_set(
+        candidate: T::AccountId,
+        display_name: Vec<u8>,
+        website: Vec<u8>,
+        contact: Vec<u8>,
+        node_hint: Vec<u8>,
+        accepting_nominations: Option<bool>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(Self::is_candidate(&candidate), Error::<T>::CandidateDNE);
+        let bound = |field: Vec<u8>| -> Result<BoundedVec<u8, T::MaxProfileFieldLength>, Error<T>> {
+            field.try_into().map_err(|_| Error::<T>::ProfileFieldTooLong)
+        };
+        let (display_name, website, contact, node_hint) =
+            (bound(display_name)?, bound(website)?, bound(contact)?, bound(node_hint)?);
+        let bytes = display_name.len() + website.len() + contact.len() + node_hint.len();
+        let deposit = T::ProfileDepositBase::get()
+            .saturating_add(T::ProfileDepositPerByte::get().saturating_mul((bytes as u32).into()));
+        let old_deposit = <OperatorProfiles<T>>::get(&candidate)
+            .map_or_else(Zero::zero, |profile| profile.deposit);
+        if deposit > old_deposit {
+            T::Currency::reserve(&candidate, deposit.saturating_sub(old_deposit))
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+        } else {
+            T::Currency::unreserve(&candidate, old_deposit.saturating_sub(deposit));
+        }
+        <OperatorProfiles<T>>::insert(
+            &candidate,
+            OperatorProfile {
+                display_name,
+                website,
+                contact,
+                node_hint,
+                accepting_nominations,
+                deposit,
+            },
+        );
+        Self::deposit_event(Event::OperatorProfileSet { candidate, deposit });
+        Ok(().into())
+    }
+
+    /// Removes the profile of `candidate` and unreserves its deposit, which is returned. None if
+    /// there was no profile.
+    pub(crate) fn operator_profile_remove(candidate: &T::AccountId) -> Option<BalanceOf<T>> {
+        let deposit = <OperatorProfiles<T>>::take(candidate)?.deposit;
+        T::Currency::unreserve(candidate, deposit);
+        Self::deposit_event(Event::OperatorProfileCleared {
+            candidate: candidate.clone(),
+            deposit,
+        });
+        Some(deposit)
+    }
+}
@@ -0,0 +1,562 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Nomination pools, through which holders of less than `MinNominatorStk` stake together
+//!
+//! A pool account nominates candidates like any nominator. Members transfer their stake to it in
+//! exchange for points, which are worth a pro rata share of the stake of the pool. The rewards
+//! reaching the pool account are shared by points, and paid to the members as they claim them or
+//! change their points. Members unbond through the scheduled requests of the pool account, and
+//! withdraw their stake once the request is due. As a nominator has one request per candidate,
+//! members cannot unbond from a candidate while a request of the pool account towards it is due in
+//! an earlier era, and fail with `PoolUnbondingUnavailable` if no other nomination can spare it.
+
+use crate::{
+    nomination_requests::{NominationAction, ScheduledRequest},
+    pallet::{
+        BalanceOf, Config, Era, EraIndex, Error, Event, NextPoolId, NominationScheduledRequests,
+        NominatorState, Pallet, PoolMembers, PoolUnbondings, Pools, StakingParams,
+    },
+    types::Bond,
+};
+use frame_support::{
+    dispatch::DispatchResultWithPostInfo,
+    ensure,
+    traits::{Currency, ExistenceRequirement, Get},
+    RuntimeDebug,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero},
+    DispatchError, FixedPointNumber, FixedU128,
+};
+use sp_std::prelude::*;
+
+/// Identifier of a nomination pool
+pub type PoolId = u32;
+
+/// A nomination pool
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AccountId, Balance> {
+    /// The account which created the pool, and can nominate more candidates for it
+    pub operator: AccountId,
+    /// Points of all the members
+    pub points: Balance,
+    /// Stake the points are worth, bonded in the nominations of the pool or waiting to be
+    pub bonded: Balance,
+    /// Stake of the members unbonding, or unbonded and not withdrawn yet
+    pub unbonding: Balance,
+    /// Points of the unbonding members, sharing `unbonding` so that they bear slashes too
+    pub unbonding_points: Balance,
+    /// Rewards received per point since the pool was created
+    pub reward_counter: FixedU128,
+    /// Rewards received and not paid to the members yet
+    pub reward_balance: Balance,
+}
+
+/// A member of a nomination pool
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolMember<Balance> {
+    pub points: Balance,
+    /// The `reward_counter` of the pool when the member was last paid its rewards
+    pub reward_counter: FixedU128,
+}
+
+/// Stake of a pool member being unbonded from a candidate
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolUnbonding<AccountId, Balance> {
+    pub candidate: AccountId,
+    /// Stake unbonded, before any slash
+    pub amount: Balance,
+    /// Share of the unbonding stake of the pool
+    pub points: Balance,
+    /// The era the request of the pool account to unbond it is executable
+    pub when_executable: EraIndex,
+}
+
+/// Returns `amount * part / whole`, zero if `whole` is zero.
+pub(crate) fn pro_rata<Balance: AtLeast32BitUnsigned + Copy>(
+    amount: Balance,
+    part: Balance,
+    whole: Balance,
+) -> Balance {
+    multiply_by_rational(amount.saturated_into(), part.saturated_into(), whole.saturated_into())
+        .map(|result| result.saturated_into())
+        .unwrap_or_else(|_| Zero::zero())
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account holding the stake of the pool `pool_id`.
+    pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+        T::PoolPalletId::get().into_sub_account_truncating(pool_id)
+    }
+
+    /// Returns the stake of the pool owned by its points which is not bonded in its nominations,
+    /// such as the stake of nominations kicked or of candidates which left.
+    fn pool_idle_stake(
+        pool_account: &T::AccountId,
+        pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+    ) -> BalanceOf<T> {
+        let active = <NominatorState<T>>::get(pool_account)
+            .map_or(Zero::zero(), |state| state.total.saturating_sub(state.less_total));
+        pool.bonded.saturating_sub(active)
+    }
+
+    /// Shares the rewards received by the pool account since last time among the points.
+    /// A shortfall of the balance of the pool account, from a slash, is taken out of `bonded`
+    /// and `unbonding` pro rata.
+    fn settle_pool_rewards(
+        pool_account: &T::AccountId,
+        pool: &mut PoolInfo<T::AccountId, BalanceOf<T>>,
+    ) {
+        let held = T::Currency::free_balance(pool_account);
+        let staked = pool.bonded.saturating_add(pool.unbonding);
+        let accounted = staked.saturating_add(pool.reward_balance);
+        if held < accounted {
+            let shortfall = (accounted - held).min(staked);
+            let from_unbonding = pro_rata(shortfall, pool.unbonding, staked);
+            pool.unbonding = pool.unbonding.saturating_sub(from_unbonding);
+            pool.bonded = pool.bonded.saturating_sub(shortfall.saturating_sub(from_unbonding));
+            return
+        }
+        let rewards = held - accounted;
+        if rewards.is_zero() || pool.points.is_zero() {
+            return
+        }
+        pool.reward_counter =
+            pool.reward_counter.saturating_add(FixedU128::saturating_from_rational(
+                rewards.saturated_into::<u128>(),
+                pool.points.saturated_into::<u128>(),
+            ));
+        pool.reward_balance = pool.reward_balance.saturating_add(rewards);
+    }
+
+    /// Pays `member` its share of the rewards of the pool since it was last paid.
+    /// Returns the rewards paid.
+    fn pay_pool_member_rewards(
+        pool_id: PoolId,
+        pool_account: &T::AccountId,
+        pool: &mut PoolInfo<T::AccountId, BalanceOf<T>>,
+        member: &T::AccountId,
+        member_state: &mut PoolMember<BalanceOf<T>>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        Self::settle_pool_rewards(pool_account, pool);
+        let rewards: BalanceOf<T> = pool
+            .reward_counter
+            .saturating_sub(member_state.reward_counter)
+            .saturating_mul_int(member_state.points.saturated_into::<u128>())
+            .saturated_into();
+        member_state.reward_counter = pool.reward_counter;
+        // rounded down, so the reward balance always covers the rewards of the members
+        let rewards = rewards.min(pool.reward_balance);
+        if rewards.is_zero() {
+            return Ok(rewards)
+        }
+        T::Currency::transfer(pool_account, member, rewards, ExistenceRequirement::AllowDeath)?;
+        pool.reward_balance = pool.reward_balance.saturating_sub(rewards);
+        Self::deposit_event(Event::PoolRewardPaid { pool_id, member: member.clone(), rewards });
+        Ok(rewards)
+    }
+
+    /// Returns the points worth `amount` of stake of the pool.
+    fn pool_points_of(
+        pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if pool.points.is_zero() || pool.bonded.is_zero() {
+            amount
+        } else {
+            pro_rata(amount, pool.points, pool.bonded)
+        }
+    }
+
+    /// Returns the unbonding points worth `amount` of unbonding stake of the pool.
+    fn pool_unbonding_points_of(
+        pool: &PoolInfo<T::AccountId, BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if pool.unbonding_points.is_zero() || pool.unbonding.is_zero() {
+            amount
+        } else {
+            pro_rata(amount, pool.unbonding_points, pool.unbonding)
+        }
+    }
+
+    /// Creates a pool nominating `candidate` with `amount` of the stake of `operator`, which
+    /// becomes its operator and first member.
+    pub(crate) fn pool_create(
+        operator: T::AccountId,
+        candidate: T::AccountId,
+        amount: BalanceOf<T>,
+        candidate_nomination_count: u32,
+    ) -> DispatchResultWithPostInfo {
+        let pool_id = <NextPoolId<T>>::get();
+        let pool_account = Self::pool_account(pool_id);
+        T::Currency::transfer(&operator, &pool_account, amount, ExistenceRequirement::KeepAlive)?;
+        Self::nominator_add_nomination(
+            pool_account,
+            candidate.clone(),
+            amount,
+            candidate_nomination_count,
+            0u32,
+        )?;
+        <Pools<T>>::insert(
+            pool_id,
+            PoolInfo {
+                operator: operator.clone(),
+                points: amount,
+                bonded: amount,
+                unbonding: Zero::zero(),
+                unbonding_points: Zero::zero(),
+                reward_counter: FixedU128::zero(),
+                reward_balance: Zero::zero(),
+            },
+        );
+        <PoolMembers<T>>::insert(
+            pool_id,
+            &operator,
+            PoolMember { points: amount, reward_counter: FixedU128::zero() },
+        );
+        <NextPoolId<T>>::put(pool_id.saturating_add(1));
+        Self::deposit_event(Event::PoolCreated { pool_id, operator, candidate, amount });
+        Ok(().into())
+    }
+
+    /// Nominates `candidate` for the pool with `amount`, taken from the idle stake of the pool
+    /// first, and from `operator` for the rest, in exchange for points.
+    pub(crate) fn pool_nominate_candidate(
+        operator: T::AccountId,
+        pool_id: PoolId,
+        candidate: T::AccountId,
+        amount: BalanceOf<T>,
+        candidate_nomination_count: u32,
+        nomination_count: u32,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(<Error<T>>::PoolDNE)?;
+        ensure!(pool.operator == operator, <Error<T>>::NotPoolOperator);
+        let pool_account = Self::pool_account(pool_id);
+        let mut member_state = <PoolMembers<T>>::get(pool_id, &operator)
+            .unwrap_or(PoolMember { points: Zero::zero(), reward_counter: pool.reward_counter });
+        Self::pay_pool_member_rewards(
+            pool_id,
+            &pool_account,
+            &mut pool,
+            &operator,
+            &mut member_state,
+        )?;
+
+        let extra = amount.saturating_sub(Self::pool_idle_stake(&pool_account, &pool));
+        if !extra.is_zero() {
+            let points = Self::pool_points_of(&pool, extra);
+            T::Currency::transfer(
+                &operator,
+                &pool_account,
+                extra,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            pool.points = pool.points.saturating_add(points);
+            pool.bonded = pool.bonded.saturating_add(extra);
+            member_state.points = member_state.points.saturating_add(points);
+        }
+        Self::nominator_add_nomination(
+            pool_account,
+            candidate.clone(),
+            amount,
+            candidate_nomination_count,
+            nomination_count,
+        )?;
+        <PoolMembers<T>>::insert(pool_id, &operator, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+        Self::deposit_event(Event::PoolNominated { pool_id, candidate, amount });
+        Ok(().into())
+    }
+
+    /// Adds `amount` of the stake of `member` to the pool, bonded into its least bonded
+    /// nomination which is not being revoked, in exchange for points.
+    pub(crate) fn pool_join(
+        member: T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(amount >= T::MinPoolJoin::get(), <Error<T>>::PoolJoinBelowMin);
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(<Error<T>>::PoolDNE)?;
+        let pool_account = Self::pool_account(pool_id);
+        let mut member_state = <PoolMembers<T>>::get(pool_id, &member)
+            .unwrap_or(PoolMember { points: Zero::zero(), reward_counter: pool.reward_counter });
+        Self::pay_pool_member_rewards(
+            pool_id,
+            &pool_account,
+            &mut pool,
+            &member,
+            &mut member_state,
+        )?;
+
+        let points = Self::pool_points_of(&pool, amount);
+        ensure!(!points.is_zero(), <Error<T>>::PoolJoinBelowMin);
+        T::Currency::transfer(&member, &pool_account, amount, ExistenceRequirement::KeepAlive)?;
+        if let Some(mut state) = <NominatorState<T>>::get(&pool_account) {
+            let candidate = state
+                .nominations
+                .0
+                .iter()
+                .filter(|bond| !Self::nomination_request_revoke_exists(&bond.owner, &pool_account))
+                .min_by_key(|bond| bond.amount)
+                .map(|bond| bond.owner.clone());
+            // the stake stays idle until the operator nominates if every nomination is revoked
+            if let Some(candidate) = candidate {
+                state.increase_nomination::<T>(candidate, amount)?;
+            }
+        }
+        pool.points = pool.points.saturating_add(points);
+        pool.bonded = pool.bonded.saturating_add(amount);
+        member_state.points = member_state.points.saturating_add(points);
+        <PoolMembers<T>>::insert(pool_id, &member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+        Self::deposit_event(Event::PoolJoined { pool_id, member, amount, points });
+        Ok(().into())
+    }
+
+    /// Schedules `staker`, the account of a pool or of liquid staking, to unbond `amount` from
+    /// the nomination with the most stake not being unbonded yet, if it can spare it. A pending
+    /// request of `staker` towards the candidate is merged into the new one if both would be
+    /// executable in the same era. Otherwise the candidate is skipped, as delaying the pending
+    /// request would delay the unbondings already waiting for it.
+    /// Returns the candidate and the era the request is executable, None if no nomination can
+    /// spare `amount`.
+    pub(crate) fn schedule_stake_unbond(
+        staker: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<Option<(T::AccountId, EraIndex)>, DispatchError> {
+        let state = match <NominatorState<T>>::get(staker) {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+        let mut nominations = state
+            .nominations
+            .0
+            .into_iter()
+            .map(|bond| {
+                let pending = <NominationScheduledRequests<T>>::get(&bond.owner)
+                    .into_iter()
+                    .find(|request| &request.nominator == staker);
+                (bond, pending)
+            })
+            .collect::<Vec<_>>();
+        let available = |(bond, pending): &(
+            Bond<T::AccountId, BalanceOf<T>>,
+            Option<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
+        )| {
+            pending
+                .as_ref()
+                .map_or(bond.amount, |request| bond.amount.saturating_sub(request.action.amount()))
+        };
+        nominations.sort_by(|a, b| available(b).cmp(&available(a)));
+        let now = <Era<T>>::get().current;
+        let params = <StakingParams<T>>::get();
+        for (bond, pending) in nominations {
+            let total = pending
+                .as_ref()
+                .map_or(amount, |request| request.action.amount().saturating_add(amount));
+            if total > bond.amount {
+                continue
+            }
+            let revoke = total == bond.amount;
+            let when = now.saturating_add(if revoke {
+                params.revoke_nomination_delay
+            } else {
+                params.nomination_bond_less_delay
+            });
+            if let Some(request) = pending {
+                if request.when_executable != when ||
+                    matches!(request.action, NominationAction::Revoke(_))
+                {
+                    continue
+                }
+                Self::nomination_cancel_request(bond.owner.clone(), staker.clone())?;
+            }
+            if revoke {
+                Self::nomination_schedule_revoke(bond.owner.clone(), staker.clone())?;
+            } else {
+                Self::nomination_schedule_bond_decrease(bond.owner.clone(), staker.clone(), total)?;
+            }
+            return Ok(Some((bond.owner, when)))
+        }
+        Ok(None)
+    }
+
+    /// Unbonds the stake `points` of `member` are worth from the pool. Idle stake of the pool is
+    /// withdrawn at once, bonded stake once the request of the pool to unbond it is due.
+    pub(crate) fn pool_unbond(
+        member: T::AccountId,
+        pool_id: PoolId,
+        points: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(<Error<T>>::PoolDNE)?;
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, &member).ok_or(<Error<T>>::PoolMemberDNE)?;
+        ensure!(
+            !points.is_zero() && points <= member_state.points,
+            <Error<T>>::NotEnoughPoolPoints
+        );
+        let pool_account = Self::pool_account(pool_id);
+        Self::pay_pool_member_rewards(
+            pool_id,
+            &pool_account,
+            &mut pool,
+            &member,
+            &mut member_state,
+        )?;
+
+        let amount = pro_rata(pool.bonded, points, pool.points);
+        ensure!(!amount.is_zero(), <Error<T>>::NotEnoughPoolPoints);
+        if amount <= Self::pool_idle_stake(&pool_account, &pool) {
+            T::Currency::transfer(
+                &pool_account,
+                &member,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::deposit_event(Event::PoolMemberWithdrawn {
+                pool_id,
+                member: member.clone(),
+                amount,
+            });
+        } else {
+            let (candidate, when_executable) = Self::schedule_stake_unbond(&pool_account, amount)?
+                .ok_or(<Error<T>>::PoolUnbondingUnavailable)?;
+            let unbonding_points = Self::pool_unbonding_points_of(&pool, amount);
+            <PoolUnbondings<T>>::try_mutate(pool_id, &member, |unbondings| {
+                unbondings.try_push(PoolUnbonding {
+                    candidate,
+                    amount,
+                    points: unbonding_points,
+                    when_executable,
+                })
+            })
+            .map_err(|_| <Error<T>>::TooManyPoolUnbondings)?;
+            pool.unbonding = pool.unbonding.saturating_add(amount);
+            pool.unbonding_points = pool.unbonding_points.saturating_add(unbonding_points);
+        }
+        pool.points = pool.points.saturating_sub(points);
+        pool.bonded = pool.bonded.saturating_sub(amount);
+        member_state.points = member_state.points.saturating_sub(points);
+        Self::update_pool_member(pool_id, &member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+        Self::deposit_event(Event::PoolMemberUnbonded { pool_id, member, points, amount });
+        Ok(().into())
+    }
+
+    /// Withdraws the stake of `member` unbonded from the pool, executing the requests of the
+    /// pool account which are due. The stake is the share of the unbonding stake of the pool
+    /// the points of the unbondings are worth, less any slash since they were unbonded.
+    pub(crate) fn pool_withdraw_unbonded(
+        member: T::AccountId,
+        pool_id: PoolId,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(<Error<T>>::PoolDNE)?;
+        let pool_account = Self::pool_account(pool_id);
+        let now = <Era<T>>::get().current;
+        let mut unbondings = <PoolUnbondings<T>>::get(pool_id, &member);
+        let due = unbondings
+            .iter()
+            .filter(|unbonding| unbonding.when_executable <= now)
+            .cloned()
+            .collect::<Vec<_>>();
+        ensure!(!due.is_empty(), <Error<T>>::NoPoolUnbondingDue);
+        unbondings.retain(|unbonding| unbonding.when_executable > now);
+
+        Self::settle_pool_rewards(&pool_account, &mut pool);
+        let mut points = BalanceOf::<T>::zero();
+        for unbonding in due {
+            Self::execute_stake_unbond_if_due(&unbonding.candidate, &pool_account, now)?;
+            points = points.saturating_add(unbonding.points);
+        }
+        let amount = pro_rata(pool.unbonding, points, pool.unbonding_points);
+        T::Currency::transfer(&pool_account, &member, amount, ExistenceRequirement::AllowDeath)?;
+        pool.unbonding = pool.unbonding.saturating_sub(amount);
+        pool.unbonding_points = pool.unbonding_points.saturating_sub(points);
+        <Pools<T>>::insert(pool_id, pool);
+        if unbondings.is_empty() {
+            <PoolUnbondings<T>>::remove(pool_id, &member);
+        } else {
+            <PoolUnbondings<T>>::insert(pool_id, &member, unbondings);
+        }
+        if let Some(member_state) = <PoolMembers<T>>::get(pool_id, &member) {
+            Self::update_pool_member(pool_id, &member, member_state);
+        }
+        Self::deposit_event(Event::PoolMemberWithdrawn { pool_id, member, amount });
+        Ok(().into())
+    }
+
+    /// Executes the request of `staker` towards `candidate` if it is due in era `now`. The
+    /// request may be gone already, executed for another unbonding or dropped when the candidate
+    /// left.
+    pub(crate) fn execute_stake_unbond_if_due(
+        candidate: &T::AccountId,
+        staker: &T::AccountId,
+        now: EraIndex,
+    ) -> Result<(), DispatchError> {
+        let request_due = <NominationScheduledRequests<T>>::get(candidate)
+            .iter()
+            .any(|request| &request.nominator == staker && request.when_executable <= now);
+        if request_due {
+            Self::nomination_execute_scheduled_request(candidate.clone(), staker.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Pays `member` its share of the rewards of the pool, collecting the rewards credited to
+    /// the pool account first.
+    pub(crate) fn pool_claim_rewards(
+        member: T::AccountId,
+        pool_id: PoolId,
+    ) -> DispatchResultWithPostInfo {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(<Error<T>>::PoolDNE)?;
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, &member).ok_or(<Error<T>>::PoolMemberDNE)?;
+        let pool_account = Self::pool_account(pool_id);
+        if !Self::total_claimable_rewards(&pool_account).is_zero() {
+            Self::staker_claim_rewards(pool_account.clone(), T::MaxNominationsPerNominator::get())?;
+        }
+        let rewards = Self::pay_pool_member_rewards(
+            pool_id,
+            &pool_account,
+            &mut pool,
+            &member,
+            &mut member_state,
+        )?;
+        ensure!(!rewards.is_zero(), <Error<T>>::NoRewardsToClaim);
+        <PoolMembers<T>>::insert(pool_id, &member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+        Ok(().into())
+    }
+
+    /// Stores `member_state`, removing the member once it has no points nor unbonding stake
+    /// left.
+    fn update_pool_member(
+        pool_id: PoolId,
+        member: &T::AccountId,
+        member_state: PoolMember<BalanceOf<T>>,
+    ) {
+        if member_state.points.is_zero() && !<PoolUnbondings<T>>::contains_key(pool_id, member) {
+            <PoolMembers<T>>::remove(pool_id, member);
+        } else {
+            <PoolMembers<T>>::insert(pool_id, member, member_state);
+        }
+    }
+}
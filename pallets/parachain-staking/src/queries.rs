@@ -0,0 +1,279 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only views of the staking state, served by the `ParachainStakingApi` runtime API
+
+use crate::{
+    nomination_requests::ScheduledRequest,
+    pallet::{
+        AtStake, AwardedPts, BalanceOf, BottomNominations, CandidateInfo, Config, DelayedPayouts,
+        Era, EraIndex, FailedRewardPayments, NominationScheduledRequests, NominatorState, Pallet,
+        ParachainBondInfo, Points, RewardPoint, StakingParams, TopNominations,
+    },
+    types::{
+        Bond, CandidateBondLessRequest, CollatorSnapshot, CollatorStatus, CommissionChangeRequest,
+    },
+};
+use frame_support::{traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{One, Saturating, Zero},
+    Perbill, Percent,
+};
+use sp_std::prelude::*;
+
+/// Everything known about a collator candidate.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateProfile<AccountId, Balance> {
+    /// The candidate self bond
+    pub bond: Balance,
+    /// Self bond + sum of top nominations
+    pub total_counted: Balance,
+    /// Total number of nominations to the candidate
+    pub nomination_count: u32,
+    pub status: CollatorStatus,
+    /// Whether the candidate is selected for the current era
+    pub is_selected: bool,
+    pub commission: Perbill,
+    pub commission_request: Option<CommissionChangeRequest>,
+    pub bond_less_request: Option<CandidateBondLessRequest<Balance>>,
+    /// Top nominations, from the highest to the lowest
+    pub top_nominations: Vec<Bond<AccountId, Balance>>,
+    /// Bottom nominations, from the highest to the lowest
+    pub bottom_nominations: Vec<Bond<AccountId, Balance>>,
+    /// Pending requests of the nominators of the candidate
+    pub nomination_requests: Vec<ScheduledRequest<AccountId, Balance>>,
+}
+
+/// Whether a nomination counts towards the stake of its candidate.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum NominationPlacement {
+    Top,
+    Bottom,
+}
+
+/// A nomination seen from its nominator.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationPosition<AccountId, Balance> {
+    pub candidate: AccountId,
+    pub amount: Balance,
+    pub placement: NominationPlacement,
+    pub auto_compound: Percent,
+    /// The pending request to revoke or decrease the nomination, if any
+    pub request: Option<ScheduledRequest<AccountId, Balance>>,
+}
+
+/// The staking rewards of an account which are not in its balance yet.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct StakingRewards<Balance> {
+    /// Rewards credited in `PayoutMode::Claim`, to collect with `claim_rewards`
+    pub claimable: Balance,
+    /// Reward payments that failed and wait to be retried
+    pub failed: Balance,
+    /// Rewards of the eras being paid out, including eras carried over
+    pub pending: Balance,
+    /// Rewards earned so far in the eras not paid out yet, assuming the reward pot balance
+    /// currently available, less the parachain bond reserve, is shared equally by them
+    pub estimated: Balance,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the profile of `candidate`, None if it is not a candidate.
+    pub fn candidate_profile(
+        candidate: &T::AccountId,
+    ) -> Option<CandidateProfile<T::AccountId, BalanceOf<T>>> {
+        let state = <CandidateInfo<T>>::get(candidate)?;
+        Some(CandidateProfile {
+            bond: state.bond,
+            total_counted: state.total_counted,
+            nomination_count: state.nomination_count,
+            status: state.status,
+            is_selected: Self::is_selected_candidate(candidate),
+            commission: state.commission,
+            commission_request: state.commission_request,
+            bond_less_request: state.request,
+            top_nominations: <TopNominations<T>>::get(candidate)
+                .map(|nominations| nominations.nominations.into_inner())
+                .unwrap_or_default(),
+            bottom_nominations: <BottomNominations<T>>::get(candidate)
+                .map(|nominations| nominations.nominations.into_inner())
+                .unwrap_or_default(),
+            nomination_requests: <NominationScheduledRequests<T>>::get(candidate).into_inner(),
+        })
+    }
+
+    /// Returns the nominations of `nominator`, empty if it is not a nominator.
+    pub fn nomination_positions(
+        nominator: &T::AccountId,
+    ) -> Vec<NominationPosition<T::AccountId, BalanceOf<T>>> {
+        let state = match <NominatorState<T>>::get(nominator) {
+            Some(state) => state,
+            None => return Vec::new(),
+        };
+        state
+            .nominations
+            .0
+            .into_iter()
+            .map(|Bond { owner: candidate, amount }| {
+                let is_top = <TopNominations<T>>::get(&candidate).map_or(false, |top| {
+                    top.nominations.iter().any(|bond| &bond.owner == nominator)
+                });
+                let auto_compound = Self::nomination_auto_compound(&candidate, nominator);
+                let request = <NominationScheduledRequests<T>>::get(&candidate)
+                    .into_iter()
+                    .find(|request| &request.nominator == nominator);
+                NominationPosition {
+                    candidate,
+                    amount,
+                    placement: if is_top {
+                        NominationPlacement::Top
+                    } else {
+                        NominationPlacement::Bottom
+                    },
+                    auto_compound,
+                    request,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the minimum amount of a new nomination to enter the top nominations of
+    /// `candidate`, None if it is not a candidate. The first nomination of a nominator must also
+    /// be at least `MinNominatorStk`.
+    pub fn min_top_nomination_stake(candidate: &T::AccountId) -> Option<BalanceOf<T>> {
+        let state = <CandidateInfo<T>>::get(candidate)?;
+        let top_count =
+            <TopNominations<T>>::get(candidate).map_or(0, |top| top.nominations.len() as u32);
+        let min_nomination = <StakingParams<T>>::get().min_nomination;
+        if top_count < T::MaxTopNominationsPerCandidate::get() {
+            return Some(min_nomination)
+        }
+        // top nominations must be strictly higher than the lowest one they replace
+        Some(
+            state
+                .lowest_top_nomination_amount
+                .saturating_add(One::one())
+                .max(min_nomination),
+        )
+    }
+
+    /// Returns the minimum total counted stake of a candidate to be selected, as things stand,
+    /// at the next era.
+    pub fn min_selection_stake() -> BalanceOf<T> {
+        let min_collator_stk = <StakingParams<T>>::get().min_collator_stk;
+        let total_selected = Self::total_selected() as usize;
+        let candidates = Self::top_candidate_pool(total_selected);
+        if candidates.len() < total_selected || total_selected.is_zero() {
+            return min_collator_stk
+        }
+        // selected candidates must be strictly higher than the lowest one they replace
+        candidates[total_selected - 1]
+            .amount
+            .saturating_add(One::one())
+            .max(min_collator_stk)
+    }
+
+    /// Returns the rewards of `account` which are not in its balance yet.
+    pub fn staking_rewards(account: &T::AccountId) -> StakingRewards<BalanceOf<T>> {
+        let mut rewards = StakingRewards {
+            claimable: Self::total_claimable_rewards(account),
+            failed: <FailedRewardPayments<T>>::iter_prefix_values(account)
+                .fold(Zero::zero(), |total, payment| total.saturating_add(payment.amount)),
+            ..Default::default()
+        };
+
+        for (era, payout) in <DelayedPayouts<T>>::iter() {
+            rewards.pending = rewards.pending.saturating_add(Self::share_of_era_reward(
+                account,
+                era,
+                payout.total_points,
+                payout.total_staking_reward,
+            ));
+        }
+
+        let now = <Era<T>>::get().current;
+        // the points of eras due to be paid out moved to `DelayedPayouts`
+        let unpaid_eras =
+            (now.saturating_sub(<StakingParams<T>>::get().reward_payment_delay).max(1)..=now)
+                .map(|era| (era, <Points<T>>::get(era)))
+                .filter(|(_, total_points)| !total_points.is_zero())
+                .collect::<Vec<_>>();
+        if unpaid_eras.is_empty() {
+            return rewards
+        }
+        let available_reward = Self::reward_pot().saturating_sub(Self::locked_era_payout());
+        let bond_percent = <ParachainBondInfo<T>>::get().percent;
+        // the available reward is shared by the unpaid eras
+        let era_reward = available_reward.saturating_sub(bond_percent * available_reward) /
+            BalanceOf::<T>::from(unpaid_eras.len() as u32);
+        for (era, total_points) in unpaid_eras {
+            rewards.estimated = rewards.estimated.saturating_add(Self::share_of_era_reward(
+                account,
+                era,
+                total_points,
+                era_reward,
+            ));
+        }
+        rewards
+    }
+
+    /// Returns the share of `account` in the `era_reward` of `era`, shared by the collators not
+    /// paid yet by points.
+    fn share_of_era_reward(
+        account: &T::AccountId,
+        era: EraIndex,
+        total_points: RewardPoint,
+        era_reward: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let mut share = BalanceOf::<T>::zero();
+        for (collator, points) in <AwardedPts<T>>::iter_prefix(era) {
+            let collator_reward = Perbill::from_rational(points, total_points) * era_reward;
+            let snapshot = <AtStake<T>>::get(era, &collator);
+            share = share.saturating_add(Self::share_of_collator_reward(
+                account,
+                &collator,
+                &snapshot,
+                collator_reward,
+            ));
+        }
+        share
+    }
+
+    /// Returns the share of `account` in the `reward` of the collator of `snapshot`, split as in
+    /// `pay_one_collator_reward`.
+    fn share_of_collator_reward(
+        account: &T::AccountId,
+        collator: &T::AccountId,
+        snapshot: &CollatorSnapshot<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
+        reward: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let commission = snapshot.commission * reward;
+        let reward_to_share = reward.saturating_sub(commission);
+        let mut share = BalanceOf::<T>::zero();
+        if account == collator {
+            share = commission.saturating_add(
+                Perbill::from_rational(snapshot.bond, snapshot.total) * reward_to_share,
+            );
+        }
+        for bond in snapshot.nominations.iter().filter(|bond| &bond.owner == account) {
+            share = share.saturating_add(
+                Perbill::from_rational(bond.amount, snapshot.total) * reward_to_share,
+            );
+        }
+        share
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Integration with `pallet_session`
+
+use crate::pallet::{Config, Era, Pallet, QueuedCandidates};
+use frame_support::{traits::Get, weights::DispatchClass, BoundedVec};
+use pallet_session::SessionManager;
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+/// Hands the selected candidates to `pallet_session`, which queues their keys for the next
+/// session.
+///
+/// With eras of `Era.length` blocks, every new session gets the candidates selected at that time.
+/// With eras of `T::SessionsPerEra` sessions, `pallet_session` plans each session one session
+/// before it starts. The collators of the next era are planned with its first session, and the
+/// era transition happens when that session starts, together with the rotation of their keys.
+/// Until then, the collators of the current era keep authoring and earning points in it.
+impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
+    fn new_session(new_index: u32) -> Option<Vec<T::AccountId>> {
+        if T::SessionsPerEra::get().is_zero() {
+            return Some(Self::selected_candidates().into_inner())
+        }
+        if !Self::session_starts_era(new_index) {
+            return None
+        }
+
+        let collators = Self::compute_top_candidates();
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            T::DbWeight::get()
+                .reads(collators.len() as u64 + 2)
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Mandatory,
+        );
+        <QueuedCandidates<T>>::put(
+            BoundedVec::try_from(collators.clone())
+                .expect("no more collators than candidates in the bounded pool; qed"),
+        );
+        // without any qualified candidate, the collators of the current era are kept
+        if collators.is_empty() {
+            None
+        } else {
+            Some(collators)
+        }
+    }
+
+    fn new_session_genesis(_new_index: u32) -> Option<Vec<T::AccountId>> {
+        Some(Self::selected_candidates().into_inner())
+    }
+
+    fn end_session(_end_index: u32) {
+        // eras end when the session starting the next one starts
+    }
+
+    fn start_session(start_index: u32) {
+        if T::SessionsPerEra::get().is_zero() || !Self::session_starts_era(start_index) {
+            return
+        }
+
+        let mut era = <Era<T>>::get();
+        let weight = Self::start_new_era(&mut era, frame_system::Pallet::<T>::block_number());
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            weight,
+            DispatchClass::Mandatory,
+        );
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether the session `index` is the first one of an era made of sessions. The genesis
+    /// session belongs to the genesis era.
+    fn session_starts_era(index: u32) -> bool {
+        !index.is_zero() && index % T::SessionsPerEra::get() == 0
+    }
+}
@@ -15,12 +15,13 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 /* TODO: use orml_utilities::OrderedSet without leaking substrate v2.0 dependencies */
-use parity_scale_codec::{Decode, Encode};
+use frame_support::{traits::Get, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
-use sp_std::prelude::*;
+use sp_std::{fmt::Debug, prelude::*};
 
 /// An ordered set backed by `Vec`
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -87,3 +88,77 @@ impl<T: Ord> From<Vec<T>> for OrderedSet<T> {
         Self::from(v)
     }
 }
+
+/// An ordered set backed by `BoundedVec`
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(S))]
+#[codec(mel_bound(T: MaxEncodedLen, S: Get<u32>))]
+pub struct BoundedOrderedSet<T, S: Get<u32>>(pub BoundedVec<T, S>);
+
+impl<T: Ord, S: Get<u32>> BoundedOrderedSet<T, S> {
+    /// Create a new empty set
+    pub fn new() -> Self {
+        Self(BoundedVec::default())
+    }
+
+    /// Create a set from a `Vec`.
+    /// `v` will be sorted and dedup first.
+    /// Return None if `v` has more unique elements than the bound.
+    pub fn try_from_vec(mut v: Vec<T>) -> Option<Self> {
+        v.sort();
+        v.dedup();
+        BoundedVec::try_from(v).ok().map(Self)
+    }
+
+    /// Insert an element.
+    /// Return true if insertion happened, an error if the set is full.
+    pub fn try_insert(&mut self, value: T) -> Result<bool, ()> {
+        match self.0.binary_search(&value) {
+            Ok(_) => Ok(false),
+            Err(loc) => self.0.try_insert(loc, value).map(|_| true).map_err(|_| ()),
+        }
+    }
+
+    /// Remove an element.
+    /// Return true if removal happened.
+    pub fn remove(&mut self, value: &T) -> bool {
+        match self.0.binary_search(value) {
+            Ok(loc) => {
+                self.0.remove(loc);
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Return if the set contains `value`
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.binary_search(value).is_ok()
+    }
+}
+
+impl<T, S: Get<u32>> Default for BoundedOrderedSet<T, S> {
+    fn default() -> Self {
+        Self(BoundedVec::default())
+    }
+}
+
+impl<T: Clone, S: Get<u32>> Clone for BoundedOrderedSet<T, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: PartialEq, S: Get<u32>> PartialEq for BoundedOrderedSet<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, S: Get<u32>> Eq for BoundedOrderedSet<T, S> {}
+
+impl<T: Debug, S: Get<u32>> Debug for BoundedOrderedSet<T, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        f.debug_tuple("BoundedOrderedSet").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Slashing of collators and their nominators
+
+use crate::{
+    nomination_requests::NominationAction,
+    pallet::{
+        AtStake, BalanceOf, CandidateInfo, Config, DelayedPayouts, Era, EraIndex, Error, Event,
+        NegativeImbalanceOf, NominationScheduledRequests, NominatorState, Pallet,
+        ProcessedOffences, StakingParams, Total, UnappliedSlashes, COLLATOR_LOCK_ID,
+    },
+    traits::ReportOffence,
+    WeightInfo,
+};
+use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    ensure,
+    traits::{tokens::WithdrawReasons, Currency, Get, Imbalance, LockableCurrency, OnUnbalanced},
+    weights::Weight,
+    BoundedVec,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError, Perbill,
+};
+use sp_std::{fmt::Debug, prelude::*};
+
+/// A slash of a collator and its nominators, waiting for its era to be applied.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNominations))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct UnappliedSlash<AccountId, Balance, MaxNominations: Get<u32>> {
+    /// The collator being slashed
+    pub collator: AccountId,
+    /// The era of the offence
+    pub era: EraIndex,
+    /// Amount to slash from the collator self bond
+    pub own: Balance,
+    /// Amounts to slash from the nominations towards the collator
+    pub others: BoundedVec<(AccountId, Balance), MaxNominations>,
+}
+
+impl<A: Clone, B: Clone, S: Get<u32>> Clone for UnappliedSlash<A, B, S> {
+    fn clone(&self) -> Self {
+        UnappliedSlash {
+            collator: self.collator.clone(),
+            era: self.era,
+            own: self.own.clone(),
+            others: self.others.clone(),
+        }
+    }
+}
+
+impl<A: PartialEq, B: PartialEq, S: Get<u32>> PartialEq for UnappliedSlash<A, B, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.collator == other.collator &&
+            self.era == other.era &&
+            self.own == other.own &&
+            self.others == other.others
+    }
+}
+
+impl<A: Eq, B: Eq, S: Get<u32>> Eq for UnappliedSlash<A, B, S> {}
+
+impl<A: Debug, B: Debug, S: Get<u32>> Debug for UnappliedSlash<A, B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+        f.debug_struct("UnappliedSlash")
+            .field("collator", &self.collator)
+            .field("era", &self.era)
+            .field("own", &self.own)
+            .field("others", &self.others)
+            .finish()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Slashes `fraction` of the stake of `collator` and its nominators, as recorded in `AtStake`
+    /// for the offence `era`, at most `T::SlashableEras` eras ago. The slash is applied
+    /// `T::SlashDeferDuration` eras from now unless it is cancelled before, or immediately if
+    /// there is no deferral.
+    /// Nominations are slashed on their bonded amount, which the snapshot lowers by the pending
+    /// revoke or decrease requests that keep it from earning rewards.
+    pub fn slash_collator(
+        collator: &T::AccountId,
+        era: EraIndex,
+        fraction: Perbill,
+    ) -> DispatchResult {
+        let now = <Era<T>>::get().current;
+        ensure!(era.saturating_add(T::SlashableEras::get()) >= now, <Error<T>>::OffenceTooOld);
+        ensure!(<AtStake<T>>::contains_key(era, collator), <Error<T>>::CollatorSnapshotDNE);
+        if fraction.is_zero() {
+            return Ok(())
+        }
+        let snapshot = <AtStake<T>>::get(era, collator);
+        let others = snapshot
+            .nominations
+            .into_iter()
+            .map(|bond| {
+                let bonded = <NominatorState<T>>::get(&bond.owner)
+                    .and_then(|state| state.get_bond_amount(collator))
+                    .unwrap_or(bond.amount);
+                (bond.owner, fraction * bonded)
+            })
+            .filter(|(_, amount)| !amount.is_zero())
+            .collect::<Vec<_>>();
+        let slash = UnappliedSlash {
+            collator: collator.clone(),
+            era,
+            own: fraction * snapshot.bond,
+            others: BoundedVec::try_from(others)
+                .expect("no more than the nominations of the bounded snapshot; qed"),
+        };
+
+        let apply_era = now.saturating_add(T::SlashDeferDuration::get());
+        if apply_era != now {
+            <UnappliedSlashes<T>>::try_append(apply_era, &slash)
+                .map_err(|_| <Error<T>>::TooManyUnappliedSlashes)?;
+        }
+        Self::deposit_event(Event::SlashReported {
+            collator: collator.clone(),
+            era,
+            fraction,
+            apply_era,
+        });
+        if apply_era == now {
+            Self::apply_slash(slash);
+        }
+        Ok(())
+    }
+
+    /// Number of eras the `AtStake` snapshots are kept for, to pay out and to slash their era.
+    fn at_stake_history_depth() -> EraIndex {
+        T::SlashableEras::get().max(<StakingParams<T>>::get().reward_payment_delay)
+    }
+
+    /// Removes the snapshots of the era leaving the history when era `now` starts.
+    /// Returns the weight consumed.
+    pub(crate) fn prune_expired_at_stake(now: EraIndex) -> Weight {
+        let expired = now.saturating_sub(Self::at_stake_history_depth().saturating_add(1));
+        T::DbWeight::get().reads(1).saturating_add(Self::prune_at_stake(expired, now))
+    }
+
+    /// Removes the snapshots of `era` if it left the history by era `now` and is fully paid out.
+    /// Eras still paid out when they leave the history are pruned once they are paid.
+    /// Returns the weight consumed.
+    pub(crate) fn prune_at_stake(era: EraIndex, now: EraIndex) -> Weight {
+        let weight = T::DbWeight::get().reads(2);
+        if era.saturating_add(Self::at_stake_history_depth()) >= now ||
+            <DelayedPayouts<T>>::contains_key(era)
+        {
+            return weight
+        }
+        let removed = <AtStake<T>>::clear_prefix(era, u32::MAX, None).unique;
+        weight.saturating_add(T::DbWeight::get().writes(removed.into()))
+    }
+
+    /// Removes the reported offences of the era leaving the `T::SlashableEras` window when era
+    /// `now` starts. Returns the weight consumed.
+    pub(crate) fn prune_processed_offences(now: EraIndex) -> Weight {
+        let expired = match now.checked_sub(T::SlashableEras::get().saturating_add(1)) {
+            Some(expired) => expired,
+            None => return 0,
+        };
+        let removed = <ProcessedOffences<T>>::clear_prefix(expired, u32::MAX, None).unique;
+        T::DbWeight::get().writes(removed.into())
+    }
+
+    /// Forces an active candidate offline, out of the candidate pool, until it calls `go_online`.
+    /// Returns true if the candidate was active.
+    pub(crate) fn chill_candidate(candidate: &T::AccountId) -> bool {
+        let mut state = match <CandidateInfo<T>>::get(candidate) {
+            Some(state) if state.is_active() => state,
+            _ => return false,
+        };
+        state.go_offline();
+        Self::candidate_pool_remove(candidate);
+        <CandidateInfo<T>>::insert(candidate, state);
+        true
+    }
+
+    /// Cancels the slashes deferred to `era` at the given sorted `slash_indices`.
+    pub(crate) fn cancel_unapplied_slashes(
+        era: EraIndex,
+        slash_indices: Vec<u32>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(!slash_indices.is_empty(), <Error<T>>::NoSlashesToCancel);
+        ensure!(
+            slash_indices.windows(2).all(|pair| pair[0] < pair[1]),
+            <Error<T>>::SlashIndicesNotSortedAndUnique
+        );
+        let mut slashes = <UnappliedSlashes<T>>::get(era);
+        let last = *slash_indices.last().expect("slash_indices is not empty; qed");
+        ensure!((last as usize) < slashes.len(), <Error<T>>::InvalidSlashIndex);
+
+        // remove from the back so the indices of the remaining slashes are not shifted
+        for index in slash_indices.into_iter().rev() {
+            let slash = slashes.remove(index as usize);
+            Self::deposit_event(Event::SlashCancelled { collator: slash.collator, era: slash.era });
+        }
+        if slashes.is_empty() {
+            <UnappliedSlashes<T>>::remove(era);
+        } else {
+            <UnappliedSlashes<T>>::insert(era, slashes);
+        }
+        Ok(().into())
+    }
+
+    /// Applies all the slashes deferred to `era`.
+    /// Returns the weight consumed.
+    pub(crate) fn apply_unapplied_slashes(era: EraIndex) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        for slash in <UnappliedSlashes<T>>::take(era) {
+            weight = weight.saturating_add(T::WeightInfo::apply_slash(slash.others.len() as u32));
+            Self::apply_slash(slash);
+        }
+        weight
+    }
+
+    /// Slashes the collator self bond and the nominations of an [UnappliedSlash], capped at what
+    /// is still bonded towards the collator, and hands the funds to `T::Slash`.
+    /// A nomination slashed in full remains with a zero bond until it is revoked.
+    pub(crate) fn apply_slash(
+        slash: UnappliedSlash<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
+    ) {
+        let mut imbalance = NegativeImbalanceOf::<T>::zero();
+        let collator = slash.collator;
+
+        if let Some(mut state) = <CandidateInfo<T>>::get(&collator) {
+            let amount = slash.own.min(state.bond);
+            if !amount.is_zero() {
+                state.bond = state.bond.saturating_sub(amount);
+                state.total_counted = state.total_counted.saturating_sub(amount);
+                <Total<T>>::mutate(|total| *total = total.saturating_sub(amount));
+                T::Currency::set_lock(
+                    COLLATOR_LOCK_ID,
+                    &collator,
+                    state.bond,
+                    WithdrawReasons::all(),
+                );
+                imbalance.subsume(T::Currency::slash(&collator, amount).0);
+                // a pending bond decrease must not take the bond below the minimum
+                if let Some(request) = state.request {
+                    if state.bond.saturating_sub(request.amount) <
+                        <StakingParams<T>>::get().min_candidate_stk
+                    {
+                        state.request = None;
+                        Self::deposit_event(Event::CancelledCandidateBondLess {
+                            candidate: collator.clone(),
+                            amount: request.amount,
+                            execute_era: request.when_executable,
+                        });
+                    }
+                }
+                if state.is_active() {
+                    Self::update_active(collator.clone(), state.total_counted);
+                }
+                <CandidateInfo<T>>::insert(&collator, state);
+                Self::deposit_event(Event::Slashed { account: collator.clone(), amount });
+            }
+        }
+
+        for (nominator, amount) in slash.others {
+            match Self::slash_nomination(&collator, &nominator, amount) {
+                Ok(Some((slashed, amount))) => {
+                    imbalance.subsume(slashed);
+                    Self::deposit_event(Event::Slashed { account: nominator, amount });
+                },
+                Ok(None) => (),
+                Err(error) => log::warn!(
+                    "Slashing nominator {:?} of collator {:?} failed with error: {:?}",
+                    nominator,
+                    collator,
+                    error
+                ),
+            }
+        }
+
+        T::Slash::on_unbalanced(imbalance);
+    }
+
+    /// Slashes up to `amount` from the nomination of `nominator` towards `collator`.
+    /// Returns the slashed funds and amount, or None if nothing is bonded anymore.
+    fn slash_nomination(
+        collator: &T::AccountId,
+        nominator: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<Option<(NegativeImbalanceOf<T>, BalanceOf<T>)>, DispatchError> {
+        let mut state = match <NominatorState<T>>::get(nominator) {
+            Some(state) => state,
+            None => return Ok(None),
+        };
+        let bond = match state.get_bond_amount(collator) {
+            Some(bond) => bond,
+            None => return Ok(None),
+        };
+        let amount = amount.min(bond);
+        if amount.is_zero() {
+            return Ok(None)
+        }
+        let mut candidate = <CandidateInfo<T>>::get(collator).ok_or(<Error<T>>::CandidateDNE)?;
+        // updates the candidate pool if the counted stake changes
+        candidate.decrease_nomination::<T>(collator, nominator.clone(), bond, amount)?;
+        <CandidateInfo<T>>::insert(collator, candidate);
+        <Total<T>>::mutate(|total| *total = total.saturating_sub(amount));
+
+        for nomination in state.nominations.0.iter_mut() {
+            if &nomination.owner == collator {
+                nomination.amount = nomination.amount.saturating_sub(amount);
+            }
+        }
+        state.total_sub::<T>(amount)?;
+
+        // a pending revoke must unbond what is left of the nomination, and a pending decrease
+        // must not take the nomination or the nominator stake below the minimum
+        let remaining = bond.saturating_sub(amount);
+        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(collator);
+        if let Some(index) =
+            scheduled_requests.iter().position(|request| &request.nominator == nominator)
+        {
+            match scheduled_requests[index].action {
+                NominationAction::Revoke(revoke_amount) => {
+                    state.less_total =
+                        state.less_total.saturating_sub(revoke_amount.saturating_sub(remaining));
+                    scheduled_requests[index].action = NominationAction::Revoke(remaining);
+                    <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                },
+                NominationAction::Decrease(decrease_amount)
+                    if remaining.saturating_sub(decrease_amount) <
+                        <StakingParams<T>>::get().min_nomination ||
+                        state.total.saturating_sub(decrease_amount) <
+                            <StakingParams<T>>::get().min_nominator_stk =>
+                {
+                    let request = scheduled_requests.remove(index);
+                    state.less_total = state.less_total.saturating_sub(decrease_amount);
+                    <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                    Self::deposit_event(Event::CancelledNominationRequest {
+                        nominator: nominator.clone(),
+                        collator: collator.clone(),
+                        cancelled_request: request.into(),
+                    });
+                },
+                NominationAction::Decrease(_) => (),
+            }
+        }
+        <NominatorState<T>>::insert(nominator, state);
+        Self::liquid_note_slash(nominator, amount);
+
+        Ok(Some((T::Currency::slash(nominator, amount).0, amount)))
+    }
+}
+
+impl<T: Config> ReportOffence<T::AccountId> for Pallet<T> {
+    /// Offenders without an `AtStake` snapshot for the era are chilled but not slashed.
+    /// Offences already reported, or committed more than `T::SlashableEras` eras ago, are ignored.
+    fn report_offence(offenders: &[(T::AccountId, EraIndex, Perbill)]) -> Weight {
+        let now = <Era<T>>::get().current;
+        let mut weight = T::DbWeight::get().reads(1);
+        for (offender, era, fraction) in offenders {
+            if era.saturating_add(T::SlashableEras::get()) < now ||
+                <ProcessedOffences<T>>::contains_key(era, offender)
+            {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                continue
+            }
+            // an offence which could not be slashed can be reported again
+            match Self::slash_collator(offender, *era, *fraction) {
+                Ok(()) => <ProcessedOffences<T>>::insert(era, offender, ()),
+                Err(error) => log::warn!(
+                    "Slashing offender {:?} for era {:?} failed with error: {:?}",
+                    offender,
+                    era,
+                    error
+                ),
+            }
+            if Self::chill_candidate(offender) {
+                Self::deposit_event(Event::CandidateChilled { candidate: offender.clone() });
+            }
+            // the bonded amount of each nomination in the snapshot is read
+            weight = weight.saturating_add(T::WeightInfo::report_offence()).saturating_add(
+                T::DbWeight::get().reads(T::MaxTopNominationsPerCandidate::get().into()),
+            );
+            if T::SlashDeferDuration::get().is_zero() {
+                weight = weight.saturating_add(T::WeightInfo::apply_slash(
+                    T::MaxTopNominationsPerCandidate::get(),
+                ));
+            }
+        }
+        weight
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+use crate::mock::{roll_one_block, ExtBuilder, Origin, ParachainStaking};
+use frame_support::assert_ok;
+use nimbus_primitives::{AccountLookup, CanAuthor, EventHandler, NimbusId};
+use parity_scale_codec::Encode;
+use sp_runtime::Percent;
+
+fn nimbus_id(account: u64) -> NimbusId {
+    let mut raw = [0u8; 32];
+    raw[..8].copy_from_slice(&account.encode());
+    NimbusId::from(sp_core::sr25519::Public::from_raw(raw))
+}
+
+#[test]
+fn all_selected_candidates_can_author_without_eligible_ratio() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::eligible_authors(0), vec![1, 2, 3, 4, 5]);
+            for account in 1..=5 {
+                assert!(<ParachainStaking as CanAuthor<u64>>::can_author(&account, &0));
+            }
+            assert!(!<ParachainStaking as CanAuthor<u64>>::can_author(&6, &0));
+        });
+}
+
+#[test]
+fn eligible_ratio_selects_rounded_up_subset_of_selected_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_eligible_ratio(
+                Origin::root(),
+                Some(Percent::from_percent(50))
+            ));
+            for slot in 0..10 {
+                let eligible = ParachainStaking::eligible_authors(slot);
+                assert_eq!(eligible.len(), 3);
+                let mut sorted = eligible.clone();
+                sorted.sort();
+                sorted.dedup();
+                assert_eq!(sorted, eligible);
+                for account in 1..=5 {
+                    assert_eq!(
+                        <ParachainStaking as CanAuthor<u64>>::can_author(&account, &slot),
+                        eligible.contains(&account)
+                    );
+                }
+            }
+        });
+}
+
+#[test]
+fn at_least_one_selected_candidate_is_eligible() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_eligible_ratio(
+                Origin::root(),
+                Some(Percent::from_percent(1))
+            ));
+            assert_eq!(ParachainStaking::eligible_authors(0).len(), 1);
+        });
+}
+
+#[test]
+fn eligible_authors_change_with_slot_and_randomness() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_eligible_ratio(
+                Origin::root(),
+                Some(Percent::from_percent(20))
+            ));
+            let eligible: Vec<_> = (0..20).map(ParachainStaking::eligible_authors).collect();
+            assert!(eligible.iter().any(|authors| authors != &eligible[0]));
+            assert_eq!(ParachainStaking::eligible_authors(0), eligible[0]);
+            roll_one_block();
+            let eligible_next_block: Vec<_> =
+                (0..20).map(ParachainStaking::eligible_authors).collect();
+            assert_ne!(eligible_next_block, eligible);
+        });
+}
+
+#[test]
+fn nimbus_note_author_awards_points() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            <ParachainStaking as EventHandler<u64>>::note_author(1);
+            assert_eq!(ParachainStaking::awarded_pts(1, 1), 20);
+            assert_eq!(ParachainStaking::points(1), 20);
+            assert_eq!(ParachainStaking::authored_blocks(1, 1), 1);
+        });
+}
+
+#[test]
+fn lookup_account_returns_selected_candidates_only() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                <ParachainStaking as AccountLookup<u64>>::lookup_account(&nimbus_id(1)),
+                Some(1)
+            );
+            assert_eq!(
+                <ParachainStaking as AccountLookup<u64>>::lookup_account(&nimbus_id(6)),
+                None
+            );
+        });
+}
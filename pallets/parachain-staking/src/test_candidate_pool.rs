@@ -0,0 +1,99 @@
+#[cfg(test)]
+use crate::mock::{ExtBuilder, Origin, ParachainStaking};
+use crate::{candidate_pool::bag_index, Bond};
+use frame_support::assert_ok;
+
+fn bonds(bonds: Vec<(u64, u128)>) -> Vec<Bond<u64, u128>> {
+    bonds.into_iter().map(|(owner, amount)| Bond { owner, amount }).collect()
+}
+
+#[test]
+fn bag_index_increases_with_amount() {
+    assert_eq!(bag_index(0u128), 0);
+    assert_eq!(bag_index(1u128), 1);
+    // 16 bags per doubling of the amount
+    assert_eq!(bag_index(16u128), 65);
+    assert_eq!(bag_index(17u128), 66);
+    assert_eq!(bag_index(32u128), 81);
+    for amount in 0u128..10_000 {
+        assert!(bag_index(amount) <= bag_index(amount + 1));
+    }
+    assert!(bag_index(u128::MAX) > bag_index(u128::MAX >> 1));
+}
+
+#[test]
+fn candidate_pool_is_ordered_by_stake_then_account() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 1000)])
+        .with_candidates(vec![(1, 10), (2, 20), (3, 30), (4, 20), (5, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::candidate_pool_count(), 5);
+            assert_eq!(
+                ParachainStaking::candidate_pool(),
+                bonds(vec![(5, 1000), (3, 30), (4, 20), (2, 20), (1, 10)])
+            );
+            assert_eq!(
+                ParachainStaking::top_candidate_pool(3),
+                bonds(vec![(5, 1000), (3, 30), (4, 20)])
+            );
+            assert!(ParachainStaking::top_candidate_pool(0).is_empty());
+        });
+}
+
+#[test]
+fn candidates_in_the_same_bag_are_ordered_by_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        // 64 to 67 share a bag
+        .with_candidates(vec![(1, 67), (2, 64), (3, 66)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(bag_index(64u128), bag_index(67u128));
+            assert_eq!(ParachainStaking::non_empty_candidate_pool_bags().len(), 1);
+            assert_eq!(ParachainStaking::top_candidate_pool(2), bonds(vec![(1, 67), (3, 66)]));
+        });
+}
+
+#[test]
+fn updating_stake_moves_candidate_to_its_new_bag() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 10), (2, 20)])
+        .build()
+        .execute_with(|| {
+            let old_bag = bag_index(10u128);
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 20));
+            let node = ParachainStaking::candidate_pool_node(1).unwrap();
+            assert_eq!(node.amount, 30);
+            assert_eq!(node.bag, bag_index(30u128));
+            assert!(ParachainStaking::candidate_pool_bag(old_bag).is_none());
+            assert!(!ParachainStaking::non_empty_candidate_pool_bags().contains(&old_bag));
+            assert_eq!(ParachainStaking::candidate_pool(), bonds(vec![(1, 30), (2, 20)]));
+        });
+}
+
+#[test]
+fn removing_candidate_relinks_its_bag() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(2)));
+            assert!(ParachainStaking::candidate_pool_node(2).is_none());
+            assert_eq!(ParachainStaking::candidate_pool_node(1).unwrap().next, Some(3));
+            assert_eq!(ParachainStaking::candidate_pool_node(3).unwrap().prev, Some(1));
+            assert_eq!(ParachainStaking::candidate_pool_count(), 2);
+
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(3)));
+            assert!(ParachainStaking::candidate_pool_bag(bag_index(20u128)).is_none());
+            assert!(ParachainStaking::non_empty_candidate_pool_bags().is_empty());
+            assert_eq!(ParachainStaking::candidate_pool_count(), 0);
+
+            assert_ok!(ParachainStaking::go_online(Origin::signed(2)));
+            let bag = ParachainStaking::candidate_pool_bag(bag_index(20u128)).unwrap();
+            assert_eq!((bag.head, bag.tail), (Some(2), Some(2)));
+        });
+}
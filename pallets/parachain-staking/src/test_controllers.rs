@@ -0,0 +1,216 @@
+#[cfg(test)]
+use crate::mock::{roll_to_era_begin, ExtBuilder, Origin, ParachainStaking, Test};
+use crate::{assert_event_emitted, Error, Event, Payee, RewardDestination};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{Perbill, Percent};
+
+fn set_controllers() {
+    assert_ok!(ParachainStaking::set_controller(Origin::signed(1), 11));
+    assert_ok!(ParachainStaking::set_controller(Origin::signed(2), 12));
+}
+
+#[test]
+fn set_controller_maps_both_ways() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            assert_eq!(ParachainStaking::controller(1), Some(11));
+            assert_eq!(ParachainStaking::stash(11), Some(1));
+            assert_event_emitted!(Event::ControllerSet { stash: 1, old: None, new: 11 });
+
+            assert_ok!(ParachainStaking::set_controller(Origin::signed(1), 21));
+            assert_eq!(ParachainStaking::stash(11), None);
+            assert_eq!(ParachainStaking::stash(21), Some(1));
+            assert_event_emitted!(Event::ControllerSet { stash: 1, old: Some(11), new: 21 });
+
+            assert_ok!(ParachainStaking::clear_controller(Origin::signed(1)));
+            assert_eq!(ParachainStaking::controller(1), None);
+            assert_eq!(ParachainStaking::stash(21), None);
+            assert_event_emitted!(Event::ControllerCleared { stash: 1, controller: 21 });
+            assert_noop!(
+                ParachainStaking::clear_controller(Origin::signed(1)),
+                Error::<Test>::NoController
+            );
+        });
+}
+
+#[test]
+fn invalid_controllers_are_rejected() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(3), 13),
+                Error::<Test>::CannotSetControllerIfNotStaker
+            );
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(1), 1),
+                Error::<Test>::InvalidController
+            );
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(1), 2),
+                Error::<Test>::InvalidController
+            );
+            set_controllers();
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(1), 11),
+                Error::<Test>::NoWritingSameValue
+            );
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(1), 12),
+                Error::<Test>::ControllerAlreadyPaired
+            );
+            // a controller cannot pick its own controller
+            assert_noop!(
+                ParachainStaking::set_controller(Origin::signed(11), 13),
+                Error::<Test>::CannotSetControllerIfNotStaker
+            );
+        });
+}
+
+#[test]
+fn controller_runs_the_operational_calls_of_the_collator() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(11)));
+            assert_event_emitted!(Event::CandidateWentOffline { candidate: 1 });
+            assert_ok!(ParachainStaking::go_online(Origin::signed(11)));
+            assert_event_emitted!(Event::CandidateBackOnline { candidate: 1 });
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(11),
+                Perbill::from_percent(7)
+            ));
+            assert!(ParachainStaking::candidate_info(1)
+                .and_then(|info| info.commission_request)
+                .is_some());
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(11), 1));
+            assert!(ParachainStaking::candidate_info(1).map_or(false, |info| info.is_leaving()));
+        });
+}
+
+#[test]
+fn controller_runs_the_operational_calls_of_the_nominator() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(12), 1, 2));
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(12), 1));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(12), 1));
+            assert!(ParachainStaking::nomination_request_revoke_exists(&1, &2));
+        });
+}
+
+#[test]
+fn controller_cannot_move_or_add_funds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            // calls handling funds act for the signer itself
+            assert_noop!(
+                ParachainStaking::candidate_bond_more(Origin::signed(11), 5),
+                Error::<Test>::CandidateDNE
+            );
+            assert_noop!(
+                ParachainStaking::set_payee(Origin::signed(12), RewardDestination::Account(12)),
+                Error::<Test>::ControllerCanOnlySetBondedPayee
+            );
+            assert_noop!(
+                ParachainStaking::set_payee(Origin::signed(12), RewardDestination::Restake),
+                Error::<Test>::ControllerCanOnlySetBondedPayee
+            );
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(2), RewardDestination::Restake));
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(12), RewardDestination::Bonded));
+            assert_eq!(<Payee<Test>>::get(2), RewardDestination::Bonded);
+
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(12),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::ControllerCannotAutoCompound
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(12),
+                1,
+                Percent::zero(),
+                1,
+                1
+            ));
+            assert_eq!(ParachainStaking::auto_compounding_nominations(1).len(), 0);
+        });
+}
+
+#[test]
+fn paired_controller_cannot_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            assert_noop!(
+                ParachainStaking::join_candidates(Origin::signed(11), 10, 1),
+                Error::<Test>::ControllerCannotStake
+            );
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(11), 1, 10, 1, 0),
+                Error::<Test>::ControllerCannotStake
+            );
+        });
+}
+
+#[test]
+fn pair_is_removed_when_the_stash_stops_staking() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (11, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_controllers();
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(12), 1));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(11), 1));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(3), 2, 1));
+            assert_eq!(ParachainStaking::controller(2), None);
+            assert_eq!(ParachainStaking::stash(12), None);
+            assert_event_emitted!(Event::ControllerCleared { stash: 2, controller: 12 });
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0));
+            assert_eq!(ParachainStaking::controller(1), None);
+            assert_eq!(ParachainStaking::stash(11), None);
+
+            // the former controller can now stake on its own
+            assert_ok!(ParachainStaking::join_candidates(Origin::signed(11), 10, 0));
+        });
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to_era_begin, set_author, set_reward_pot, Balances, ExtBuilder, Origin, ParachainStaking,
+    SessionPeriod, SessionsPerEra,
+};
+use crate::{
+    assert_event_emitted, assert_event_not_emitted,
+    inflation::{perbill_annual_to_perbill_era, BLOCKS_PER_YEAR},
+    Event, InflationInfo, Range, RewardDestination,
+};
+use frame_support::assert_ok;
+use sp_runtime::Perbill;
+
+fn era_inflation(min: u32, ideal: u32, max: u32) -> InflationInfo {
+    InflationInfo {
+        expect: Range {
+            min: Perbill::from_percent(10),
+            ideal: Perbill::from_percent(20),
+            max: Perbill::from_percent(30),
+        },
+        annual: Range::default(),
+        era: Range {
+            min: Perbill::from_percent(min),
+            ideal: Perbill::from_percent(ideal),
+            max: Perbill::from_percent(max),
+        },
+    }
+}
+
+fn assert_close(actual: Perbill, expected: Perbill) {
+    let difference = actual.deconstruct().abs_diff(expected.deconstruct());
+    assert!(difference <= 10, "{:?} is not close to {:?}", actual, expected);
+}
+
+#[test]
+fn annual_inflation_is_compounded_per_era() {
+    // 1% a month compounds to 12.682503% a year
+    let era = perbill_annual_to_perbill_era(
+        Range {
+            min: Perbill::zero(),
+            ideal: Perbill::from_parts(126_825_030),
+            max: Perbill::from_percent(100),
+        },
+        12,
+    );
+    assert_eq!(era.min, Perbill::zero());
+    assert_close(era.ideal, Perbill::from_percent(1));
+    // 2^(1/12) - 1
+    assert_close(era.max, Perbill::from_parts(59_463_094));
+}
+
+#[test]
+fn issuance_is_scaled_by_stake_between_expectations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 500), (2, 500)])
+        .with_inflation(era_inflation(1, 2, 4))
+        .build()
+        .execute_with(|| {
+            // 1%, 2% and 4% of the total issuance of 1000, as 10%, 20% and 30% of it is staked
+            assert_eq!(ParachainStaking::compute_issuance(50), 10);
+            assert_eq!(ParachainStaking::compute_issuance(100), 10);
+            assert_eq!(ParachainStaking::compute_issuance(150), 15);
+            assert_eq!(ParachainStaking::compute_issuance(200), 20);
+            assert_eq!(ParachainStaking::compute_issuance(250), 30);
+            assert_eq!(ParachainStaking::compute_issuance(300), 40);
+            assert_eq!(ParachainStaking::compute_issuance(400), 40);
+        });
+}
+
+#[test]
+fn no_issuance_without_inflation_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 1000)])
+        .with_candidates(vec![(1, 500)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::compute_issuance(500), 0);
+            set_author(1, 1, 20);
+            set_reward_pot(10);
+            roll_to_era_begin(3);
+            assert_event_not_emitted!(Event::InflationMinted { era: 2, amount: 0 });
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 10,
+                destination: RewardDestination::Bonded,
+            });
+        });
+}
+
+#[test]
+fn inflation_is_minted_into_reward_pot_at_era_start() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 1000)])
+        .with_candidates(vec![(1, 500)])
+        .with_inflation(era_inflation(1, 1, 1))
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 20);
+            roll_to_era_begin(2);
+            assert_event_emitted!(Event::InflationMinted { era: 2, amount: 10 });
+            assert_eq!(
+                Balances::free_balance(&ParachainStaking::compute_reward_pot_account_id()),
+                10
+            );
+            // the pot is paid out with the rewards of era 1, before the inflation of era 3 is
+            // minted
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 10,
+                destination: RewardDestination::Bonded,
+            });
+            assert_event_emitted!(Event::InflationMinted { era: 3, amount: 10 });
+            assert_eq!(Balances::total_issuance(), 1020);
+        });
+}
+
+#[test]
+fn inflation_is_paid_alongside_fees_in_reward_pot() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 1000)])
+        .with_candidates(vec![(1, 500)])
+        .with_inflation(era_inflation(1, 1, 1))
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 20);
+            set_reward_pot(5);
+            roll_to_era_begin(3);
+            // 1% of the total issuance of 1005
+            assert_event_emitted!(Event::InflationMinted { era: 2, amount: 10 });
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 15,
+                destination: RewardDestination::Bonded,
+            });
+        });
+}
+
+#[test]
+fn era_inflation_is_reset_with_blocks_per_era() {
+    ExtBuilder::default().build().execute_with(|| {
+        let annual = Range {
+            min: Perbill::from_percent(3),
+            ideal: Perbill::from_percent(4),
+            max: Perbill::from_percent(5),
+        };
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), annual));
+        assert_eq!(
+            ParachainStaking::inflation_config().era,
+            perbill_annual_to_perbill_era(annual, BLOCKS_PER_YEAR / 5)
+        );
+        assert_ok!(ParachainStaking::set_blocks_per_era(Origin::root(), 10));
+        assert_eq!(
+            ParachainStaking::inflation_config().era,
+            perbill_annual_to_perbill_era(annual, BLOCKS_PER_YEAR / 10)
+        );
+    });
+}
+
+#[test]
+fn era_inflation_of_eras_made_of_sessions_follows_the_session_length() {
+    ExtBuilder::default().build().execute_with(|| {
+        SessionsPerEra::set(2);
+        SessionPeriod::set(10);
+        let annual = Range {
+            min: Perbill::from_percent(3),
+            ideal: Perbill::from_percent(4),
+            max: Perbill::from_percent(5),
+        };
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), annual));
+        assert_eq!(
+            ParachainStaking::inflation_config().era,
+            perbill_annual_to_perbill_era(annual, BLOCKS_PER_YEAR / 20)
+        );
+    });
+}
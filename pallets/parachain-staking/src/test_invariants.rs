@@ -0,0 +1,191 @@
+#[cfg(test)]
+use crate::mock::{
+    query_lock_amount, roll_to_era_begin, ExtBuilder, Origin, ParachainStaking, Test,
+};
+use crate::{
+    assert_event_emitted, BottomNominations, CandidateInfo, Error, Event, NominatorState,
+    TopNominations, Total, NOMINATOR_LOCK_ID,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn try_state_holds_through_staking_operations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::do_try_state());
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(4), 1, 5));
+            assert_ok!(ParachainStaking::do_try_state());
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(3), 2));
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(3), 1, 10));
+            assert_ok!(ParachainStaking::do_try_state());
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(2)));
+            assert_ok!(ParachainStaking::do_try_state());
+            roll_to_era_begin(4);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(3), 3, 2));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn try_state_detects_total_mismatch() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <Total<Test>>::mutate(|total| *total += 1);
+            assert_eq!(
+                ParachainStaking::do_try_state(),
+                Err("Total is not the sum of the candidate bonds and nominations")
+            );
+        });
+}
+
+#[test]
+fn try_state_detects_candidate_pool_mismatch() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <CandidateInfo<Test>>::mutate(1, |state| {
+                let state = state.as_mut().expect("candidate exists");
+                state.bond += 1;
+                state.total_counted += 1;
+            });
+            <Total<Test>>::mutate(|total| *total += 1);
+            assert_eq!(
+                ParachainStaking::do_try_state(),
+                Err("candidate is not in the candidate pool with its total_counted")
+            );
+        });
+}
+
+#[test]
+fn try_state_detects_nomination_count_mismatch() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <CandidateInfo<Test>>::mutate(1, |state| {
+                state.as_mut().expect("candidate exists").nomination_count = 3
+            });
+            assert_eq!(
+                ParachainStaking::do_try_state(),
+                Err("nomination_count does not count the top and bottom nominations")
+            );
+        });
+}
+
+#[test]
+fn try_state_detects_nominator_less_total_mismatch() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <NominatorState<Test>>::mutate(3, |state| {
+                state.as_mut().expect("nominator exists").less_total = 5
+            });
+            assert_eq!(
+                ParachainStaking::do_try_state(),
+                Err("nominator less_total is not the sum of its pending requests")
+            );
+        });
+}
+
+#[test]
+fn repair_staking_state_recomputes_derived_values() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <TopNominations<Test>>::mutate(1, |top| {
+                top.as_mut().expect("candidate exists").total = 0
+            });
+            <CandidateInfo<Test>>::mutate(1, |state| {
+                let state = state.as_mut().expect("candidate exists");
+                state.total_counted = 50;
+                state.nomination_count = 0;
+            });
+            <BottomNominations<Test>>::mutate(2, |bottom| {
+                bottom.as_mut().expect("candidate exists").total = 7
+            });
+            <NominatorState<Test>>::mutate(3, |state| {
+                let state = state.as_mut().expect("nominator exists");
+                state.total = 60;
+                state.less_total = 5;
+            });
+            <Total<Test>>::put(0);
+            assert!(ParachainStaking::do_try_state().is_err());
+
+            assert_ok!(ParachainStaking::repair_staking_state(Origin::root(), vec![1, 2, 3, 4], 2));
+            assert_ok!(ParachainStaking::do_try_state());
+            assert_event_emitted!(Event::StakingStateRepaired { account: 1 });
+            assert_event_emitted!(Event::StakingStateRepaired { account: 2 });
+            assert_event_emitted!(Event::StakingStateRepaired { account: 3 });
+            assert_event_emitted!(Event::TotalRepaired { old: 0, new: 150 });
+            let state = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!((state.total_counted, state.nomination_count), (90, 2));
+            assert_eq!(ParachainStaking::candidate_pool_node(1).expect("in pool").amount, 90);
+            assert_eq!(query_lock_amount(3, NOMINATOR_LOCK_ID), Some(50));
+        });
+}
+
+#[test]
+fn repair_staking_state_leaves_consistent_state_unchanged() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::repair_staking_state(Origin::root(), vec![1, 3], 2));
+            assert!(!crate::mock::events().iter().any(|event| matches!(
+                event,
+                Event::StakingStateRepaired { .. } | Event::TotalRepaired { .. }
+            )));
+        });
+}
+
+#[test]
+fn repair_staking_state_is_root_only() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::repair_staking_state(Origin::signed(1), vec![1], 2),
+                DispatchError::BadOrigin
+            );
+        });
+}
+
+#[test]
+fn repair_staking_state_checks_candidate_count_hint() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 50), (2, 40)])
+        .with_nominations(vec![(3, 1, 30), (3, 2, 20), (4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::repair_staking_state(Origin::root(), vec![1], 1),
+                Error::<Test>::TooLowCandidateCountWeightHintRepairStakingState
+            );
+        });
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+use crate::mock::{
+    nomination_amount, roll_one_block, roll_to_era_begin, set_author, set_reward_pot, Assets,
+    Balances, ExtBuilder, LiquidStakingAssetId, Origin, ParachainStaking, Test, LIQUID_ASSET,
+};
+use crate::{
+    assert_event_emitted, Error, Event, LiquidStakingStrategy, NominationAction, Payee,
+    RewardDestination,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::{traits::One, DispatchError, FixedPointNumber, FixedU128, Perbill};
+
+fn liquid_nomination(candidate: u64) -> Option<u128> {
+    nomination_amount(ParachainStaking::liquid_staking_account(), candidate)
+}
+
+#[test]
+fn liquid_stake_mints_the_derivative_at_par_first() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 10));
+            assert_eq!(Assets::balance(LIQUID_ASSET, 3), 10);
+            assert_eq!(Balances::free_balance(&3), 90);
+            assert_eq!(liquid_nomination(1), Some(10));
+            assert_eq!(
+                <Payee<Test>>::get(ParachainStaking::liquid_staking_account()),
+                RewardDestination::Restake
+            );
+            assert_eq!(ParachainStaking::liquid_exchange_rate(), FixedU128::one());
+            assert_event_emitted!(Event::LiquidStaked { staker: 3, amount: 10, minted: 10 });
+        });
+}
+
+#[test]
+fn disabled_liquid_staking_rejects_deposits() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            LiquidStakingAssetId::set(None);
+            assert_noop!(
+                ParachainStaking::liquid_stake(Origin::signed(3), 10),
+                Error::<Test>::LiquidStakingDisabled
+            );
+        });
+}
+
+#[test]
+fn equal_strategy_spreads_the_stake_over_the_selected_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 10));
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(4), 10));
+            assert_eq!(liquid_nomination(1), Some(10));
+            assert_eq!(liquid_nomination(2), Some(10));
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 6));
+            assert_eq!(liquid_nomination(1), Some(16));
+        });
+}
+
+#[test]
+fn least_backed_strategy_bonds_with_the_least_backed_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_liquid_strategy(
+                    Origin::signed(3),
+                    LiquidStakingStrategy::LeastBacked
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(ParachainStaking::set_liquid_strategy(
+                Origin::root(),
+                LiquidStakingStrategy::LeastBacked
+            ));
+            assert_event_emitted!(Event::LiquidStrategySet {
+                old: LiquidStakingStrategy::Equal,
+                new: LiquidStakingStrategy::LeastBacked,
+            });
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 5));
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 4));
+            assert_eq!(liquid_nomination(2), Some(9));
+            assert_eq!(liquid_nomination(1), None);
+        });
+}
+
+#[test]
+fn exchange_rate_rises_as_rewards_are_restaked() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 10));
+            roll_to_era_begin(2);
+            set_author(2, 1, 1);
+            set_reward_pot(35);
+            roll_to_era_begin(4);
+            roll_one_block();
+            let restaked = liquid_nomination(1).expect("liquid nomination exists") - 10;
+            assert!(restaked > 0);
+            assert_eq!(
+                ParachainStaking::liquid_exchange_rate(),
+                FixedU128::saturating_from_rational(10 + restaked, 10)
+            );
+
+            // later stakers get less of the derivative for the same stake
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(4), 10));
+            let minted = Assets::balance(LIQUID_ASSET, 4);
+            assert_eq!(minted, 100 / (10 + restaked));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn transfers_to_the_liquid_staking_account_do_not_change_the_exchange_rate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 5));
+            // the first staker sends funds to inflate what the derivative is worth
+            assert_ok!(Balances::transfer(
+                Origin::signed(3),
+                ParachainStaking::liquid_staking_account(),
+                50
+            ));
+            assert_eq!(ParachainStaking::liquid_exchange_rate(), FixedU128::one());
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(4), 10));
+            assert_eq!(Assets::balance(LIQUID_ASSET, 4), 10);
+            assert_eq!(ParachainStaking::liquid_staked_value(), 15);
+        });
+}
+
+#[test]
+fn liquid_unstake_withdraws_after_the_decrease_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 10));
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(4), 10));
+            assert_noop!(
+                ParachainStaking::liquid_unstake(Origin::signed(3), 11),
+                Error::<Test>::InsufficientBalance
+            );
+            assert_noop!(
+                ParachainStaking::liquid_unstake(Origin::signed(3), 0),
+                Error::<Test>::LiquidAmountTooLow
+            );
+            assert_ok!(ParachainStaking::liquid_unstake(Origin::signed(3), 4));
+            assert_event_emitted!(Event::LiquidUnstaked { staker: 3, burnt: 4, amount: 4 });
+            assert_eq!(Assets::balance(LIQUID_ASSET, 3), 6);
+            let unbondings = ParachainStaking::liquid_unbondings(3);
+            assert_eq!(unbondings.len(), 1);
+            assert_eq!((unbondings[0].candidate, unbondings[0].amount), (1, 4));
+            assert_eq!(unbondings[0].when_executable, 3);
+            let requests = ParachainStaking::nomination_scheduled_requests(1);
+            assert_eq!(requests[0].action, NominationAction::Decrease(4));
+            // the stake unbonding is not worth any of the derivative left
+            assert_eq!(ParachainStaking::liquid_exchange_rate(), FixedU128::one());
+            assert_ok!(ParachainStaking::do_try_state());
+
+            assert_noop!(
+                ParachainStaking::withdraw_liquid_unbonded(Origin::signed(3)),
+                Error::<Test>::NoLiquidUnbondingDue
+            );
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::withdraw_liquid_unbonded(Origin::signed(3)));
+            assert_event_emitted!(Event::LiquidWithdrawn { staker: 3, amount: 4 });
+            assert_eq!(Balances::free_balance(&3), 94);
+            assert_eq!(liquid_nomination(1), Some(6));
+            assert!(ParachainStaking::liquid_unbondings(3).is_empty());
+            assert_eq!(ParachainStaking::liquid_unbonding_total(), 0);
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn slash_is_shared_by_bonded_and_unbonding_liquid_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            let account = ParachainStaking::liquid_staking_account();
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 10));
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(4), 10));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::liquid_unstake(Origin::signed(3), 10));
+            assert_eq!(ParachainStaking::liquid_unbonding_total(), 10);
+            assert_eq!(ParachainStaking::liquid_staked_value(), 10);
+
+            assert_ok!(ParachainStaking::slash_collator(&1, 2, Perbill::from_percent(10)));
+            roll_to_era_begin(4);
+            assert_event_emitted!(Event::Slashed { account, amount: 2 });
+            // the nomination of 20 lost 2, half of it unbonding for 3
+            assert_eq!(ParachainStaking::liquid_unbonding_total(), 9);
+            assert_eq!(ParachainStaking::liquid_staked_value(), 9);
+            assert_ok!(ParachainStaking::do_try_state());
+
+            assert_ok!(ParachainStaking::withdraw_liquid_unbonded(Origin::signed(3)));
+            assert_event_emitted!(Event::LiquidWithdrawn { staker: 3, amount: 9 });
+            assert_eq!(Balances::free_balance(&3), 99);
+            assert_eq!(liquid_nomination(1), Some(8));
+            assert_eq!(ParachainStaking::liquid_unbonding_total(), 0);
+            assert_eq!(ParachainStaking::liquid_unbonding_points(), 0);
+            assert_eq!(
+                ParachainStaking::liquid_exchange_rate(),
+                FixedU128::saturating_from_rational(9, 10)
+            );
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn idle_liquid_stake_is_withdrawn_at_once() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            // below MinNominatorStk, the stake stays idle
+            assert_ok!(ParachainStaking::liquid_stake(Origin::signed(3), 4));
+            assert!(!ParachainStaking::is_nominator(&ParachainStaking::liquid_staking_account()));
+            assert_ok!(ParachainStaking::liquid_unstake(Origin::signed(3), 4));
+            assert_event_emitted!(Event::LiquidWithdrawn { staker: 3, amount: 4 });
+            assert!(ParachainStaking::liquid_unbondings(3).is_empty());
+            assert_eq!(Balances::free_balance(&3), 100);
+        });
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to_era_begin, ExtBuilder, MaxUnderperformingEras, Origin, ParachainStaking,
+};
+use crate::{assert_event_emitted, assert_event_not_emitted, CollatorStatus, Event};
+use frame_support::assert_ok;
+use pallet_authorship::EventHandler;
+
+fn author_blocks(author: u64, blocks: u32) {
+    for _ in 0..blocks {
+        <ParachainStaking as EventHandler<u64, u64>>::note_author(author);
+    }
+}
+
+#[test]
+fn note_author_counts_authored_blocks() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            author_blocks(1, 3);
+            assert_eq!(ParachainStaking::authored_blocks(1, 1), 3);
+            assert_eq!(ParachainStaking::authored_blocks(1, 2), 0);
+            assert_eq!(ParachainStaking::awarded_pts(1, 1), 60);
+        });
+}
+
+#[test]
+fn authored_blocks_are_cleared_when_era_is_checked() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            author_blocks(1, 3);
+            roll_to_era_begin(2);
+            assert_eq!(ParachainStaking::authored_blocks(1, 1), 0);
+        });
+}
+
+#[test]
+fn collator_is_idled_after_consecutive_underperforming_eras() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxUnderperformingEras::set(2);
+            author_blocks(1, 4);
+            roll_to_era_begin(2);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 1);
+            assert_eq!(ParachainStaking::underperforming_eras(1), 0);
+            assert_event_not_emitted!(Event::CandidateIdled {
+                candidate: 2,
+                era: 1,
+                authored_blocks: 0,
+                expected_blocks: 2,
+            });
+
+            author_blocks(1, 5);
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::CandidateIdled {
+                candidate: 2,
+                era: 2,
+                authored_blocks: 0,
+                expected_blocks: 2,
+            });
+            let candidate = ParachainStaking::candidate_info(2).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Idle);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 0);
+            assert_eq!(ParachainStaking::selected_candidates(), vec![1]);
+            assert!(ParachainStaking::candidate_pool().iter().all(|bond| bond.owner != 2));
+        });
+}
+
+#[test]
+fn authoring_enough_blocks_resets_underperforming_eras() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxUnderperformingEras::set(2);
+            author_blocks(1, 4);
+            roll_to_era_begin(2);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 1);
+
+            author_blocks(1, 4);
+            author_blocks(2, 1);
+            roll_to_era_begin(3);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 0);
+
+            author_blocks(1, 5);
+            roll_to_era_begin(4);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 1);
+            let candidate = ParachainStaking::candidate_info(2).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Active);
+        });
+}
+
+#[test]
+fn collators_are_never_idled_if_max_underperforming_eras_is_zero() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            roll_to_era_begin(5);
+            assert_eq!(ParachainStaking::underperforming_eras(1), 0);
+            assert_eq!(ParachainStaking::underperforming_eras(2), 0);
+            assert_eq!(ParachainStaking::selected_candidates(), vec![1, 2]);
+        });
+}
+
+#[test]
+fn idled_collator_can_go_online() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxUnderperformingEras::set(1);
+            author_blocks(1, 4);
+            roll_to_era_begin(2);
+            let candidate = ParachainStaking::candidate_info(2).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Idle);
+
+            assert_ok!(ParachainStaking::go_online(Origin::signed(2)));
+            author_blocks(1, 5);
+            roll_to_era_begin(3);
+            assert_eq!(ParachainStaking::selected_candidates(), vec![1, 2]);
+        });
+}
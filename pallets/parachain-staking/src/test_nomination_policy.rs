@@ -0,0 +1,145 @@
+#[cfg(test)]
+use crate::mock::{nomination_amount, ExtBuilder, Origin, ParachainStaking, Test};
+use crate::{assert_event_emitted, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+
+fn nomination_count_of_1() -> Option<u32> {
+    ParachainStaking::candidate_info(1).map(|info| info.nomination_count)
+}
+
+#[test]
+fn allowlist_only_accepts_listed_nominators() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_nomination_policy(
+                Origin::signed(1),
+                None,
+                None,
+                Some(vec![4, 3, 3])
+            ));
+            let policy = ParachainStaking::nomination_policy(1).expect("policy exists");
+            assert_eq!(policy.allowlist.map(|allowlist| allowlist.to_vec()), Some(vec![3, 4]));
+            assert_event_emitted!(Event::NominationPolicySet {
+                candidate: 1,
+                min_nomination: None,
+                max_nominators: None,
+                allowlist_len: Some(2),
+            });
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(5), 1, 10, 1, 0),
+                Error::<Test>::NominatorNotAllowed
+            );
+            assert_ok!(ParachainStaking::nominate(Origin::signed(3), 1, 10, 1, 0));
+            assert_eq!(nomination_count_of_1(), Some(2));
+            assert_noop!(
+                ParachainStaking::set_nomination_policy(
+                    Origin::signed(1),
+                    None,
+                    None,
+                    Some(vec![2, 3, 4, 5, 6])
+                ),
+                Error::<Test>::AllowlistTooLong
+            );
+        });
+}
+
+#[test]
+fn max_nominators_and_min_nomination_restrict_new_nominations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_nomination_policy(
+                Origin::signed(1),
+                Some(8),
+                Some(2),
+                None
+            ));
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(3), 1, 7, 1, 0),
+                Error::<Test>::NominationBelowCandidateMin
+            );
+            assert_ok!(ParachainStaking::nominate(Origin::signed(3), 1, 8, 1, 0));
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(4), 1, 10, 2, 0),
+                Error::<Test>::CandidateNominatorsFull
+            );
+            // the minimum only applies to new nominations
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(3), 1, 1));
+        });
+}
+
+#[test]
+fn existing_nominations_are_grandfathered() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_nomination_policy(
+                Origin::signed(1),
+                Some(20),
+                Some(0),
+                Some(vec![3])
+            ));
+            assert_eq!(nomination_count_of_1(), Some(1));
+            assert_eq!(nomination_amount(2, 1), Some(10));
+            // and can still grow although 2 is not on the allowlist
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(nomination_amount(2, 1), Some(15));
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 2));
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(4), 1, 20, 1, 0),
+                Error::<Test>::NominatorNotAllowed
+            );
+        });
+}
+
+#[test]
+fn controller_sets_and_clears_the_policy() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_controller(Origin::signed(1), 11));
+            assert_ok!(ParachainStaking::set_nomination_policy(
+                Origin::signed(11),
+                None,
+                Some(3),
+                None
+            ));
+            assert_eq!(
+                ParachainStaking::nomination_policy(1).and_then(|policy| policy.max_nominators),
+                Some(3)
+            );
+            assert_noop!(
+                ParachainStaking::set_nomination_policy(Origin::signed(11), None, Some(3), None),
+                Error::<Test>::NoWritingSameValue
+            );
+            // a policy without any restriction is removed
+            assert_ok!(ParachainStaking::set_nomination_policy(
+                Origin::signed(11),
+                None,
+                None,
+                None
+            ));
+            assert_eq!(ParachainStaking::nomination_policy(1), None);
+            assert_noop!(
+                ParachainStaking::set_nomination_policy(Origin::signed(1), None, None, None),
+                Error::<Test>::NoWritingSameValue
+            );
+            assert_noop!(
+                ParachainStaking::set_nomination_policy(Origin::signed(3), None, Some(3), None),
+                Error::<Test>::CandidateDNE
+            );
+        });
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+use crate::mock::{
+    events, nomination_amount, query_lock_amount, ExtBuilder, Origin, ParachainStaking, Test,
+};
+use crate::{
+    assert_event_emitted, Error, Event, NominationTargets, NominatorAdded, SplitStrategy,
+    NOMINATOR_LOCK_ID,
+};
+use frame_support::{assert_noop, assert_ok};
+
+fn nomination_events() -> usize {
+    events()
+        .into_iter()
+        .filter(|event| matches!(event, Event::Nomination { .. }))
+        .count()
+}
+
+#[test]
+fn nominate_many_adds_a_nomination_per_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 40), (2, 20), (3, 25)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_many(
+                Origin::signed(4),
+                NominationTargets::Amounts(vec![(1, 10), (2, 5), (3, 5)])
+            ));
+            assert_eq!(
+                (nomination_amount(4, 1), nomination_amount(4, 2), nomination_amount(4, 3)),
+                (Some(10), Some(5), Some(5))
+            );
+            assert_eq!(query_lock_amount(4, NOMINATOR_LOCK_ID), Some(20));
+            assert_eq!(nomination_events(), 3);
+            assert_event_emitted!(Event::Nomination {
+                nominator: 4,
+                locked_amount: 5,
+                candidate: 3,
+                nominator_position: NominatorAdded::AddedToTop { new_total: 30 },
+            });
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn nominate_many_adds_nothing_if_a_nomination_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 40), (2, 20), (3, 25)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![(1, 10), (9, 5)])
+                ),
+                Error::<Test>::CandidateDNE
+            );
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![(1, 10), (2, 1)])
+                ),
+                Error::<Test>::NominationBelowMin
+            );
+            assert!(!ParachainStaking::is_nominator(&4));
+        });
+}
+
+#[test]
+fn nominate_many_checks_all_the_targets_first() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 40), (2, 20), (3, 25)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![])
+                ),
+                Error::<Test>::NoNominationTargets
+            );
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![(2, 5), (2, 5)])
+                ),
+                Error::<Test>::DuplicateNominationTarget
+            );
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![(2, 50), (3, 50)])
+                ),
+                Error::<Test>::InsufficientBalance
+            );
+            assert_noop!(
+                ParachainStaking::nominate_many(
+                    Origin::signed(4),
+                    NominationTargets::Amounts(vec![(2, 5), (3, 5), (5, 5), (6, 5)])
+                ),
+                Error::<Test>::ExceedMaxNominationsPerNominator
+            );
+        });
+}
+
+#[test]
+fn equal_split_gives_the_remainder_to_the_first_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 40), (2, 20), (3, 25)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_many(
+                Origin::signed(4),
+                NominationTargets::Split {
+                    total: 20,
+                    candidates: vec![1, 2, 3],
+                    strategy: SplitStrategy::Equal,
+                }
+            ));
+            assert_eq!(
+                (nomination_amount(4, 1), nomination_amount(4, 2), nomination_amount(4, 3)),
+                (Some(8), Some(6), Some(6))
+            );
+            assert_eq!(nomination_events(), 3);
+        });
+}
+
+#[test]
+fn fill_split_raises_the_least_backed_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 40), (2, 20), (3, 25)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_many(
+                Origin::signed(4),
+                NominationTargets::Split {
+                    total: 20,
+                    candidates: vec![1, 2, 3],
+                    strategy: SplitStrategy::FillUnderSubscribed,
+                }
+            ));
+            // 2 and 3 are raised to 33 and 32, leaving out 1 which is above them
+            assert_eq!(
+                (nomination_amount(4, 1), nomination_amount(4, 2), nomination_amount(4, 3)),
+                (None, Some(13), Some(7))
+            );
+            assert_eq!(nomination_events(), 2);
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
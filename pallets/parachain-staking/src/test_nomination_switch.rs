@@ -0,0 +1,164 @@
+#[cfg(test)]
+use crate::mock::{
+    nomination_amount, query_lock_amount, roll_to_era_begin, ExtBuilder, Origin, ParachainStaking,
+    Test,
+};
+use crate::{assert_event_emitted, Error, Event, NOMINATOR_LOCK_ID};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Percent;
+
+fn total_counted(candidate: u64) -> u128 {
+    ParachainStaking::candidate_info(candidate)
+        .expect("candidate exists")
+        .total_counted
+}
+
+#[test]
+fn switching_the_whole_nomination_keeps_the_stake_locked() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 10, 0, 1));
+            assert_eq!(nomination_amount(4, 1), None);
+            assert_eq!(nomination_amount(4, 2), Some(10));
+            assert_eq!(total_counted(1), 20);
+            assert_eq!(total_counted(2), 30);
+            assert_eq!(
+                ParachainStaking::candidate_info(1).map(|info| info.nomination_count),
+                Some(0)
+            );
+            assert_eq!(query_lock_amount(4, NOMINATOR_LOCK_ID), Some(10));
+            assert_eq!(ParachainStaking::total(), 70);
+            assert_eq!(ParachainStaking::last_nomination_switch(4), Some(1));
+            assert_event_emitted!(Event::NominationSwitched {
+                nominator: 4,
+                from: 1,
+                to: 2,
+                amount: 10
+            });
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn switching_part_of_a_nomination_into_an_existing_one() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .with_nominations(vec![(4, 1, 10), (4, 2, 5)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 4, 0, 2));
+            assert_eq!(nomination_amount(4, 1), Some(6));
+            assert_eq!(nomination_amount(4, 2), Some(9));
+            assert_eq!(total_counted(1), 26);
+            assert_eq!(total_counted(2), 29);
+            assert_eq!(query_lock_amount(4, NOMINATOR_LOCK_ID), Some(15));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn switching_is_limited_by_the_cooldown() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 5, 0, 1));
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 2, 3, 5, 0, 2),
+                Error::<Test>::NominationSwitchCooldown
+            );
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 2, 3, 5, 0, 2));
+            assert_eq!(nomination_amount(4, 3), Some(5));
+        });
+}
+
+#[test]
+fn last_switch_is_cleared_when_the_nominator_exits() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 10, 0, 1));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(4), 2));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(4), 4, 2));
+            assert!(!ParachainStaking::is_nominator(&4));
+            assert_eq!(ParachainStaking::last_nomination_switch(4), None);
+        });
+}
+
+#[test]
+fn invalid_switches_are_rejected() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 1, 5, 0, 1),
+                Error::<Test>::CannotSwitchToSameCandidate
+            );
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 0, 0, 1),
+                Error::<Test>::InvalidSwitchAmount
+            );
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 11, 0, 1),
+                Error::<Test>::InvalidSwitchAmount
+            );
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 2, 3, 5, 0, 1),
+                Error::<Test>::NominationDNE
+            );
+            // 2 would be left towards collator 1, below MinNomination
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 8, 0, 1),
+                Error::<Test>::NominationBelowMin
+            );
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 10, 0, 0),
+                Error::<Test>::TooLowNominationCountToNominate
+            );
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(4), 1, 2));
+            assert_noop!(
+                ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 5, 0, 1),
+                Error::<Test>::PendingNominationRequestAlreadyExists
+            );
+        });
+}
+
+#[test]
+fn auto_compounding_follows_the_whole_nomination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_nominations(vec![(4, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(4),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::switch_nomination(Origin::signed(4), 1, 2, 10, 0, 1));
+            assert_eq!(ParachainStaking::nomination_auto_compound(&1, &4), Percent::zero());
+            assert_eq!(
+                ParachainStaking::nomination_auto_compound(&2, &4),
+                Percent::from_percent(50)
+            );
+        });
+}
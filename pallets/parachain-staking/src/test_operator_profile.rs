@@ -0,0 +1,145 @@
+#[cfg(test)]
+use crate::mock::{roll_to_era_begin, Balances, ExtBuilder, Origin, ParachainStaking, Test};
+use crate::{assert_event_emitted, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+
+fn set_profile(candidate: u64, display_name: &[u8], website: &[u8]) {
+    assert_ok!(ParachainStaking::set_operator_profile(
+        Origin::signed(candidate),
+        display_name.to_vec(),
+        website.to_vec(),
+        b"ops".to_vec(),
+        vec![],
+        Some(true)
+    ));
+}
+
+#[test]
+fn set_operator_profile_reserves_a_deposit_for_its_length() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            set_profile(1, b"alice", b"a.io");
+            let profile = ParachainStaking::operator_profile(1).expect("profile exists");
+            assert_eq!(profile.display_name.to_vec(), b"alice".to_vec());
+            assert_eq!(profile.accepting_nominations, Some(true));
+            // 5 plus 1 for each of the 12 bytes
+            assert_eq!(profile.deposit, 17);
+            assert_eq!(Balances::reserved_balance(&1), 17);
+            assert_event_emitted!(Event::OperatorProfileSet { candidate: 1, deposit: 17 });
+
+            // a shorter profile returns the excess
+            set_profile(1, b"al", b"");
+            assert_eq!(Balances::reserved_balance(&1), 10);
+            set_profile(1, b"alice", b"alice.io");
+            assert_eq!(Balances::reserved_balance(&1), 21);
+        });
+}
+
+#[test]
+fn invalid_operator_profiles_are_rejected() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_operator_profile(
+                    Origin::signed(3),
+                    b"carol".to_vec(),
+                    vec![],
+                    vec![],
+                    vec![],
+                    None
+                ),
+                Error::<Test>::CandidateDNE
+            );
+            assert_noop!(
+                ParachainStaking::set_operator_profile(
+                    Origin::signed(1),
+                    vec![0u8; 17],
+                    vec![],
+                    vec![],
+                    vec![],
+                    None
+                ),
+                Error::<Test>::ProfileFieldTooLong
+            );
+            // the whole balance of 2 is bonded
+            assert_noop!(
+                ParachainStaking::set_operator_profile(
+                    Origin::signed(2),
+                    b"bob".to_vec(),
+                    vec![],
+                    vec![],
+                    vec![],
+                    None
+                ),
+                Error::<Test>::InsufficientBalance
+            );
+            assert_noop!(
+                ParachainStaking::clear_operator_profile(Origin::signed(1)),
+                Error::<Test>::NoOperatorProfile
+            );
+        });
+}
+
+#[test]
+fn candidate_not_accepting_nominations_rejects_new_nominations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_operator_profile(
+                Origin::signed(1),
+                b"alice".to_vec(),
+                vec![],
+                vec![],
+                vec![],
+                Some(false)
+            ));
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(3), 1, 10, 0, 0),
+                Error::<Test>::CandidateNotAcceptingNominations
+            );
+            assert_ok!(ParachainStaking::nominate(Origin::signed(3), 2, 10, 0, 0));
+
+            set_profile(1, b"alice", b"");
+            assert_ok!(ParachainStaking::nominate(Origin::signed(3), 1, 10, 0, 1));
+        });
+}
+
+#[test]
+fn clear_operator_profile_returns_the_deposit() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            set_profile(1, b"alice", b"a.io");
+            assert_ok!(ParachainStaking::clear_operator_profile(Origin::signed(1)));
+            assert_eq!(ParachainStaking::operator_profile(1), None);
+            assert_eq!(Balances::reserved_balance(&1), 0);
+            assert_event_emitted!(Event::OperatorProfileCleared { candidate: 1, deposit: 17 });
+        });
+}
+
+#[test]
+fn leaving_candidates_clears_the_operator_profile() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            set_profile(1, b"alice", b"a.io");
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 2));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert_eq!(ParachainStaking::operator_profile(1), None);
+            assert_eq!(Balances::reserved_balance(&1), 0);
+            assert_event_emitted!(Event::OperatorProfileCleared { candidate: 1, deposit: 17 });
+        });
+}
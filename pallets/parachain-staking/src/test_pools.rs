@@ -0,0 +1,347 @@
+#[cfg(test)]
+use crate::mock::{
+    nomination_amount, query_lock_amount, roll_one_block, roll_to_era_begin, set_author,
+    set_reward_pot, Balances, ExtBuilder, Origin, ParachainStaking, Test,
+};
+use crate::{assert_event_emitted, Error, Event, NominationAction, PoolMembers, NOMINATOR_LOCK_ID};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::Perbill;
+
+const POOL: u32 = 0;
+
+fn create_pool() {
+    assert_ok!(ParachainStaking::create_pool(Origin::signed(3), 1, 10, 0));
+}
+
+fn pool_nomination(candidate: u64) -> Option<u128> {
+    nomination_amount(ParachainStaking::pool_account(POOL), candidate)
+}
+
+#[test]
+fn create_pool_nominates_with_the_pool_account() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            let pool = ParachainStaking::pool(POOL).expect("pool exists");
+            assert_eq!((pool.operator, pool.points, pool.bonded), (3, 10, 10));
+            assert_eq!(
+                ParachainStaking::pool_member(POOL, 3).map(|member| member.points),
+                Some(10)
+            );
+            assert_eq!(pool_nomination(1), Some(10));
+            assert_eq!(
+                query_lock_amount(ParachainStaking::pool_account(POOL), NOMINATOR_LOCK_ID),
+                Some(10)
+            );
+            assert_eq!(Balances::free_balance(&3), 90);
+            assert_eq!(ParachainStaking::next_pool_id(), 1);
+            assert_event_emitted!(Event::PoolCreated {
+                pool_id: POOL,
+                operator: 3,
+                candidate: 1,
+                amount: 10,
+            });
+        });
+}
+
+#[test]
+fn create_pool_needs_min_nominator_stk() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::create_pool(Origin::signed(3), 1, 4, 0),
+                Error::<Test>::NominatorBondBelowMin
+            );
+        });
+}
+
+#[test]
+fn members_join_with_less_than_min_nominator_stk() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 2));
+            assert_eq!(ParachainStaking::pool_member(POOL, 4).map(|member| member.points), Some(2));
+            assert_eq!(pool_nomination(1), Some(12));
+            assert_eq!(ParachainStaking::total(), 52);
+            assert_event_emitted!(Event::PoolJoined {
+                pool_id: POOL,
+                member: 4,
+                amount: 2,
+                points: 2
+            });
+            assert_noop!(
+                ParachainStaking::join_pool(Origin::signed(4), POOL, 0),
+                Error::<Test>::PoolJoinBelowMin
+            );
+            assert_noop!(
+                ParachainStaking::join_pool(Origin::signed(4), 1, 2),
+                Error::<Test>::PoolDNE
+            );
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn only_operator_nominates_for_the_pool() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_noop!(
+                ParachainStaking::pool_nominate(Origin::signed(4), POOL, 2, 5, 0, 1),
+                Error::<Test>::NotPoolOperator
+            );
+            assert_ok!(ParachainStaking::pool_nominate(Origin::signed(3), POOL, 2, 5, 0, 1));
+            assert_eq!(pool_nomination(2), Some(5));
+            assert_eq!(
+                ParachainStaking::pool_member(POOL, 3).map(|member| member.points),
+                Some(15)
+            );
+            assert_event_emitted!(Event::PoolNominated { pool_id: POOL, candidate: 2, amount: 5 });
+
+            // a join is bonded into the least bonded nomination
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 3));
+            assert_eq!(pool_nomination(2), Some(8));
+            assert_eq!(pool_nomination(1), Some(10));
+        });
+}
+
+#[test]
+fn rewards_of_the_pool_are_shared_by_points() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 5));
+            roll_to_era_begin(2);
+            set_author(2, 1, 1);
+            set_reward_pot(35);
+            roll_to_era_begin(4);
+            roll_one_block();
+            let pool_account = ParachainStaking::pool_account(POOL);
+            let pool_rewards = Balances::free_balance(&pool_account) - 15;
+            assert!(pool_rewards > 0);
+
+            assert_ok!(ParachainStaking::claim_pool_rewards(Origin::signed(4), POOL));
+            assert_event_emitted!(Event::PoolRewardPaid {
+                pool_id: POOL,
+                member: 4,
+                rewards: pool_rewards / 3,
+            });
+            assert_ok!(ParachainStaking::claim_pool_rewards(Origin::signed(3), POOL));
+            assert_event_emitted!(Event::PoolRewardPaid {
+                pool_id: POOL,
+                member: 3,
+                rewards: pool_rewards * 2 / 3,
+            });
+            assert_noop!(
+                ParachainStaking::claim_pool_rewards(Origin::signed(4), POOL),
+                Error::<Test>::NoRewardsToClaim
+            );
+        });
+}
+
+#[test]
+fn members_joining_later_do_not_share_earlier_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            let pool_account = ParachainStaking::pool_account(POOL);
+            Balances::make_free_balance_be(&pool_account, 40);
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 10));
+            assert_noop!(
+                ParachainStaking::claim_pool_rewards(Origin::signed(4), POOL),
+                Error::<Test>::NoRewardsToClaim
+            );
+            assert_ok!(ParachainStaking::claim_pool_rewards(Origin::signed(3), POOL));
+            assert_eq!(Balances::free_balance(&3), 120);
+        });
+}
+
+#[test]
+fn unbonding_member_withdraws_after_the_decrease_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 4));
+            assert_noop!(
+                ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 5),
+                Error::<Test>::NotEnoughPoolPoints
+            );
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 4));
+            assert_event_emitted!(Event::PoolMemberUnbonded {
+                pool_id: POOL,
+                member: 4,
+                points: 4,
+                amount: 4,
+            });
+            let unbondings = ParachainStaking::pool_unbondings(POOL, 4);
+            assert_eq!(unbondings.len(), 1);
+            assert_eq!((unbondings[0].candidate, unbondings[0].amount), (1, 4));
+            assert_eq!(unbondings[0].when_executable, 3);
+            assert_ok!(ParachainStaking::do_try_state());
+
+            assert_noop!(
+                ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(4), POOL),
+                Error::<Test>::NoPoolUnbondingDue
+            );
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(4), POOL));
+            assert_event_emitted!(Event::PoolMemberWithdrawn {
+                pool_id: POOL,
+                member: 4,
+                amount: 4
+            });
+            assert_eq!(Balances::free_balance(&4), 100);
+            assert_eq!(pool_nomination(1), Some(10));
+            assert!(!<PoolMembers<Test>>::contains_key(POOL, 4));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn unbondings_of_the_same_era_share_one_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 5));
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(5), POOL, 5));
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 5));
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(5), POOL, 5));
+            let requests = ParachainStaking::nomination_scheduled_requests(1);
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].action, NominationAction::Decrease(10));
+
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(4), POOL));
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(5), POOL));
+            assert_eq!(Balances::free_balance(&4), 100);
+            assert_eq!(Balances::free_balance(&5), 100);
+            assert_eq!(pool_nomination(1), Some(10));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn unbonding_waits_for_a_pending_request_due_in_another_era() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 10));
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 5));
+            roll_to_era_begin(2);
+            // the request due in era 3 cannot take an unbonding due in era 4
+            assert_noop!(
+                ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 5),
+                Error::<Test>::PoolUnbondingUnavailable
+            );
+
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(4), POOL));
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 5));
+            let unbondings = ParachainStaking::pool_unbondings(POOL, 4);
+            assert_eq!(unbondings[0].when_executable, 5);
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
+
+#[test]
+fn unbonding_the_whole_pool_revokes_its_nomination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(3), POOL, 10));
+            let requests = ParachainStaking::nomination_scheduled_requests(1);
+            assert_eq!(requests[0].action, NominationAction::Revoke(10));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(3), POOL));
+            assert!(!ParachainStaking::is_nominator(&ParachainStaking::pool_account(POOL)));
+            assert_eq!(Balances::free_balance(&3), 100);
+        });
+}
+
+#[test]
+fn idle_stake_of_the_pool_is_withdrawn_at_once() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 10));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 2));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
+            assert!(!ParachainStaking::is_nominator(&ParachainStaking::pool_account(POOL)));
+
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 10));
+            assert_event_emitted!(Event::PoolMemberWithdrawn {
+                pool_id: POOL,
+                member: 4,
+                amount: 10
+            });
+            assert!(ParachainStaking::pool_unbondings(POOL, 4).is_empty());
+            assert_eq!(Balances::free_balance(&4), 100);
+        });
+}
+
+#[test]
+fn slash_is_shared_by_bonded_and_unbonding_stake_of_the_pool() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::join_pool(Origin::signed(4), POOL, 10));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::unbond_from_pool(Origin::signed(4), POOL, 10));
+            assert_ok!(ParachainStaking::slash_collator(&1, 2, Perbill::from_percent(10)));
+            roll_to_era_begin(4);
+            assert_event_emitted!(Event::Slashed {
+                account: ParachainStaking::pool_account(POOL),
+                amount: 2
+            });
+
+            // the unbonding half of the pool stake bears half of the slash
+            assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(Origin::signed(4), POOL));
+            assert_event_emitted!(Event::PoolMemberWithdrawn {
+                pool_id: POOL,
+                member: 4,
+                amount: 9
+            });
+            assert_eq!(Balances::free_balance(&4), 99);
+            let pool = ParachainStaking::pool(POOL).expect("pool exists");
+            assert_eq!((pool.bonded, pool.unbonding, pool.unbonding_points), (9, 0, 0));
+            assert_ok!(ParachainStaking::do_try_state());
+        });
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to_era_begin, set_author, set_reward_pot, ExtBuilder, Origin, ParachainStaking,
+};
+use crate::{
+    AwardedPts, CollatorStatus, NominationAction, NominationPlacement, PayoutMode, ScheduledRequest,
+};
+use frame_support::assert_ok;
+use sp_runtime::Percent;
+
+#[test]
+fn candidate_profile_returns_candidate_state_and_nominations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 9), (5, 1, 8), (6, 1, 7), (7, 1, 6)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(3), 1));
+            let profile = ParachainStaking::candidate_profile(&1).expect("candidate exists");
+            assert_eq!(profile.bond, 20);
+            assert_eq!(profile.total_counted, 54);
+            assert_eq!(profile.nomination_count, 5);
+            assert_eq!(profile.status, CollatorStatus::Active);
+            assert!(profile.is_selected);
+            assert_eq!(
+                profile.top_nominations.iter().map(|b| (b.owner, b.amount)).collect::<Vec<_>>(),
+                vec![(3, 10), (4, 9), (5, 8), (6, 7)]
+            );
+            assert_eq!(
+                profile
+                    .bottom_nominations
+                    .iter()
+                    .map(|b| (b.owner, b.amount))
+                    .collect::<Vec<_>>(),
+                vec![(7, 6)]
+            );
+            assert_eq!(
+                profile.nomination_requests,
+                vec![ScheduledRequest {
+                    nominator: 3,
+                    when_executable: 3,
+                    action: NominationAction::Revoke(10),
+                }]
+            );
+        });
+}
+
+#[test]
+fn candidate_profile_is_none_for_non_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 9), (5, 1, 8), (6, 1, 7), (7, 1, 6)])
+        .build()
+        .execute_with(|| {
+            assert!(ParachainStaking::candidate_profile(&3).is_none());
+        });
+}
+
+#[test]
+fn nomination_positions_return_placement_and_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 9), (5, 1, 8), (6, 1, 7), (7, 1, 6)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(3), 1));
+            let positions = ParachainStaking::nomination_positions(&3);
+            assert_eq!(positions.len(), 1);
+            assert_eq!(positions[0].candidate, 1);
+            assert_eq!(positions[0].amount, 10);
+            assert_eq!(positions[0].placement, NominationPlacement::Top);
+            assert_eq!(positions[0].auto_compound, Percent::zero());
+            assert_eq!(
+                positions[0].request,
+                Some(ScheduledRequest {
+                    nominator: 3,
+                    when_executable: 3,
+                    action: NominationAction::Revoke(10),
+                })
+            );
+
+            let positions = ParachainStaking::nomination_positions(&7);
+            assert_eq!(positions[0].placement, NominationPlacement::Bottom);
+            assert_eq!(positions[0].request, None);
+
+            assert!(ParachainStaking::nomination_positions(&1).is_empty());
+        });
+}
+
+#[test]
+fn min_top_nomination_stake_beats_lowest_top_nomination_when_full() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 9), (5, 1, 8), (6, 1, 7), (7, 1, 6)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::min_top_nomination_stake(&1), Some(8));
+            // MinNomination while the top nominations are not full
+            assert_eq!(ParachainStaking::min_top_nomination_stake(&2), Some(3));
+            assert_eq!(ParachainStaking::min_top_nomination_stake(&3), None);
+        });
+}
+
+#[test]
+fn min_selection_stake_is_min_collator_stake_when_seats_are_free() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100), (7, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 9), (5, 1, 8), (6, 1, 7), (7, 1, 6)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::min_selection_stake(), 10);
+        });
+}
+
+#[test]
+fn min_selection_stake_beats_lowest_selected_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+        .with_candidates(vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::min_selection_stake(), 21);
+        });
+}
+
+#[test]
+fn staking_rewards_estimate_rewards_of_unpaid_eras() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (3, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_reward_pot(90);
+            set_author(1, 1, 100);
+            let rewards = ParachainStaking::staking_rewards(&1);
+            assert_eq!((rewards.pending, rewards.estimated), (0, 60));
+            let rewards = ParachainStaking::staking_rewards(&3);
+            assert_eq!((rewards.pending, rewards.estimated), (0, 30));
+        });
+}
+
+#[test]
+fn staking_rewards_share_the_reward_pot_between_unpaid_eras() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (3, 100)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            roll_to_era_begin(2);
+            set_reward_pot(90);
+            set_author(1, 1, 100);
+            set_author(2, 1, 100);
+            assert_eq!(ParachainStaking::staking_rewards(&1).estimated, 60);
+            assert_eq!(ParachainStaking::staking_rewards(&3).estimated, 30);
+        });
+}
+
+#[test]
+fn staking_rewards_include_pending_and_claimable_rewards() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_nominations(vec![(3, 1, 10), (3, 2, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_reward_pot(120);
+            set_author(1, 1, 50);
+            set_author(1, 2, 50);
+            roll_to_era_begin(3);
+            // one collator is paid out in the first block of the era
+            let (unpaid, _) = <AwardedPts<crate::mock::Test>>::iter_prefix(1)
+                .next()
+                .expect("one collator left to pay");
+            let paid = if unpaid == 1 { 2 } else { 1 };
+            assert_eq!(ParachainStaking::staking_rewards(&unpaid).pending, 40);
+            assert_eq!(ParachainStaking::staking_rewards(&paid).pending, 0);
+            assert_eq!(ParachainStaking::staking_rewards(&paid).claimable, 40);
+            let rewards = ParachainStaking::staking_rewards(&3);
+            assert_eq!((rewards.claimable, rewards.pending), (20, 20));
+        });
+}
@@ -1,9 +1,11 @@
 #[cfg(test)]
 use crate::mock::{
-    pay_gas_for_transaction, roll_one_block, roll_to_era_begin, set_author, Balances, ExtBuilder,
-    ParachainStaking, BASE_FEE, TX_LEN,
+    pay_gas_for_transaction, roll_one_block, roll_to_era_begin, set_author, set_reward_pot,
+    Balances, ExtBuilder, ParachainStaking, RewardPayoutWeight, SessionsPerEra, Test, BASE_FEE,
+    TX_LEN,
 };
-use crate::{assert_eq_events, assert_event_emitted, Event};
+use crate::{assert_eq_events, assert_event_emitted, AwardedPts, Event, RewardDestination};
+use pallet_session::SessionManager;
 use sp_runtime::{traits::Zero, Perbill};
 
 #[test]
@@ -107,11 +109,13 @@ fn end_to_end_happy_path() {
 
             assert_event_emitted!(Event::Rewarded {
                 account: collator1,
-                rewards: expected_collator1_reward
+                rewards: expected_collator1_reward,
+                destination: RewardDestination::Bonded
             });
             assert_event_emitted!(Event::Rewarded {
                 account: nominator4,
-                rewards: expected_nominator_reward
+                rewards: expected_nominator_reward,
+                destination: RewardDestination::Bonded
             });
 
             // Show that reward pot balance has decreased by "total reward payment amount"
@@ -136,7 +140,8 @@ fn end_to_end_happy_path() {
             let expected_collator2_reward = collator2_points_percentage * (expected_tx_fee + tip);
             assert_event_emitted!(Event::Rewarded {
                 account: collator2,
-                rewards: expected_collator2_reward
+                rewards: expected_collator2_reward,
+                destination: RewardDestination::Bonded
             });
 
             // Show that reward pot balance and locked era balance are 0 because everything has been
@@ -152,3 +157,63 @@ fn end_to_end_happy_path() {
 }
 
 // TODO: add failing tests for payout logic (next PR)
+
+fn assert_collators_rewarded(collators: Vec<u64>, rewards: u128) {
+    for account in collators {
+        assert_event_emitted!(Event::Rewarded {
+            account,
+            rewards,
+            destination: RewardDestination::Bonded,
+        });
+    }
+}
+
+#[test]
+fn payout_weight_pays_several_collators_per_block() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            RewardPayoutWeight::set(u64::MAX);
+            set_author(1, 1, 1);
+            set_author(1, 2, 1);
+            set_author(1, 3, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_collators_rewarded(vec![1, 2, 3], 10);
+            assert!(ParachainStaking::delayed_payouts(1).is_none());
+            assert_eq!(<AwardedPts<Test>>::iter_prefix(1).count(), 0);
+        });
+}
+
+#[test]
+fn unfinished_era_payout_carries_over_to_next_era() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            SessionsPerEra::set(1);
+            set_author(1, 1, 1);
+            set_author(1, 2, 1);
+            set_author(1, 3, 1);
+            set_reward_pot(30);
+            for session in 1..=2 {
+                <ParachainStaking as SessionManager<u64>>::start_session(session);
+            }
+            assert_eq!(ParachainStaking::era().current, 3);
+            roll_one_block();
+            assert_eq!(<AwardedPts<Test>>::iter_prefix(1).count(), 2);
+
+            // era 1 is still paid out after era 4 starts
+            <ParachainStaking as SessionManager<u64>>::start_session(3);
+            assert_eq!(ParachainStaking::era().current, 4);
+            roll_one_block();
+            roll_one_block();
+            assert_collators_rewarded(vec![1, 2, 3], 10);
+            assert!(ParachainStaking::delayed_payouts(1).is_some());
+            roll_one_block();
+            assert!(ParachainStaking::delayed_payouts(1).is_none());
+        });
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+use crate::mock::{
+    roll_to, roll_to_era_begin, ExtBuilder, Origin, ParachainStaking, Session, SessionPeriod,
+    SessionsPerEra, System,
+};
+use crate::{assert_event_emitted, Event};
+use frame_support::assert_ok;
+use pallet_session::SessionManager;
+
+#[test]
+fn genesis_session_gets_selected_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                <ParachainStaking as SessionManager<u64>>::new_session_genesis(0),
+                Some(vec![1, 2, 3])
+            );
+        });
+}
+
+#[test]
+fn sessions_follow_selected_candidates_with_block_based_eras() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(3)));
+            // the selection only changes at the next era
+            assert_eq!(
+                <ParachainStaking as SessionManager<u64>>::new_session(1),
+                Some(vec![1, 2, 3])
+            );
+            roll_to_era_begin(2);
+            assert_eq!(<ParachainStaking as SessionManager<u64>>::new_session(2), Some(vec![1, 2]));
+        });
+}
+
+#[test]
+fn eras_do_not_follow_blocks_when_made_of_sessions() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            SessionsPerEra::set(2);
+            roll_to(20);
+            assert_eq!(ParachainStaking::era().current, 1);
+        });
+}
+
+#[test]
+fn next_era_is_planned_one_session_before_it_starts() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .build()
+        .execute_with(|| {
+            SessionsPerEra::set(2);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(3)));
+            roll_to(7);
+            assert_eq!(<ParachainStaking as SessionManager<u64>>::new_session(1), None);
+            assert_eq!(<ParachainStaking as SessionManager<u64>>::new_session(2), Some(vec![1, 2]));
+            // planning the session does not change the collators of the current era
+            assert_eq!(ParachainStaking::era().current, 1);
+            assert_eq!(ParachainStaking::selected_candidates().into_inner(), vec![1, 2, 3]);
+
+            <ParachainStaking as SessionManager<u64>>::start_session(1);
+            assert_eq!(ParachainStaking::era().current, 1);
+            <ParachainStaking as SessionManager<u64>>::start_session(2);
+            let era = ParachainStaking::era();
+            assert_eq!((era.current, era.first), (2, System::block_number()));
+            assert_eq!(ParachainStaking::selected_candidates().into_inner(), vec![1, 2]);
+            assert_eq!(ParachainStaking::queued_candidates(), None);
+            assert_event_emitted!(Event::NewEra {
+                starting_block: 7,
+                era: 2,
+                selected_collators_number: 2,
+                total_balance: 50,
+            });
+        });
+}
+
+#[test]
+fn era_starts_with_the_session_rotating_in_its_collators() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 30), (2, 20), (3, 10)])
+        .with_session_keys(vec![1, 2, 3])
+        .build()
+        .execute_with(|| {
+            SessionsPerEra::set(2);
+            SessionPeriod::set(5);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(3)));
+            // session 2, the first one of era 2, is planned when session 1 starts
+            roll_to(5);
+            assert_eq!(Session::current_index(), 1);
+            assert_eq!(
+                ParachainStaking::queued_candidates().map(|queued| queued.into_inner()),
+                Some(vec![1, 2])
+            );
+            assert_eq!(Session::validators(), vec![1, 2, 3]);
+            assert_eq!(ParachainStaking::selected_candidates().into_inner(), vec![1, 2, 3]);
+
+            // the collators of era 1 author until the end of session 1, earning points in era 1
+            roll_to(9);
+            assert_eq!(ParachainStaking::era().current, 1);
+            ParachainStaking::award_points_to_block_author(3);
+            assert_eq!(ParachainStaking::awarded_pts(1, 3), 20);
+
+            roll_to(10);
+            assert_eq!(Session::current_index(), 2);
+            assert_eq!(Session::validators(), vec![1, 2]);
+            let era = ParachainStaking::era();
+            assert_eq!((era.current, era.first), (2, 10));
+            assert_eq!(ParachainStaking::selected_candidates().into_inner(), vec![1, 2]);
+            assert_eq!(ParachainStaking::at_stake(2, 1).bond, 30);
+            assert_eq!(ParachainStaking::at_stake(2, 3).bond, 0);
+            assert_event_emitted!(Event::NewEra {
+                starting_block: 10,
+                era: 2,
+                selected_collators_number: 2,
+                total_balance: 50,
+            });
+
+            roll_to(19);
+            assert_eq!(ParachainStaking::era().current, 2);
+            roll_to(20);
+            assert_eq!(ParachainStaking::era().current, 3);
+            assert_eq!(Session::current_index(), 4);
+        });
+}
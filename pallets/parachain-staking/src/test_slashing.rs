@@ -0,0 +1,505 @@
+#[cfg(test)]
+use crate::mock::{
+    query_lock_amount, roll_to_era_begin, set_author, Balances, Event as MetaEvent, ExtBuilder,
+    Origin, ParachainStaking, Test, SLASH_TREASURY,
+};
+use crate::{
+    assert_event_emitted, assert_event_not_emitted, assert_last_event,
+    nomination_requests::{CancelledScheduledRequest, NominationAction},
+    AtStake, CollatorStatus, Error, Event, ProcessedOffences, ReportOffence, UnappliedSlash,
+    COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID,
+};
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use sp_runtime::{DispatchError, Perbill};
+
+#[test]
+fn slash_is_deferred_until_apply_era() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashReported {
+                collator: 1,
+                era: 1,
+                fraction: Perbill::from_percent(10),
+                apply_era: 3,
+            }));
+            assert_eq!(
+                ParachainStaking::unapplied_slashes(3),
+                vec![UnappliedSlash {
+                    collator: 1,
+                    era: 1,
+                    own: 5,
+                    others: bounded_vec![(2, 4), (3, 1)]
+                }]
+            );
+            roll_to_era_begin(2);
+            assert_eq!(Balances::free_balance(&1), 100);
+            assert_eq!(ParachainStaking::total(), 100);
+        });
+}
+
+#[test]
+fn slash_reduces_collator_and_nominator_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::Slashed { account: 1, amount: 5 });
+            assert_event_emitted!(Event::Slashed { account: 2, amount: 4 });
+            assert_event_emitted!(Event::Slashed { account: 3, amount: 1 });
+            assert!(ParachainStaking::unapplied_slashes(3).is_empty());
+
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.bond, 45);
+            assert_eq!(candidate.total_counted, 90);
+            assert_eq!(ParachainStaking::total(), 90);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 90);
+            let top = ParachainStaking::top_nominations(1).expect("top nominations exist");
+            assert_eq!(top.total, 45);
+            assert_eq!(top.nominations[0].amount, 36);
+            assert_eq!(top.nominations[1].amount, 9);
+            assert_eq!(ParachainStaking::nominator_state(2).expect("nominator exists").total(), 36);
+            assert_eq!(ParachainStaking::nominator_state(3).expect("nominator exists").total(), 9);
+
+            assert_eq!(query_lock_amount(1, COLLATOR_LOCK_ID), Some(45));
+            assert_eq!(query_lock_amount(2, NOMINATOR_LOCK_ID), Some(36));
+            assert_eq!(query_lock_amount(3, NOMINATOR_LOCK_ID), Some(9));
+            assert_eq!(Balances::free_balance(&1), 95);
+            assert_eq!(Balances::free_balance(&2), 96);
+            assert_eq!(Balances::free_balance(&3), 99);
+            assert_eq!(Balances::free_balance(&SLASH_TREASURY), 10);
+        });
+}
+
+#[test]
+fn slash_takes_nominations_pending_revoke_at_their_bonded_amount() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(3), 1));
+            roll_to_era_begin(2);
+            assert_eq!(ParachainStaking::at_stake(2, 1).nominations[1].amount, 0);
+            assert_ok!(ParachainStaking::slash_collator(&1, 2, Perbill::from_percent(10)));
+            assert_eq!(
+                ParachainStaking::unapplied_slashes(4),
+                vec![UnappliedSlash {
+                    collator: 1,
+                    era: 2,
+                    own: 5,
+                    others: bounded_vec![(2, 4), (3, 1)]
+                }]
+            );
+        });
+}
+
+#[test]
+fn slash_can_be_cancelled_before_apply_era() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![0]));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashCancelled {
+                collator: 1,
+                era: 1,
+            }));
+            assert!(ParachainStaking::unapplied_slashes(3).is_empty());
+            roll_to_era_begin(3);
+            assert_event_not_emitted!(Event::Slashed { account: 1, amount: 5 });
+            assert_eq!(Balances::free_balance(&1), 100);
+            assert_eq!(ParachainStaking::total(), 100);
+        });
+}
+
+#[test]
+fn cancel_deferred_slash_keeps_other_slashes() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 50), (2, 50)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            assert_ok!(ParachainStaking::slash_collator(&2, 1, Perbill::from_percent(20)));
+            assert_ok!(ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![0]));
+            assert_eq!(
+                ParachainStaking::unapplied_slashes(3),
+                vec![UnappliedSlash { collator: 2, era: 1, own: 10, others: bounded_vec![] }]
+            );
+            roll_to_era_begin(3);
+            assert_eq!(Balances::free_balance(&1), 100);
+            assert_eq!(Balances::free_balance(&2), 90);
+        });
+}
+
+#[test]
+fn cancel_deferred_slash_requires_slash_cancel_origin() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(Origin::signed(1), 3, vec![0]),
+                DispatchError::BadOrigin
+            );
+        });
+}
+
+#[test]
+fn cancel_deferred_slash_rejects_invalid_indices() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 50), (2, 50)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            assert_ok!(ParachainStaking::slash_collator(&2, 1, Perbill::from_percent(10)));
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![]),
+                Error::<Test>::NoSlashesToCancel
+            );
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![1, 0]),
+                Error::<Test>::SlashIndicesNotSortedAndUnique
+            );
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![0, 0]),
+                Error::<Test>::SlashIndicesNotSortedAndUnique
+            );
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(Origin::root(), 3, vec![2]),
+                Error::<Test>::InvalidSlashIndex
+            );
+        });
+}
+
+#[test]
+fn cannot_slash_collator_without_snapshot() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::slash_collator(&1, 2, Perbill::from_percent(10)),
+                Error::<Test>::CollatorSnapshotDNE
+            );
+            assert_noop!(
+                ParachainStaking::slash_collator(&2, 1, Perbill::from_percent(10)),
+                Error::<Test>::CollatorSnapshotDNE
+            );
+        });
+}
+
+#[test]
+fn offence_can_be_slashed_after_its_era_is_paid_out() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_author(1, 1, 1);
+            roll_to_era_begin(5);
+            assert!(ParachainStaking::delayed_payouts(1).is_none());
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            assert_eq!(
+                ParachainStaking::unapplied_slashes(7),
+                vec![UnappliedSlash {
+                    collator: 1,
+                    era: 1,
+                    own: 5,
+                    others: bounded_vec![(2, 4), (3, 1)]
+                }]
+            );
+            roll_to_era_begin(6);
+            assert_noop!(
+                ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)),
+                Error::<Test>::OffenceTooOld
+            );
+        });
+}
+
+#[test]
+fn slash_skips_nomination_revoked_before_apply_era() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(3), 1));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(3), 3, 1));
+            roll_to_era_begin(4);
+            assert_event_emitted!(Event::Slashed { account: 2, amount: 4 });
+            assert_event_not_emitted!(Event::Slashed { account: 3, amount: 1 });
+            assert_eq!(Balances::free_balance(&3), 100);
+            assert_eq!(ParachainStaking::total(), 81);
+        });
+}
+
+#[test]
+fn pending_revoke_unbonds_what_is_left_after_slash() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(10)));
+            roll_to_era_begin(3);
+            assert_eq!(
+                ParachainStaking::nominator_state(2).expect("nominator exists").less_total,
+                36
+            );
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominationRevoked {
+                nominator: 2,
+                candidate: 1,
+                unstaked_amount: 36,
+            });
+            assert!(ParachainStaking::nominator_state(2).is_none());
+            assert_eq!(query_lock_amount(2, NOMINATOR_LOCK_ID), None);
+            assert_eq!(ParachainStaking::total(), 54);
+        });
+}
+
+#[test]
+fn slash_cancels_nomination_decrease_that_would_go_below_min() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 10));
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(3), 1, 5));
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(50)));
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::CancelledNominationRequest {
+                nominator: 3,
+                collator: 1,
+                cancelled_request: CancelledScheduledRequest {
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                },
+            });
+            assert_eq!(
+                ParachainStaking::nominator_state(3).expect("nominator exists").less_total,
+                0
+            );
+            assert_noop!(
+                ParachainStaking::execute_nomination_request(Origin::signed(3), 3, 1),
+                Error::<Test>::PendingNominationRequestDNE
+            );
+            // the decrease of nominator 2 still leaves more than the minimum after the slash
+            assert_eq!(
+                ParachainStaking::nominator_state(2).expect("nominator exists").less_total,
+                10
+            );
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::nominator_state(2).expect("nominator exists").total(), 10);
+        });
+}
+
+#[test]
+fn slash_cancels_candidate_bond_less_that_would_go_below_min() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 35));
+            assert_ok!(ParachainStaking::slash_collator(&1, 1, Perbill::from_percent(50)));
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::CancelledCandidateBondLess {
+                candidate: 1,
+                amount: 35,
+                execute_era: 3,
+            });
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.bond, 25);
+            assert!(candidate.request.is_none());
+        });
+}
+
+// REPORT OFFENCE
+
+#[test]
+fn report_offence_slashes_and_chills_offender() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            assert_event_emitted!(Event::SlashReported {
+                collator: 1,
+                era: 1,
+                fraction: Perbill::from_percent(10),
+                apply_era: 3,
+            });
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateChilled {
+                candidate: 1
+            }));
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Idle);
+            assert!(ParachainStaking::candidate_pool().is_empty());
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::Slashed { account: 1, amount: 5 });
+        });
+}
+
+#[test]
+fn chilled_offender_can_go_online() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Active);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 90);
+        });
+}
+
+#[test]
+fn report_offence_without_snapshot_only_chills_offender() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                2,
+                Perbill::from_percent(10),
+            )]);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateChilled {
+                candidate: 1
+            }));
+            assert!(ParachainStaking::unapplied_slashes(4).is_empty());
+            assert!(ParachainStaking::candidate_pool().is_empty());
+        });
+}
+
+#[test]
+fn report_offence_does_not_chill_idle_candidate_again() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            assert_event_not_emitted!(Event::CandidateChilled { candidate: 1 });
+            assert_eq!(ParachainStaking::unapplied_slashes(3).len(), 1);
+        });
+}
+
+#[test]
+fn report_offence_ignores_offences_already_reported_or_too_old() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            assert!(<ProcessedOffences<Test>>::contains_key(1, 1));
+            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(20),
+            )]);
+            assert_eq!(ParachainStaking::unapplied_slashes(3).len(), 1);
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Active);
+
+            roll_to_era_begin(6);
+            assert!(!<ProcessedOffences<Test>>::contains_key(1, 1));
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.status, CollatorStatus::Active);
+            assert!(ParachainStaking::unapplied_slashes(8).is_empty());
+        });
+}
+
+#[test]
+fn offence_which_failed_to_slash_can_be_reported_again() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 40)])
+        .build()
+        .execute_with(|| {
+            let snapshot = <AtStake<Test>>::take(1, 1);
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            assert!(!<ProcessedOffences<Test>>::contains_key(1, 1));
+            assert!(ParachainStaking::unapplied_slashes(3).is_empty());
+
+            <AtStake<Test>>::insert(1, 1, snapshot);
+            <ParachainStaking as ReportOffence<u64>>::report_offence(&[(
+                1,
+                1,
+                Perbill::from_percent(10),
+            )]);
+            assert!(<ProcessedOffences<Test>>::contains_key(1, 1));
+            assert_eq!(ParachainStaking::unapplied_slashes(3).len(), 1);
+        });
+}
@@ -22,18 +22,23 @@
 //! 3. Public (Collator, Nominator)
 //! 4. Miscellaneous Property-Based Tests
 use crate::{
-    assert_eq_events, assert_eq_last_events, assert_event_emitted, assert_last_event,
-    assert_tail_eq,
+    assert_eq_events, assert_eq_last_events, assert_event_emitted, assert_event_not_emitted,
+    assert_last_event, assert_tail_eq,
+    inflation::{perbill_annual_to_perbill_era, BLOCKS_PER_YEAR},
     mock::{
         roll_one_block, roll_to, roll_to_era_begin, roll_to_era_end, set_author, set_reward_pot,
-        Balances, Event as MetaEvent, ExtBuilder, Origin, ParachainStaking, Test,
+        Balances, Event as MetaEvent, ExistentialDeposit, ExtBuilder, MaxCandidates,
+        MaxTopNominationsPerCandidate, Origin, ParachainStaking, Test,
     },
     nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest},
-    AtStake, Bond, CollatorStatus, Error, Event, NominationScheduledRequests, NominatorAdded,
-    NominatorState, NominatorStatus, NOMINATOR_LOCK_ID,
+    AtStake, AutoCompoundConfig, AutoCompoundingNominations, Bond, CollatorStatus,
+    CommissionChangeRequest, Error, Event, FailedRewardPayment, FailedRewardPayments,
+    LockedEraPayout, NominationScheduledRequests, Nominations, NominatorAdded, NominatorState,
+    NominatorStatus, Payee, PayoutMode, Range, RewardDestination, StakingParameter,
+    NOMINATOR_LOCK_ID,
 };
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::{traits::Zero, DispatchError, ModuleError};
+use frame_support::{assert_noop, assert_ok, bounded_vec, BoundedVec};
+use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
 
@@ -48,6 +53,14 @@ fn invalid_root_origin_fails() {
             ParachainStaking::set_blocks_per_era(Origin::signed(45), 3u32),
             sp_runtime::DispatchError::BadOrigin
         );
+        assert_noop!(
+            ParachainStaking::set_payout_mode(Origin::signed(45), PayoutMode::Claim),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_eligible_ratio(Origin::signed(45), None),
+            sp_runtime::DispatchError::BadOrigin
+        );
     });
 }
 
@@ -154,6 +167,16 @@ fn cannot_set_total_selected_below_module_min() {
     });
 }
 
+#[test]
+fn cannot_set_total_selected_above_max_candidates() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_total_selected(Origin::root(), 21u32),
+            Error::<Test>::CannotSetAboveMaxCandidates
+        );
+    });
+}
+
 // SET BLOCKS PER ERA
 
 #[test]
@@ -228,1205 +251,2639 @@ fn era_immediately_jumps_if_current_duration_exceeds_new_blocks_per_era() {
         });
 }
 
-// ~~ PUBLIC ~~
-
-// JOIN CANDIDATES
+// SET PAYOUT MODE
 
 #[test]
-fn join_candidates_event_emits_correctly() {
-    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
-        assert_last_event!(MetaEvent::ParachainStaking(Event::JoinedCollatorCandidates {
-            account: 1,
-            amount_locked: 10u128,
-            new_total_amt_locked: 10u128,
+fn set_payout_mode_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::PayoutModeSet {
+            old: PayoutMode::Push,
+            new: PayoutMode::Claim,
         }));
     });
 }
 
 #[test]
-fn join_candidates_reserves_balance() {
-    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-        assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
-        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
-        assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
+fn set_payout_mode_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::payout_mode(), PayoutMode::Push);
+        assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+        assert_eq!(ParachainStaking::payout_mode(), PayoutMode::Claim);
     });
 }
 
 #[test]
-fn join_candidates_increases_total_staked() {
-    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-        assert_eq!(ParachainStaking::total(), 0);
-        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
-        assert_eq!(ParachainStaking::total(), 10);
+fn cannot_set_payout_mode_to_current_payout_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Push),
+            Error::<Test>::NoWritingSameValue
+        );
     });
 }
 
+// SET ELIGIBLE RATIO
+
 #[test]
-fn join_candidates_creates_candidate_state() {
-    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-        assert!(ParachainStaking::candidate_info(1).is_none());
-        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
-        let candidate_state = ParachainStaking::candidate_info(1).expect("just joined => exists");
-        assert_eq!(candidate_state.bond, 10u128);
+fn set_eligible_ratio_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_eligible_ratio(
+            Origin::root(),
+            Some(Percent::from_percent(50))
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::EligibleRatioSet {
+            old: None,
+            new: Some(Percent::from_percent(50)),
+        }));
     });
 }
 
 #[test]
-fn join_candidates_adds_to_candidate_pool() {
-    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
-        assert!(ParachainStaking::candidate_pool().0.is_empty());
-        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
-        let candidate_pool = ParachainStaking::candidate_pool();
-        assert_eq!(candidate_pool.0[0].owner, 1);
-        assert_eq!(candidate_pool.0[0].amount, 10);
+fn set_eligible_ratio_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::eligible_ratio(), None);
+        assert_ok!(ParachainStaking::set_eligible_ratio(
+            Origin::root(),
+            Some(Percent::from_percent(50))
+        ));
+        assert_eq!(ParachainStaking::eligible_ratio(), Some(Percent::from_percent(50)));
+        assert_ok!(ParachainStaking::set_eligible_ratio(Origin::root(), None));
+        assert_eq!(ParachainStaking::eligible_ratio(), None);
     });
 }
 
 #[test]
-fn cannot_join_candidates_if_candidate() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 1000)])
-        .with_candidates(vec![(1, 500)])
-        .build()
-        .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::join_candidates(Origin::signed(1), 11u128, 100u32),
-                Error::<Test>::CandidateExists
-            );
-        });
+fn cannot_set_eligible_ratio_to_zero() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_eligible_ratio(Origin::root(), Some(Percent::zero())),
+            Error::<Test>::EligibleRatioCannotBeZero
+        );
+    });
 }
 
 #[test]
-fn cannot_join_candidates_if_nominator() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 50), (2, 20)])
-        .with_candidates(vec![(1, 50)])
-        .with_nominations(vec![(2, 1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::join_candidates(Origin::signed(2), 10u128, 1u32),
-                Error::<Test>::NominatorExists
-            );
-        });
+fn cannot_set_eligible_ratio_to_current_eligible_ratio() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_eligible_ratio(Origin::root(), None),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
+// ~~ MONETARY GOVERNANCE ~~
+
 #[test]
-fn cannot_join_candidates_without_min_bond() {
-    ExtBuilder::default().with_balances(vec![(1, 1000)]).build().execute_with(|| {
+fn invalid_monetary_governance_origin_fails() {
+    ExtBuilder::default().build().execute_with(|| {
         assert_noop!(
-            ParachainStaking::join_candidates(Origin::signed(1), 9u128, 100u32),
-            Error::<Test>::CandidateBondBelowMin
+            ParachainStaking::set_staking_expectations(
+                Origin::signed(45),
+                Range {
+                    min: Perbill::from_percent(3),
+                    ideal: Perbill::from_percent(4),
+                    max: Perbill::from_percent(5)
+                }
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_inflation(
+                Origin::signed(45),
+                Range {
+                    min: Perbill::from_percent(3),
+                    ideal: Perbill::from_percent(4),
+                    max: Perbill::from_percent(5)
+                }
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_account(Origin::signed(45), 11),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_reserve_percent(
+                Origin::signed(45),
+                Percent::from_percent(2)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::signed(45),
+                StakingParameter::MinNomination(4)
+            ),
+            sp_runtime::DispatchError::BadOrigin
         );
     });
 }
 
+// SET STAKING EXPECTATIONS
+
 #[test]
-fn cannot_join_candidates_with_more_than_available_balance() {
-    ExtBuilder::default().with_balances(vec![(1, 500)]).build().execute_with(|| {
-        assert_noop!(
-            ParachainStaking::join_candidates(Origin::signed(1), 501u128, 100u32),
-            DispatchError::Module(ModuleError {
-                index: 2,
-                error: [8, 0, 0, 0],
-                message: Some("InsufficientBalance")
-            })
-        );
+fn set_staking_expectations_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        // valid call succeeds
+        assert_ok!(ParachainStaking::set_staking_expectations(
+            Origin::root(),
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_percent(5)
+            }
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::StakeExpectationsSet {
+            expect_min: Perbill::from_percent(3),
+            expect_ideal: Perbill::from_percent(4),
+            expect_max: Perbill::from_percent(5),
+        }));
     });
 }
 
 #[test]
-fn insufficient_join_candidates_weight_hint_fails() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .build()
-        .execute_with(|| {
-            for i in 0..5 {
-                assert_noop!(
-                    ParachainStaking::join_candidates(Origin::signed(6), 20, i),
-                    Error::<Test>::TooLowCandidateCountWeightHintJoinCandidates
-                );
+fn set_staking_expectations_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::inflation_config().expect, Range::default());
+        assert_ok!(ParachainStaking::set_staking_expectations(
+            Origin::root(),
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_percent(5)
             }
-        });
+        ));
+        assert_eq!(
+            ParachainStaking::inflation_config().expect,
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_percent(5)
+            }
+        );
+    });
 }
 
 #[test]
-fn sufficient_join_candidates_weight_hint_succeeds() {
-    ExtBuilder::default()
-        .with_balances(vec![
-            (1, 20),
-            (2, 20),
-            (3, 20),
-            (4, 20),
-            (5, 20),
-            (6, 20),
-            (7, 20),
-            (8, 20),
-            (9, 20),
-        ])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .build()
-        .execute_with(|| {
-            let mut count = 5u32;
-            for i in 6..10 {
-                assert_ok!(ParachainStaking::join_candidates(Origin::signed(i), 20, count));
-                count += 1u32;
+fn cannot_set_invalid_staking_expectations() {
+    ExtBuilder::default().build().execute_with(|| {
+        // invalid call fails
+        assert_noop!(
+            ParachainStaking::set_staking_expectations(
+                Origin::root(),
+                Range {
+                    min: Perbill::from_percent(5),
+                    ideal: Perbill::from_percent(4),
+                    max: Perbill::from_percent(3)
+                }
+            ),
+            Error::<Test>::InvalidSchedule
+        );
+    });
+}
+
+#[test]
+fn cannot_set_same_staking_expectations() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_staking_expectations(
+            Origin::root(),
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_percent(5)
             }
-        });
+        ));
+        assert_noop!(
+            ParachainStaking::set_staking_expectations(
+                Origin::root(),
+                Range {
+                    min: Perbill::from_percent(3),
+                    ideal: Perbill::from_percent(4),
+                    max: Perbill::from_percent(5)
+                }
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
-// SCHEDULE LEAVE CANDIDATES
+// SET INFLATION
 
 #[test]
-fn leave_candidates_event_emits_correctly() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateScheduledExit {
-                exit_allowed_era: 1,
-                candidate: 1,
-                scheduled_exit: 3
-            }));
-        });
+fn set_inflation_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (min, ideal, max): (Perbill, Perbill, Perbill) =
+            (Perbill::from_percent(3), Perbill::from_percent(4), Perbill::from_percent(5));
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), Range { min, ideal, max }));
+        let era = perbill_annual_to_perbill_era(Range { min, ideal, max }, BLOCKS_PER_YEAR / 5);
+        assert_last_event!(MetaEvent::ParachainStaking(Event::InflationSet {
+            annual_min: min,
+            annual_ideal: ideal,
+            annual_max: max,
+            era_min: era.min,
+            era_ideal: era.ideal,
+            era_max: era.max,
+        }));
+    });
 }
 
 #[test]
-fn leave_candidates_removes_candidate_from_candidate_pool() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_eq!(ParachainStaking::candidate_pool().0.len(), 1);
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert!(ParachainStaking::candidate_pool().0.is_empty());
-        });
+fn set_inflation_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (min, ideal, max): (Perbill, Perbill, Perbill) =
+            (Perbill::from_percent(3), Perbill::from_percent(4), Perbill::from_percent(5));
+        assert_eq!(ParachainStaking::inflation_config().annual, Range::default());
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), Range { min, ideal, max }));
+        assert_eq!(ParachainStaking::inflation_config().annual, Range { min, ideal, max });
+        assert_eq!(
+            ParachainStaking::inflation_config().era,
+            perbill_annual_to_perbill_era(Range { min, ideal, max }, BLOCKS_PER_YEAR / 5)
+        );
+    });
 }
 
 #[test]
-fn cannot_leave_candidates_if_not_candidate() {
+fn cannot_set_invalid_inflation() {
     ExtBuilder::default().build().execute_with(|| {
         assert_noop!(
-            ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32),
-            Error::<Test>::CandidateDNE
+            ParachainStaking::set_inflation(
+                Origin::root(),
+                Range {
+                    min: Perbill::from_percent(5),
+                    ideal: Perbill::from_percent(4),
+                    max: Perbill::from_percent(3)
+                }
+            ),
+            Error::<Test>::InvalidSchedule
         );
     });
 }
 
 #[test]
-fn cannot_leave_candidates_if_already_leaving_candidates() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_noop!(
-                ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32),
-                Error::<Test>::CandidateAlreadyLeaving
-            );
-        });
+fn cannot_set_same_inflation() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (min, ideal, max): (Perbill, Perbill, Perbill) =
+            (Perbill::from_percent(3), Perbill::from_percent(4), Perbill::from_percent(5));
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), Range { min, ideal, max }));
+        assert_noop!(
+            ParachainStaking::set_inflation(Origin::root(), Range { min, ideal, max }),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
+// SET PARACHAIN BOND ACCOUNT
+
 #[test]
-fn insufficient_leave_candidates_weight_hint_fails() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .build()
-        .execute_with(|| {
-            for i in 1..6 {
-                assert_noop!(
-                    ParachainStaking::schedule_leave_candidates(Origin::signed(i), 4u32),
-                    Error::<Test>::TooLowCandidateCountToLeaveCandidates
-                );
-            }
-        });
+fn set_parachain_bond_account_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_account(Origin::root(), 11));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::ParachainBondAccountSet {
+            old: 0,
+            new: 11,
+        }));
+    });
 }
 
 #[test]
-fn sufficient_leave_candidates_weight_hint_succeeds() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .build()
-        .execute_with(|| {
-            let mut count = 5u32;
-            for i in 1..6 {
-                assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(i), count));
-                count -= 1u32;
-            }
-        });
+fn set_parachain_bond_account_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::parachain_bond_info().account, 0);
+        assert_ok!(ParachainStaking::set_parachain_bond_account(Origin::root(), 11));
+        assert_eq!(ParachainStaking::parachain_bond_info().account, 11);
+    });
 }
 
-// EXECUTE LEAVE CANDIDATES
-
 #[test]
-fn execute_leave_candidates_emits_event() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateLeft {
-                ex_candidate: 1,
-                unlocked_amount: 10,
-                new_total_amt_locked: 0
-            }));
-        });
+fn cannot_set_same_parachain_bond_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_account(Origin::root(), 11));
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_account(Origin::root(), 11),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
-#[test]
-fn execute_leave_candidates_callable_by_any_signed() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(2), 1, 0));
-        });
-}
+// SET PARACHAIN BOND RESERVE PERCENT
 
 #[test]
-fn execute_leave_candidates_requires_correct_weight_hint() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10), (2, 10), (3, 10), (4, 10)])
-        .with_candidates(vec![(1, 10)])
-        .with_nominations(vec![(2, 1, 10), (3, 1, 10), (4, 1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            roll_to(10);
-            for i in 0..3 {
-                assert_noop!(
-                    ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, i),
-                    Error::<Test>::TooLowCandidateNominationCountToLeaveCandidates
-                );
-            }
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(2), 1, 3));
-        });
+fn set_parachain_bond_reserve_percent_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+            Origin::root(),
+            Percent::from_percent(50)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::ParachainBondReservePercentSet {
+            old: Percent::zero(),
+            new: Percent::from_percent(50),
+        }));
+    });
 }
 
 #[test]
-fn execute_leave_candidates_unreserves_balance() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
-        });
+fn set_parachain_bond_reserve_percent_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::parachain_bond_info().percent, Percent::zero());
+        assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+            Origin::root(),
+            Percent::from_percent(50)
+        ));
+        assert_eq!(ParachainStaking::parachain_bond_info().percent, Percent::from_percent(50));
+    });
 }
 
 #[test]
-fn execute_leave_candidates_decreases_total_staked() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 10);
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
-            assert_eq!(ParachainStaking::total(), 0);
-        });
+fn cannot_set_same_parachain_bond_reserve_percent() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_reserve_percent(Origin::root(), Percent::zero()),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
 #[test]
-fn execute_leave_candidates_removes_candidate_state() {
+fn parachain_bond_reserve_is_carved_from_era_reward() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            // candidate state is not immediately removed
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("just left => still exists");
-            assert_eq!(candidate_state.bond, 10u128);
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
-            assert!(ParachainStaking::candidate_info(1).is_none());
+            assert_ok!(ParachainStaking::set_parachain_bond_account(Origin::root(), 11));
+            assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+                Origin::root(),
+                Percent::from_percent(30)
+            ));
+            set_author(1, 1, 1);
+            set_reward_pot(100);
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::ReservedForParachainBond { account: 11, value: 30 });
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 70,
+                destination: RewardDestination::Bonded,
+            });
+            let payout = ParachainStaking::delayed_payouts(1).expect("era 1 is being paid out");
+            assert_eq!(payout.era_issuance, 100);
+            assert_eq!(payout.total_staking_reward, 70);
+            assert_eq!(Balances::free_balance(&11), 30);
+            assert_eq!(ParachainStaking::locked_era_payout(), 0);
         });
 }
 
 #[test]
-fn execute_leave_candidates_removes_pending_nomination_requests() {
+fn failed_parachain_bond_reserve_stays_in_the_reward_pot() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 10), (2, 15)])
-        .with_candidates(vec![(1, 10)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            let state = ParachainStaking::nomination_scheduled_requests(&1);
-            assert_eq!(
-                state,
-                vec![ScheduledRequest {
-                    nominator: 2,
-                    when_executable: 3,
-                    action: NominationAction::Decrease(5),
-                }],
-            );
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            // candidate state is not immediately removed
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("just left => still exists");
-            assert_eq!(candidate_state.bond, 10u128);
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
-            assert!(ParachainStaking::candidate_info(1).is_none());
-            assert!(
-                !ParachainStaking::nomination_scheduled_requests(&1)
-                    .iter()
-                    .any(|x| x.nominator == 2),
-                "nomination request not removed"
-            );
-            assert!(
-                !<NominationScheduledRequests<Test>>::contains_key(&1),
-                "the key was not removed from storage"
-            );
+            assert_ok!(ParachainStaking::set_parachain_bond_account(Origin::root(), 11));
+            assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+                Origin::root(),
+                Percent::from_percent(30)
+            ));
+            // the reserve of 30% of the 60 left in the pot, above the existential deposit and
+            // what is locked, is below the existential deposit of the new bond account
+            ExistentialDeposit::set(40);
+            set_author(1, 1, 1);
+            set_reward_pot(200);
+            <LockedEraPayout<Test>>::put(100);
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::ParachainBondReserveDeferred { account: 11, value: 18 });
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 42,
+                destination: RewardDestination::Bonded,
+            });
+            let payout = ParachainStaking::delayed_payouts(1).expect("era 1 is being paid out");
+            assert_eq!(payout.total_staking_reward, 42);
+            assert_eq!(Balances::free_balance(&11), 0);
+            assert_eq!(ParachainStaking::reward_pot(), 118);
+            assert_eq!(ParachainStaking::locked_era_payout(), 100);
         });
 }
 
 #[test]
-fn cannot_execute_leave_candidates_before_delay() {
+fn no_parachain_bond_reserve_by_default() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_noop!(
-                ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0),
-                Error::<Test>::CandidateCannotLeaveYet
-            );
-            roll_to(9);
-            assert_noop!(
-                ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0),
-                Error::<Test>::CandidateCannotLeaveYet
-            );
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0));
+            set_author(1, 1, 1);
+            set_reward_pot(100);
+            roll_to_era_begin(3);
+            assert_event_not_emitted!(Event::ReservedForParachainBond { account: 0, value: 0 });
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 100,
+                destination: RewardDestination::Bonded,
+            });
         });
 }
 
-// CANCEL LEAVE CANDIDATES
+// SET STAKING PARAMETER
 
 #[test]
-fn cancel_leave_candidates_emits_event() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledCandidateExit {
-                candidate: 1
-            }));
-        });
+fn set_staking_parameter_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_staking_parameter(
+            Origin::root(),
+            StakingParameter::MinNomination(4)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::StakingParameterSet {
+            old: StakingParameter::MinNomination(3),
+            new: StakingParameter::MinNomination(4),
+        }));
+    });
 }
 
 #[test]
-fn cancel_leave_candidates_updates_candidate_state() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
-            let candidate =
-                ParachainStaking::candidate_info(&1).expect("just cancelled leave so exists");
-            assert!(candidate.is_active());
-        });
+fn staking_params_are_seeded_from_config() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::staking_params(), ParachainStaking::initial_staking_params());
+        assert_eq!(ParachainStaking::staking_params().min_nomination, 3);
+        assert_eq!(ParachainStaking::pending_staking_params(), None);
+    });
 }
 
 #[test]
-fn cancel_leave_candidates_adds_to_candidate_pool() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 10)])
-        .with_candidates(vec![(1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
-            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 10);
-        });
+fn set_staking_parameter_applies_at_next_era() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_staking_parameter(
+            Origin::root(),
+            StakingParameter::MinNomination(4)
+        ));
+        assert_ok!(ParachainStaking::set_staking_parameter(
+            Origin::root(),
+            StakingParameter::LeaveCandidatesDelay(3)
+        ));
+        assert_eq!(ParachainStaking::staking_params().min_nomination, 3);
+        let pending = ParachainStaking::pending_staking_params().expect("parameters were set");
+        assert_eq!(pending.min_nomination, 4);
+        assert_eq!(pending.leave_candidates_delay, 3);
+        roll_to_era_begin(2);
+        assert_eq!(ParachainStaking::staking_params(), pending);
+        assert_eq!(ParachainStaking::pending_staking_params(), None);
+    });
 }
 
-// GO OFFLINE
-
 #[test]
-fn go_offline_event_emits_correctly() {
+fn min_candidate_stk_set_by_governance_is_enforced_from_next_era() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![(1, 100), (2, 100)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateWentOffline {
-                candidate: 1
-            }));
+            assert_ok!(ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::MinCandidateStk(30)
+            ));
+            assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 25, 0));
+            roll_to_era_begin(2);
+            assert_noop!(
+                ParachainStaking::join_candidates(Origin::signed(2), 25, 1),
+                Error::<Test>::CandidateBondBelowMin
+            );
+            assert_ok!(ParachainStaking::join_candidates(Origin::signed(2), 30, 1));
         });
 }
 
 #[test]
-fn go_offline_removes_candidate_from_candidate_pool() {
+fn leave_candidates_delay_set_by_governance_delays_exit() {
     ExtBuilder::default()
         .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::candidate_pool().0.len(), 1);
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            assert!(ParachainStaking::candidate_pool().0.is_empty());
+            assert_ok!(ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::LeaveCandidatesDelay(4)
+            ));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateScheduledExit {
+                exit_allowed_era: 2,
+                candidate: 1,
+                scheduled_exit: 6,
+            }));
         });
 }
 
 #[test]
-fn go_offline_updates_candidate_state_to_idle() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            let candidate_state = ParachainStaking::candidate_info(1).expect("is active candidate");
-            assert_eq!(candidate_state.status, CollatorStatus::Active);
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("is candidate, just offline");
-            assert_eq!(candidate_state.status, CollatorStatus::Idle);
-        });
+fn cannot_set_staking_parameter_to_same_value() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::MinNomination(3)
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+        assert_ok!(ParachainStaking::set_staking_parameter(
+            Origin::root(),
+            StakingParameter::MinNomination(4)
+        ));
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::MinNomination(4)
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
 }
 
 #[test]
-fn cannot_go_offline_if_not_candidate() {
+fn cannot_set_staking_parameter_out_of_bounds() {
     ExtBuilder::default().build().execute_with(|| {
-        assert_noop!(ParachainStaking::go_offline(Origin::signed(3)), Error::<Test>::CandidateDNE);
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::MinCandidateStk(0)
+            ),
+            Error::<Test>::CannotSetBelowMin
+        );
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::RewardPaymentDelay(0)
+            ),
+            Error::<Test>::CannotSetBelowMin
+        );
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::MinNomination(6)
+            ),
+            Error::<Test>::MinNominationAboveMinNominatorStk
+        );
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::RevokeNominationDelay(1)
+            ),
+            Error::<Test>::DelayShorterThanSlashDeferDuration
+        );
+        assert_noop!(
+            ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::RewardPaymentDelay(1)
+            ),
+            Error::<Test>::RewardPaymentDelayCannotDecrease
+        );
+        // the commission change delay does not protect slashable stake
+        assert_ok!(ParachainStaking::set_staking_parameter(
+            Origin::root(),
+            StakingParameter::CommissionChangeDelay(1)
+        ));
     });
 }
 
 #[test]
-fn cannot_go_offline_if_already_offline() {
+fn increased_reward_payment_delay_does_not_pay_era_twice() {
     ExtBuilder::default()
         .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            assert_noop!(
-                ParachainStaking::go_offline(Origin::signed(1)),
-                Error::<Test>::AlreadyOffline
-            );
+            set_author(1, 1, 1);
+            set_author(2, 1, 1);
+            set_reward_pot(10);
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 10,
+                destination: RewardDestination::Bonded,
+            });
+            assert_ok!(ParachainStaking::set_staking_parameter(
+                Origin::root(),
+                StakingParameter::RewardPaymentDelay(3)
+            ));
+            set_reward_pot(20);
+            roll_to_era_begin(4);
+            assert_event_not_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 20,
+                destination: RewardDestination::Bonded,
+            });
+            roll_to_era_begin(5);
+            assert_event_emitted!(Event::Rewarded {
+                account: 1,
+                rewards: 20,
+                destination: RewardDestination::Bonded,
+            });
         });
 }
 
-// GO ONLINE
+// ~~ PUBLIC ~~
+
+// JOIN CANDIDATES
 
 #[test]
-fn go_online_event_emits_correctly() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBackOnline {
-                candidate: 1
-            }));
-        });
+fn join_candidates_event_emits_correctly() {
+    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::JoinedCollatorCandidates {
+            account: 1,
+            amount_locked: 10u128,
+            new_total_amt_locked: 10u128,
+        }));
+    });
 }
 
 #[test]
-fn go_online_adds_to_candidate_pool() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            assert!(ParachainStaking::candidate_pool().0.is_empty());
-            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
-        });
+fn join_candidates_reserves_balance() {
+    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+        assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
+        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
+        assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
+    });
 }
 
 #[test]
-fn go_online_storage_updates_candidate_state() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("offline still exists");
-            assert_eq!(candidate_state.status, CollatorStatus::Idle);
-            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
-            let candidate_state = ParachainStaking::candidate_info(1).expect("online so exists");
-            assert_eq!(candidate_state.status, CollatorStatus::Active);
-        });
+fn join_candidates_increases_total_staked() {
+    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+        assert_eq!(ParachainStaking::total(), 0);
+        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
+        assert_eq!(ParachainStaking::total(), 10);
+    });
 }
 
 #[test]
-fn cannot_go_online_if_not_candidate() {
-    ExtBuilder::default().build().execute_with(|| {
-        assert_noop!(ParachainStaking::go_online(Origin::signed(3)), Error::<Test>::CandidateDNE);
+fn join_candidates_creates_candidate_state() {
+    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+        assert!(ParachainStaking::candidate_info(1).is_none());
+        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
+        let candidate_state = ParachainStaking::candidate_info(1).expect("just joined => exists");
+        assert_eq!(candidate_state.bond, 10u128);
     });
 }
 
 #[test]
-fn cannot_go_online_if_already_online() {
+fn join_candidates_adds_to_candidate_pool() {
+    ExtBuilder::default().with_balances(vec![(1, 10)]).build().execute_with(|| {
+        assert!(ParachainStaking::candidate_pool().is_empty());
+        assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 10u128, 0u32));
+        let candidate_pool = ParachainStaking::candidate_pool();
+        assert_eq!(candidate_pool[0].owner, 1);
+        assert_eq!(candidate_pool[0].amount, 10);
+    });
+}
+
+#[test]
+fn cannot_join_candidates_if_candidate() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![(1, 1000)])
+        .with_candidates(vec![(1, 500)])
         .build()
         .execute_with(|| {
             assert_noop!(
-                ParachainStaking::go_online(Origin::signed(1)),
-                Error::<Test>::AlreadyActive
+                ParachainStaking::join_candidates(Origin::signed(1), 11u128, 100u32),
+                Error::<Test>::CandidateExists
             );
         });
 }
 
 #[test]
-fn cannot_go_online_if_leaving() {
+fn cannot_join_candidates_if_candidate_pool_is_full() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+        .with_candidates(vec![(1, 500), (2, 500)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            MaxCandidates::set(2);
             assert_noop!(
-                ParachainStaking::go_online(Origin::signed(1)),
-                Error::<Test>::CannotGoOnlineIfLeaving
+                ParachainStaking::join_candidates(Origin::signed(3), 11u128, 100u32),
+                Error::<Test>::CandidateLimitReached
             );
         });
 }
 
-// CANDIDATE BOND MORE
-
 #[test]
-fn candidate_bond_more_emits_correct_event() {
+fn cannot_join_candidates_if_nominator() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![(1, 50), (2, 20)])
+        .with_candidates(vec![(1, 50)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondedMore {
-                candidate: 1,
-                amount: 30,
-                new_total_bond: 50
-            }));
+            assert_noop!(
+                ParachainStaking::join_candidates(Origin::signed(2), 10u128, 1u32),
+                Error::<Test>::NominatorExists
+            );
         });
 }
 
 #[test]
-fn candidate_bond_more_reserves_balance() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 30);
-            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
-        });
+fn cannot_join_candidates_without_min_bond() {
+    ExtBuilder::default().with_balances(vec![(1, 1000)]).build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::join_candidates(Origin::signed(1), 9u128, 100u32),
+            Error::<Test>::CandidateBondBelowMin
+        );
+    });
 }
 
 #[test]
-fn candidate_bond_more_increases_total() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            let mut total = ParachainStaking::total();
-            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
-            total += 30;
-            assert_eq!(ParachainStaking::total(), total);
-        });
+fn cannot_join_candidates_with_more_than_available_balance() {
+    ExtBuilder::default().with_balances(vec![(1, 500)]).build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::join_candidates(Origin::signed(1), 501u128, 100u32),
+            DispatchError::Module(ModuleError {
+                index: 2,
+                error: [8, 0, 0, 0],
+                message: Some("InsufficientBalance")
+            })
+        );
+    });
 }
 
 #[test]
-fn candidate_bond_more_updates_candidate_state() {
+fn insufficient_join_candidates_weight_hint_fails() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
         .build()
         .execute_with(|| {
-            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
-            assert_eq!(candidate_state.bond, 20);
-            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
-            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
-            assert_eq!(candidate_state.bond, 50);
+            for i in 0..5 {
+                assert_noop!(
+                    ParachainStaking::join_candidates(Origin::signed(6), 20, i),
+                    Error::<Test>::TooLowCandidateCountWeightHintJoinCandidates
+                );
+            }
         });
 }
 
 #[test]
-fn candidate_bond_more_updates_candidate_pool() {
+fn sufficient_join_candidates_weight_hint_succeeds() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 20)])
+        .with_balances(vec![
+            (1, 20),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (7, 20),
+            (8, 20),
+            (9, 20),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
-            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 50);
+            let mut count = 5u32;
+            for i in 6..10 {
+                assert_ok!(ParachainStaking::join_candidates(Origin::signed(i), 20, count));
+                count += 1u32;
+            }
         });
 }
 
-// SCHEDULE CANDIDATE BOND LESS
+// SCHEDULE LEAVE CANDIDATES
 
 #[test]
-fn schedule_candidate_bond_less_event_emits_correctly() {
+fn leave_candidates_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondLessRequested {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateScheduledExit {
+                exit_allowed_era: 1,
                 candidate: 1,
-                amount_to_decrease: 10,
-                execute_era: 3,
+                scheduled_exit: 3
             }));
         });
 }
 
 #[test]
-fn cannot_schedule_candidate_bond_less_if_request_exists() {
+fn leave_candidates_removes_candidate_from_candidate_pool() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 5));
-            assert_noop!(
-                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 5),
-                Error::<Test>::PendingCandidateRequestAlreadyExists
-            );
+            assert_eq!(ParachainStaking::candidate_pool().len(), 1);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            assert!(ParachainStaking::candidate_pool().is_empty());
         });
 }
 
 #[test]
-fn cannot_schedule_candidate_bond_less_if_not_candidate() {
+fn cannot_leave_candidates_if_not_candidate() {
     ExtBuilder::default().build().execute_with(|| {
         assert_noop!(
-            ParachainStaking::schedule_candidate_bond_less(Origin::signed(6), 50),
+            ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32),
             Error::<Test>::CandidateDNE
         );
     });
 }
 
 #[test]
-fn cannot_schedule_candidate_bond_less_if_new_total_below_min_candidate_stk() {
+fn cannot_leave_candidates_if_already_leaving_candidates() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             assert_noop!(
-                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 21),
-                Error::<Test>::CandidateBondBelowMin
+                ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32),
+                Error::<Test>::CandidateAlreadyLeaving
             );
         });
 }
 
 #[test]
-fn can_schedule_candidate_bond_less_if_leaving_candidates() {
+fn insufficient_leave_candidates_weight_hint_fails() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
-        });
-}
-
+            for i in 1..6 {
+                assert_noop!(
+                    ParachainStaking::schedule_leave_candidates(Origin::signed(i), 4u32),
+                    Error::<Test>::TooLowCandidateCountToLeaveCandidates
+                );
+            }
+        });
+}
+
 #[test]
-fn cannot_schedule_candidate_bond_less_if_exited_candidates() {
+fn sufficient_leave_candidates_weight_hint_succeeds() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
-            assert_noop!(
-                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10),
-                Error::<Test>::CandidateDNE
-            );
+            let mut count = 5u32;
+            for i in 1..6 {
+                assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(i), count));
+                count -= 1u32;
+            }
         });
 }
 
-// 2. EXECUTE BOND LESS REQUEST
+// EXECUTE LEAVE CANDIDATES
 
 #[test]
-fn execute_candidate_bond_less_emits_correct_event() {
+fn execute_leave_candidates_emits_event() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 50)])
-        .with_candidates(vec![(1, 50)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 30));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondedLess {
-                candidate: 1,
-                amount: 30,
-                new_bond: 20
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateLeft {
+                ex_candidate: 1,
+                unlocked_amount: 10,
+                new_total_amt_locked: 0
             }));
         });
 }
 
 #[test]
-fn execute_candidate_bond_less_unreserves_balance() {
+fn execute_leave_candidates_callable_by_any_signed() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
-            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(2), 1, 0));
         });
 }
 
 #[test]
-fn execute_candidate_bond_less_decreases_total() {
+fn execute_leave_candidates_requires_correct_weight_hint() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10), (2, 10), (3, 10), (4, 10)])
+        .with_candidates(vec![(1, 10)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 10), (4, 1, 10)])
         .build()
         .execute_with(|| {
-            let mut total = ParachainStaking::total();
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
-            total -= 10;
-            assert_eq!(ParachainStaking::total(), total);
+            for i in 0..3 {
+                assert_noop!(
+                    ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, i),
+                    Error::<Test>::TooLowCandidateNominationCountToLeaveCandidates
+                );
+            }
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(2), 1, 3));
         });
 }
 
 #[test]
-fn execute_candidate_bond_less_updates_candidate_state() {
+fn execute_leave_candidates_unreserves_balance() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
-            assert_eq!(candidate_state.bond, 30);
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
-            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
-            assert_eq!(candidate_state.bond, 20);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
         });
 }
 
 #[test]
-fn execute_candidate_bond_less_updates_candidate_pool() {
+fn execute_leave_candidates_decreases_total_staked() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 30);
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_eq!(ParachainStaking::total(), 10);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
-            assert_eq!(ParachainStaking::candidate_pool().0[0].owner, 1);
-            assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 20);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert_eq!(ParachainStaking::total(), 0);
         });
 }
 
-// CANCEL CANDIDATE BOND LESS REQUEST
-
 #[test]
-fn cancel_candidate_bond_less_emits_event() {
+fn execute_leave_candidates_removes_candidate_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
-            assert_ok!(ParachainStaking::cancel_candidate_bond_less(Origin::signed(1)));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledCandidateBondLess {
-                candidate: 1,
-                amount: 10,
-                execute_era: 3,
-            }));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            // candidate state is not immediately removed
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("just left => still exists");
+            assert_eq!(candidate_state.bond, 10u128);
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert!(ParachainStaking::candidate_info(1).is_none());
         });
 }
 
 #[test]
-fn cancel_candidate_bond_less_updates_candidate_state() {
+fn execute_leave_candidates_removes_pending_nomination_requests() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10), (2, 15)])
+        .with_candidates(vec![(1, 10)])
+        .with_nominations(vec![(2, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
-            assert_ok!(ParachainStaking::cancel_candidate_bond_less(Origin::signed(1)));
-            assert!(ParachainStaking::candidate_info(&1).unwrap().request.is_none());
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            let state = ParachainStaking::nomination_scheduled_requests(&1);
+            assert_eq!(
+                state,
+                vec![ScheduledRequest {
+                    nominator: 2,
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                }],
+            );
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            // candidate state is not immediately removed
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("just left => still exists");
+            assert_eq!(candidate_state.bond, 10u128);
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
+            assert!(ParachainStaking::candidate_info(1).is_none());
+            assert!(
+                !ParachainStaking::nomination_scheduled_requests(&1)
+                    .iter()
+                    .any(|x| x.nominator == 2),
+                "nomination request not removed"
+            );
+            assert!(
+                !<NominationScheduledRequests<Test>>::contains_key(&1),
+                "the key was not removed from storage"
+            );
         });
 }
 
 #[test]
-fn only_candidate_can_cancel_candidate_bond_less_request() {
+fn cannot_execute_leave_candidates_before_delay() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
             assert_noop!(
-                ParachainStaking::cancel_candidate_bond_less(Origin::signed(2)),
-                Error::<Test>::CandidateDNE
+                ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0),
+                Error::<Test>::CandidateCannotLeaveYet
+            );
+            roll_to(9);
+            assert_noop!(
+                ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0),
+                Error::<Test>::CandidateCannotLeaveYet
             );
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(3), 1, 0));
         });
 }
 
-// NOMINATE
+// CANCEL LEAVE CANDIDATES
 
 #[test]
-fn nominate_event_emits_correctly() {
+fn cancel_leave_candidates_emits_event() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::Nomination {
-                nominator: 2,
-                locked_amount: 10,
-                candidate: 1,
-                nominator_position: NominatorAdded::AddedToTop { new_total: 40 },
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledCandidateExit {
+                candidate: 1
             }));
         });
 }
 
 #[test]
-fn nominate_reserves_balance() {
+fn cancel_leave_candidates_updates_candidate_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
+            let candidate =
+                ParachainStaking::candidate_info(&1).expect("just cancelled leave so exists");
+            assert!(candidate.is_active());
         });
 }
 
 #[test]
-fn nominate_updates_nominator_state() {
+fn cancel_leave_candidates_adds_to_candidate_pool() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 10)])
+        .with_candidates(vec![(1, 10)])
         .build()
         .execute_with(|| {
-            assert!(ParachainStaking::nominator_state(2).is_none());
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
-            let nominator_state =
-                ParachainStaking::nominator_state(2).expect("just nominated => exists");
-            assert_eq!(nominator_state.total(), 10);
-            assert_eq!(nominator_state.nominations.0[0].owner, 1);
-            assert_eq!(nominator_state.nominations.0[0].amount, 10);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1u32));
+            assert_ok!(ParachainStaking::cancel_leave_candidates(Origin::signed(1), 1));
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 10);
         });
 }
 
+// GO OFFLINE
+
 #[test]
-fn nominate_updates_collator_state() {
+fn go_offline_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("registered in genesis");
-            assert_eq!(candidate_state.total_counted, 30);
-            let top_nominations =
-                ParachainStaking::top_nominations(1).expect("registered in genesis");
-            assert!(top_nominations.nominations.is_empty());
-            assert!(top_nominations.total.is_zero());
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("just nominated => exists");
-            assert_eq!(candidate_state.total_counted, 40);
-            let top_nominations =
-                ParachainStaking::top_nominations(1).expect("just nominated => exists");
-            assert_eq!(top_nominations.nominations[0].owner, 2);
-            assert_eq!(top_nominations.nominations[0].amount, 10);
-            assert_eq!(top_nominations.total, 10);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateWentOffline {
+                candidate: 1
+            }));
         });
 }
 
 #[test]
-fn can_nominate_immediately_after_other_join_candidates() {
+fn go_offline_removes_candidate_from_candidate_pool() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 20, 0));
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 20, 0, 0));
+            assert_eq!(ParachainStaking::candidate_pool().len(), 1);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            assert!(ParachainStaking::candidate_pool().is_empty());
         });
 }
 
 #[test]
-fn can_nominate_if_revoking() {
+fn go_offline_updates_candidate_state_to_idle() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 30), (3, 20), (4, 20)])
-        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 4, 10, 0, 2));
+            let candidate_state = ParachainStaking::candidate_info(1).expect("is active candidate");
+            assert_eq!(candidate_state.status, CollatorStatus::Active);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("is candidate, just offline");
+            assert_eq!(candidate_state.status, CollatorStatus::Idle);
         });
 }
 
 #[test]
-fn cannot_nominate_if_full_and_new_nomination_less_than_or_equal_lowest_bottom() {
+fn cannot_go_offline_if_not_candidate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(ParachainStaking::go_offline(Origin::signed(3)), Error::<Test>::CandidateDNE);
+    });
+}
+
+#[test]
+fn cannot_go_offline_if_already_offline() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 20),
-            (2, 10),
-            (3, 10),
-            (4, 10),
-            (5, 10),
-            (6, 10),
-            (7, 10),
-            (8, 10),
-            (9, 10),
-            (10, 10),
-            (11, 10),
-        ])
+        .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![
-            (2, 1, 10),
-            (3, 1, 10),
-            (4, 1, 10),
-            (5, 1, 10),
-            (6, 1, 10),
-            (8, 1, 10),
-            (9, 1, 10),
-            (10, 1, 10),
-        ])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
             assert_noop!(
-                ParachainStaking::nominate(Origin::signed(11), 1, 10, 8, 0),
-                Error::<Test>::CannotNominateLessThanOrEqualToLowestBottomWhenFull
+                ParachainStaking::go_offline(Origin::signed(1)),
+                Error::<Test>::AlreadyOffline
             );
         });
 }
 
+// GO ONLINE
+
 #[test]
-fn can_nominate_if_full_and_new_nomination_greater_than_lowest_bottom() {
+fn go_online_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 20),
-            (2, 10),
-            (3, 10),
-            (4, 10),
-            (5, 10),
-            (6, 10),
-            (7, 10),
-            (8, 10),
-            (9, 10),
-            (10, 10),
-            (11, 11),
-        ])
+        .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![
-            (2, 1, 10),
-            (3, 1, 10),
-            (4, 1, 10),
-            (5, 1, 10),
-            (6, 1, 10),
-            (8, 1, 10),
-            (9, 1, 10),
-            (10, 1, 10),
-        ])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::nominate(Origin::signed(11), 1, 11, 8, 0));
-            assert_event_emitted!(Event::NominationKicked {
-                nominator: 10,
-                candidate: 1,
-                unstaked_amount: 10
-            });
-            assert_event_emitted!(Event::NominatorLeft { nominator: 10, unstaked_amount: 10 });
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBackOnline {
+                candidate: 1
+            }));
         });
 }
 
 #[test]
-fn can_still_nominate_if_leaving() {
+fn go_online_adds_to_candidate_pool() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 20), (3, 20)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 3, 10, 0, 1),);
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            assert!(ParachainStaking::candidate_pool().is_empty());
+            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
         });
 }
 
 #[test]
-fn cannot_nominate_if_candidate() {
+fn go_online_storage_updates_candidate_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 30)])
-        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0),
-                Error::<Test>::CandidateExists
-            );
+            assert_ok!(ParachainStaking::go_offline(Origin::signed(1)));
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("offline still exists");
+            assert_eq!(candidate_state.status, CollatorStatus::Idle);
+            assert_ok!(ParachainStaking::go_online(Origin::signed(1)));
+            let candidate_state = ParachainStaking::candidate_info(1).expect("online so exists");
+            assert_eq!(candidate_state.status, CollatorStatus::Active);
         });
 }
 
 #[test]
-fn cannot_nominate_if_already_nominated() {
+fn cannot_go_online_if_not_candidate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(ParachainStaking::go_online(Origin::signed(3)), Error::<Test>::CandidateDNE);
+    });
+}
+
+#[test]
+fn cannot_go_online_if_already_online() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 30)])
+        .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![(2, 1, 20)])
         .build()
         .execute_with(|| {
             assert_noop!(
-                ParachainStaking::nominate(Origin::signed(2), 1, 10, 1, 1),
-                Error::<Test>::AlreadyNominatedCandidate
+                ParachainStaking::go_online(Origin::signed(1)),
+                Error::<Test>::AlreadyActive
             );
         });
 }
 
 #[test]
-fn cannot_nominate_more_than_max_nominations() {
+fn cannot_go_online_if_leaving() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 50), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10), (2, 5, 10)])
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
             assert_noop!(
-                ParachainStaking::nominate(Origin::signed(2), 6, 10, 0, 4),
-                Error::<Test>::ExceedMaxNominationsPerNominator,
+                ParachainStaking::go_online(Origin::signed(1)),
+                Error::<Test>::CannotGoOnlineIfLeaving
             );
         });
 }
 
+// CANDIDATE BOND MORE
+
 #[test]
-fn sufficient_nominate_weight_hint_succeeds() {
+fn candidate_bond_more_emits_correct_event() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 20),
-            (2, 20),
-            (3, 20),
-            (4, 20),
-            (5, 20),
-            (6, 20),
-            (7, 20),
-            (8, 20),
-            (9, 20),
-            (10, 20),
-        ])
-        .with_candidates(vec![(1, 20), (2, 20)])
-        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            let mut count = 4u32;
-            for i in 7..11 {
-                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32));
-                count += 1u32;
-            }
-            let mut count = 0u32;
-            for i in 3..11 {
-                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 2, 10, count, 1u32));
-                count += 1u32;
-            }
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondedMore {
+                candidate: 1,
+                amount: 30,
+                new_total_bond: 50
+            }));
         });
 }
 
 #[test]
-fn insufficient_nominate_weight_hint_fails() {
+fn candidate_bond_more_reserves_balance() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 20),
-            (2, 20),
-            (3, 20),
-            (4, 20),
-            (5, 20),
-            (6, 20),
-            (7, 20),
-            (8, 20),
-            (9, 20),
-            (10, 20),
-        ])
-        .with_candidates(vec![(1, 20), (2, 20)])
-        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 20)])
         .build()
         .execute_with(|| {
-            let mut count = 3u32;
-            for i in 7..11 {
-                assert_noop!(
-                    ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32),
-                    Error::<Test>::TooLowCandidateNominationCountToNominate
-                );
-            }
-            // to set up for next error test
-            count = 4u32;
-            for i in 7..11 {
-                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32));
-                count += 1u32;
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 30);
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
+        });
+}
+
+#[test]
+fn candidate_bond_more_increases_total() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            let mut total = ParachainStaking::total();
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
+            total += 30;
+            assert_eq!(ParachainStaking::total(), total);
+        });
+}
+
+#[test]
+fn candidate_bond_more_updates_candidate_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
+            assert_eq!(candidate_state.bond, 20);
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
+            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
+            assert_eq!(candidate_state.bond, 50);
+        });
+}
+
+#[test]
+fn candidate_bond_more_updates_candidate_pool() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(1), 30));
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 50);
+        });
+}
+
+// SCHEDULE CANDIDATE BOND LESS
+
+#[test]
+fn schedule_candidate_bond_less_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondLessRequested {
+                candidate: 1,
+                amount_to_decrease: 10,
+                execute_era: 3,
+            }));
+        });
+}
+
+#[test]
+fn cannot_schedule_candidate_bond_less_if_request_exists() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 5));
+            assert_noop!(
+                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 5),
+                Error::<Test>::PendingCandidateRequestAlreadyExists
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_candidate_bond_less_if_not_candidate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::schedule_candidate_bond_less(Origin::signed(6), 50),
+            Error::<Test>::CandidateDNE
+        );
+    });
+}
+
+#[test]
+fn cannot_schedule_candidate_bond_less_if_new_total_below_min_candidate_stk() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 21),
+                Error::<Test>::CandidateBondBelowMin
+            );
+        });
+}
+
+#[test]
+fn can_schedule_candidate_bond_less_if_leaving_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+        });
+}
+
+#[test]
+fn cannot_schedule_candidate_bond_less_if_exited_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 0));
+            assert_noop!(
+                ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10),
+                Error::<Test>::CandidateDNE
+            );
+        });
+}
+
+// 2. EXECUTE BOND LESS REQUEST
+
+#[test]
+fn execute_candidate_bond_less_emits_correct_event() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 50)])
+        .with_candidates(vec![(1, 50)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 30));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondedLess {
+                candidate: 1,
+                amount: 30,
+                new_bond: 20
+            }));
+        });
+}
+
+#[test]
+fn execute_candidate_bond_less_unreserves_balance() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 0);
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
+            assert_eq!(ParachainStaking::get_collator_stakable_free_balance(&1), 10);
+        });
+}
+
+#[test]
+fn execute_candidate_bond_less_decreases_total() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            let mut total = ParachainStaking::total();
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
+            total -= 10;
+            assert_eq!(ParachainStaking::total(), total);
+        });
+}
+
+#[test]
+fn execute_candidate_bond_less_updates_candidate_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
+            assert_eq!(candidate_state.bond, 30);
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
+            let candidate_state = ParachainStaking::candidate_info(1).expect("updated => exists");
+            assert_eq!(candidate_state.bond, 20);
+        });
+}
+
+#[test]
+fn execute_candidate_bond_less_updates_candidate_pool() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 30);
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_candidate_bond_less(Origin::signed(1), 1));
+            assert_eq!(ParachainStaking::candidate_pool()[0].owner, 1);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 20);
+        });
+}
+
+// CANCEL CANDIDATE BOND LESS REQUEST
+
+#[test]
+fn cancel_candidate_bond_less_emits_event() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_ok!(ParachainStaking::cancel_candidate_bond_less(Origin::signed(1)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledCandidateBondLess {
+                candidate: 1,
+                amount: 10,
+                execute_era: 3,
+            }));
+        });
+}
+
+#[test]
+fn cancel_candidate_bond_less_updates_candidate_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_ok!(ParachainStaking::cancel_candidate_bond_less(Origin::signed(1)));
+            assert!(ParachainStaking::candidate_info(&1).unwrap().request.is_none());
+        });
+}
+
+#[test]
+fn only_candidate_can_cancel_candidate_bond_less_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_bond_less(Origin::signed(1), 10));
+            assert_noop!(
+                ParachainStaking::cancel_candidate_bond_less(Origin::signed(2)),
+                Error::<Test>::CandidateDNE
+            );
+        });
+}
+
+// SET COMMISSION
+
+#[test]
+fn set_commission_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CandidateCommissionChangeRequested {
+                    candidate: 1,
+                    commission: Perbill::from_percent(10),
+                    execute_era: 3,
+                }
+            ));
+        });
+}
+
+#[test]
+fn set_commission_schedules_request_without_changing_commission() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            let state = ParachainStaking::candidate_info(&1).expect("candidate exists");
+            assert_eq!(state.commission, Perbill::zero());
+            assert_eq!(
+                state.commission_request,
+                Some(CommissionChangeRequest {
+                    commission: Perbill::from_percent(10),
+                    when_executable: 3,
+                })
+            );
+        });
+}
+
+#[test]
+fn set_commission_replaces_pending_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(20)
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_info(&1)
+                    .expect("candidate exists")
+                    .commission_request,
+                Some(CommissionChangeRequest {
+                    commission: Perbill::from_percent(20),
+                    when_executable: 4,
+                })
+            );
+        });
+}
+
+#[test]
+fn cannot_set_commission_if_not_candidate() {
+    ExtBuilder::default().with_balances(vec![(1, 30)]).build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_commission(Origin::signed(1), Perbill::from_percent(10)),
+            Error::<Test>::CandidateDNE
+        );
+    });
+}
+
+#[test]
+fn cannot_set_commission_above_max() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_commission(Origin::signed(1), Perbill::from_percent(51)),
+                Error::<Test>::CommissionAboveMax
+            );
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(50)
+            ));
+        });
+}
+
+#[test]
+fn cannot_set_commission_to_same_value() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_commission(Origin::signed(1), Perbill::zero()),
+                Error::<Test>::NoWritingSameValue
+            );
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_noop!(
+                ParachainStaking::set_commission(Origin::signed(1), Perbill::from_percent(10)),
+                Error::<Test>::NoWritingSameValue
+            );
+        });
+}
+
+#[test]
+fn commission_change_applies_to_snapshot_after_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(50)
+            ));
+            roll_to_era_begin(2);
+            assert_eq!(ParachainStaking::at_stake(2, 1).commission, Perbill::zero());
+            assert_event_not_emitted!(Event::CandidateCommissionSet {
+                candidate: 1,
+                old: Perbill::zero(),
+                new: Perbill::from_percent(50),
+            });
+            roll_to_era_begin(3);
+            assert_event_emitted!(Event::CandidateCommissionSet {
+                candidate: 1,
+                old: Perbill::zero(),
+                new: Perbill::from_percent(50),
+            });
+            assert_eq!(ParachainStaking::at_stake(3, 1).commission, Perbill::from_percent(50));
+            let state = ParachainStaking::candidate_info(&1).expect("candidate exists");
+            assert_eq!(state.commission, Perbill::from_percent(50));
+            assert!(state.commission_request.is_none());
+        });
+}
+
+#[test]
+fn commission_is_paid_to_collator_before_pro_rata_split() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(50)
+            ));
+            roll_to_era_begin(3);
+            set_author(3, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(5);
+            // commission = 50% of 30 = 15, the remaining 15 is shared 20:10
+            assert_eq_last_events!(
+                vec![
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 25,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 5,
+                        destination: RewardDestination::Bonded
+                    },
+                ],
+                "commission was not taken before the pro-rata split"
+            );
+        });
+}
+
+#[test]
+fn commission_is_taken_from_reward_shared_with_nominators_pending_revoke() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                Origin::signed(1),
+                Perbill::from_percent(50)
+            ));
+            roll_to_era_begin(2);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to_era_begin(3);
+            set_author(3, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(5);
+            // the nominator pending revoke is not rewarded so the collator gets everything
+            assert_eq_last_events!(
+                vec![Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 30,
+                    destination: RewardDestination::Bonded
+                }],
+                "nominator pending revoke was rewarded unexpectedly"
+            );
+        });
+}
+
+// NOMINATE
+
+#[test]
+fn nominate_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Nomination {
+                nominator: 2,
+                locked_amount: 10,
+                candidate: 1,
+                nominator_position: NominatorAdded::AddedToTop { new_total: 40 },
+            }));
+        });
+}
+
+#[test]
+fn nominate_reserves_balance() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
+        });
+}
+
+#[test]
+fn nominate_updates_nominator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert!(ParachainStaking::nominator_state(2).is_none());
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
+            let nominator_state =
+                ParachainStaking::nominator_state(2).expect("just nominated => exists");
+            assert_eq!(nominator_state.total(), 10);
+            assert_eq!(nominator_state.nominations.0[0].owner, 1);
+            assert_eq!(nominator_state.nominations.0[0].amount, 10);
+        });
+}
+
+#[test]
+fn nominate_updates_collator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("registered in genesis");
+            assert_eq!(candidate_state.total_counted, 30);
+            let top_nominations =
+                ParachainStaking::top_nominations(1).expect("registered in genesis");
+            assert!(top_nominations.nominations.is_empty());
+            assert!(top_nominations.total.is_zero());
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0));
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("just nominated => exists");
+            assert_eq!(candidate_state.total_counted, 40);
+            let top_nominations =
+                ParachainStaking::top_nominations(1).expect("just nominated => exists");
+            assert_eq!(top_nominations.nominations[0].owner, 2);
+            assert_eq!(top_nominations.nominations[0].amount, 10);
+            assert_eq!(top_nominations.total, 10);
+        });
+}
+
+#[test]
+fn can_nominate_immediately_after_other_join_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 20, 0));
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 1, 20, 0, 0));
+        });
+}
+
+#[test]
+fn can_nominate_if_revoking() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 30), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 4, 10, 0, 2));
+        });
+}
+
+#[test]
+fn cannot_nominate_if_full_and_new_nomination_less_than_or_equal_lowest_bottom() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 10),
+            (3, 10),
+            (4, 10),
+            (5, 10),
+            (6, 10),
+            (7, 10),
+            (8, 10),
+            (9, 10),
+            (10, 10),
+            (11, 10),
+        ])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![
+            (2, 1, 10),
+            (3, 1, 10),
+            (4, 1, 10),
+            (5, 1, 10),
+            (6, 1, 10),
+            (8, 1, 10),
+            (9, 1, 10),
+            (10, 1, 10),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(11), 1, 10, 8, 0),
+                Error::<Test>::CannotNominateLessThanOrEqualToLowestBottomWhenFull
+            );
+        });
+}
+
+#[test]
+fn can_nominate_if_full_and_new_nomination_greater_than_lowest_bottom() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 10),
+            (3, 10),
+            (4, 10),
+            (5, 10),
+            (6, 10),
+            (7, 10),
+            (8, 10),
+            (9, 10),
+            (10, 10),
+            (11, 11),
+        ])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![
+            (2, 1, 10),
+            (3, 1, 10),
+            (4, 1, 10),
+            (5, 1, 10),
+            (6, 1, 10),
+            (8, 1, 10),
+            (9, 1, 10),
+            (10, 1, 10),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate(Origin::signed(11), 1, 11, 8, 0));
+            assert_event_emitted!(Event::NominationKicked {
+                nominator: 10,
+                candidate: 1,
+                unstaked_amount: 10
+            });
+            assert_event_emitted!(Event::NominatorLeft { nominator: 10, unstaked_amount: 10 });
+        });
+}
+
+#[test]
+fn can_still_nominate_if_leaving() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 20), (3, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_ok!(ParachainStaking::nominate(Origin::signed(2), 3, 10, 0, 1),);
+        });
+}
+
+#[test]
+fn cannot_nominate_if_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 30)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(2), 1, 10, 0, 0),
+                Error::<Test>::CandidateExists
+            );
+        });
+}
+
+#[test]
+fn cannot_nominate_if_already_nominated() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 30)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(2), 1, 10, 1, 1),
+                Error::<Test>::AlreadyNominatedCandidate
+            );
+        });
+}
+
+#[test]
+fn cannot_nominate_more_than_max_nominations() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 50), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10), (2, 5, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominate(Origin::signed(2), 6, 10, 0, 4),
+                Error::<Test>::ExceedMaxNominationsPerNominator,
+            );
+        });
+}
+
+#[test]
+fn sufficient_nominate_weight_hint_succeeds() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (7, 20),
+            (8, 20),
+            (9, 20),
+            (10, 20),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .build()
+        .execute_with(|| {
+            let mut count = 4u32;
+            for i in 7..11 {
+                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32));
+                count += 1u32;
+            }
+            let mut count = 0u32;
+            for i in 3..11 {
+                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 2, 10, count, 1u32));
+                count += 1u32;
+            }
+        });
+}
+
+#[test]
+fn insufficient_nominate_weight_hint_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (7, 20),
+            (8, 20),
+            (9, 20),
+            (10, 20),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .build()
+        .execute_with(|| {
+            let mut count = 3u32;
+            for i in 7..11 {
+                assert_noop!(
+                    ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32),
+                    Error::<Test>::TooLowCandidateNominationCountToNominate
+                );
+            }
+            // to set up for next error test
+            count = 4u32;
+            for i in 7..11 {
+                assert_ok!(ParachainStaking::nominate(Origin::signed(i), 1, 10, count, 0u32));
+                count += 1u32;
+            }
+            count = 0u32;
+            for i in 3..11 {
+                assert_noop!(
+                    ParachainStaking::nominate(Origin::signed(i), 2, 10, count, 0u32),
+                    Error::<Test>::TooLowNominationCountToNominate
+                );
+                count += 1u32;
+            }
+        });
+}
+
+// NOMINATE WITH AUTO COMPOUND
+
+#[test]
+fn nominate_with_auto_compound_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_with_auto_compound(
+                Origin::signed(2),
+                1,
+                10,
+                Percent::from_percent(50),
+                0,
+                0,
+                0
+            ));
+            assert_eq_last_events!(vec![
+                Event::Nomination {
+                    nominator: 2,
+                    locked_amount: 10,
+                    candidate: 1,
+                    nominator_position: NominatorAdded::AddedToTop { new_total: 40 },
+                },
+                Event::AutoCompoundSet {
+                    candidate: 1,
+                    nominator: 2,
+                    value: Percent::from_percent(50),
+                },
+            ]);
+        });
+}
+
+#[test]
+fn nominate_with_auto_compound_sets_auto_compound_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_with_auto_compound(
+                Origin::signed(2),
+                1,
+                10,
+                Percent::from_percent(50),
+                0,
+                0,
+                0
+            ));
+            assert!(ParachainStaking::is_nominator(&2));
+            assert_eq!(
+                vec![AutoCompoundConfig { nominator: 2, value: Percent::from_percent(50) }],
+                ParachainStaking::auto_compounding_nominations(&1),
+            );
+        });
+}
+
+#[test]
+fn nominate_with_auto_compound_zero_does_not_set_auto_compound_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::nominate_with_auto_compound(
+                Origin::signed(2),
+                1,
+                10,
+                Percent::zero(),
+                0,
+                0,
+                0
+            ));
+            assert!(ParachainStaking::is_nominator(&2));
+            assert!(ParachainStaking::auto_compounding_nominations(&1).is_empty());
+        });
+}
+
+#[test]
+fn cannot_nominate_with_auto_compound_with_low_auto_compounding_count_hint() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_noop!(
+                ParachainStaking::nominate_with_auto_compound(
+                    Origin::signed(3),
+                    1,
+                    10,
+                    Percent::from_percent(50),
+                    1,
+                    0,
+                    0
+                ),
+                Error::<Test>::TooLowCandidateAutoCompoundingNominationCountToNominate
+            );
+        });
+}
+
+// SET AUTO COMPOUND
+
+#[test]
+fn set_auto_compound_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet {
+                candidate: 1,
+                nominator: 2,
+                value: Percent::from_percent(50),
+            }));
+        });
+}
+
+#[test]
+fn set_auto_compound_inserts_updates_and_removes_config() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(3),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(10),
+                1,
+                1
+            ));
+            assert_eq!(
+                vec![
+                    AutoCompoundConfig { nominator: 2, value: Percent::from_percent(10) },
+                    AutoCompoundConfig { nominator: 3, value: Percent::from_percent(50) },
+                ],
+                ParachainStaking::auto_compounding_nominations(&1),
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(20),
+                2,
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::nomination_auto_compound(&1, &2),
+                Percent::from_percent(20)
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(3),
+                1,
+                Percent::zero(),
+                2,
+                1
+            ));
+            assert_eq!(
+                vec![AutoCompoundConfig { nominator: 2, value: Percent::from_percent(20) }],
+                ParachainStaking::auto_compounding_nominations(&1),
+            );
+        });
+}
+
+#[test]
+fn cannot_set_auto_compound_if_not_nominator() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    0
+                ),
+                Error::<Test>::NominatorDNE
+            );
+        });
+}
+
+#[test]
+fn cannot_set_auto_compound_if_nomination_dne() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    3,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::NominationDNE
+            );
+        });
+}
+
+#[test]
+fn cannot_set_auto_compound_with_low_count_hints() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    0
+                ),
+                Error::<Test>::TooLowNominationCountToAutoCompound
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(3),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    1,
+                    Percent::from_percent(50),
+                    0,
+                    1
+                ),
+                Error::<Test>::TooLowCandidateAutoCompoundingNominationCountToAutoCompound
+            );
+        });
+}
+
+#[test]
+fn auto_compound_config_is_removed_when_nomination_is_revoked() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                2
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::auto_compounding_nominations(&1).is_empty());
+        });
+}
+
+#[test]
+fn auto_compound_config_is_removed_when_candidate_leaves() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
+            assert!(!<AutoCompoundingNominations<Test>>::contains_key(&1));
+        });
+}
+
+// SCHEDULE LEAVE NOMINATORS
+
+#[test]
+fn schedule_leave_nominators_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominatorExitScheduled {
+                era: 1,
+                nominator: 2,
+                scheduled_exit: 3
+            }));
+        });
+}
+
+#[test]
+fn cannot_schedule_leave_nominators_if_already_leaving() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_noop!(
+                ParachainStaking::schedule_leave_nominators(Origin::signed(2)),
+                Error::<Test>::NominatorAlreadyLeaving
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_leave_nominators_if_not_nominator() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_leave_nominators(Origin::signed(2)),
+                Error::<Test>::NominatorDNE
+            );
+        });
+}
+
+// EXECUTE LEAVE NOMINATORS
+
+#[test]
+fn execute_leave_nominators_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
+        });
+}
+
+#[test]
+fn execute_leave_nominators_unreserves_balance() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 00);
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
+            assert_eq!(crate::mock::query_lock_amount(2, NOMINATOR_LOCK_ID), None);
+        });
+}
+
+#[test]
+fn execute_leave_nominators_decreases_total_staked() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::total(), 40);
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::total(), 30);
+        });
+}
+
+#[test]
+fn execute_leave_nominators_removes_nominator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert!(ParachainStaking::nominator_state(2).is_some());
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::nominator_state(2).is_none());
+        });
+}
+
+#[test]
+fn execute_leave_nominators_removes_pending_nomination_requests() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 10), (2, 15)])
+        .with_candidates(vec![(1, 10)])
+        .with_nominations(vec![(2, 1, 15)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            let state = ParachainStaking::nomination_scheduled_requests(&1);
+            assert_eq!(
+                state,
+                vec![ScheduledRequest {
+                    nominator: 2,
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                }],
+            );
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::nominator_state(2).is_none());
+            assert!(
+                !ParachainStaking::nomination_scheduled_requests(&1)
+                    .iter()
+                    .any(|x| x.nominator == 2),
+                "nomination request not removed"
+            )
+        });
+}
+
+#[test]
+fn execute_leave_nominators_removes_nominations_from_collator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_nominations(vec![(1, 2, 10), (1, 3, 10), (1, 4, 10), (1, 5, 10)])
+        .build()
+        .execute_with(|| {
+            for i in 2..6 {
+                let candidate_state =
+                    ParachainStaking::candidate_info(i).expect("initialized in ext builder");
+                assert_eq!(candidate_state.total_counted, 30);
+                let top_nominations =
+                    ParachainStaking::top_nominations(i).expect("initialized in ext builder");
+                assert_eq!(top_nominations.nominations[0].owner, 1);
+                assert_eq!(top_nominations.nominations[0].amount, 10);
+                assert_eq!(top_nominations.total, 10);
+            }
+            assert_eq!(ParachainStaking::nominator_state(1).unwrap().nominations.0.len(), 4usize);
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(1)));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(1), 1, 10));
+            for i in 2..6 {
+                let candidate_state =
+                    ParachainStaking::candidate_info(i).expect("initialized in ext builder");
+                assert_eq!(candidate_state.total_counted, 20);
+                let top_nominations =
+                    ParachainStaking::top_nominations(i).expect("initialized in ext builder");
+                assert!(top_nominations.nominations.is_empty());
+            }
+        });
+}
+
+#[test]
+fn cannot_execute_leave_nominators_before_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_noop!(
+                ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1),
+                Error::<Test>::NominatorCannotLeaveYet
+            );
+            // can execute after delay
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+        });
+}
+
+#[test]
+fn cannot_execute_leave_nominators_if_single_nomination_revoke_manually_cancelled() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 3));
+            roll_to(10);
+            assert_noop!(
+                ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2),
+                Error::<Test>::NominatorNotLeaving
+            );
+            // can execute after manually scheduling revoke, and the era delay after which
+            // all revokes can be executed
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
+            roll_to(20);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2));
+        });
+}
+
+#[test]
+fn insufficient_execute_leave_nominators_weight_hint_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .build()
+        .execute_with(|| {
+            for i in 3..7 {
+                assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(i)));
+            }
+            roll_to(10);
+            for i in 3..7 {
+                assert_noop!(
+                    ParachainStaking::execute_leave_nominators(Origin::signed(i), i, 0),
+                    Error::<Test>::TooLowNominationCountToLeaveNominators
+                );
+            }
+        });
+}
+
+#[test]
+fn sufficient_execute_leave_nominators_weight_hint_succeeds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .build()
+        .execute_with(|| {
+            for i in 3..7 {
+                assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(i)));
             }
-            count = 0u32;
-            for i in 3..11 {
-                assert_noop!(
-                    ParachainStaking::nominate(Origin::signed(i), 2, 10, count, 0u32),
-                    Error::<Test>::TooLowNominationCountToNominate
-                );
-                count += 1u32;
+            roll_to(10);
+            for i in 3..7 {
+                assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(i), i, 1));
             }
         });
 }
 
-// SCHEDULE LEAVE NOMINATORS
+// CANCEL LEAVE NOMINATORS
 
 #[test]
-fn schedule_leave_nominators_event_emits_correctly() {
+fn cancel_leave_nominators_emits_correct_event() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
@@ -1434,16 +2891,15 @@ fn schedule_leave_nominators_event_emits_correctly() {
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominatorExitScheduled {
-                era: 1,
-                nominator: 2,
-                scheduled_exit: 3
+            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominatorExitCancelled {
+                nominator: 2
             }));
         });
 }
 
 #[test]
-fn cannot_schedule_leave_nominators_if_already_leaving() {
+fn cancel_leave_nominators_updates_nominator_state() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
@@ -1451,364 +2907,401 @@ fn cannot_schedule_leave_nominators_if_already_leaving() {
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
+            let nominator =
+                ParachainStaking::nominator_state(&2).expect("just cancelled exit so exists");
+            assert!(nominator.is_active());
+        });
+}
+
+#[test]
+fn cannot_cancel_leave_nominators_if_single_nomination_revoke_manually_cancelled() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 3));
+            roll_to(10);
             assert_noop!(
-                ParachainStaking::schedule_leave_nominators(Origin::signed(2)),
-                Error::<Test>::NominatorAlreadyLeaving
+                ParachainStaking::cancel_leave_nominators(Origin::signed(2)),
+                Error::<Test>::NominatorNotLeaving
             );
+            // can execute after manually scheduling revoke, without waiting for era delay after
+            // which all revokes can be executed
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
+            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
         });
 }
 
+// SCHEDULE REVOKE NOMINATION
+
 #[test]
-fn cannot_schedule_leave_nominators_if_not_nominator() {
+fn revoke_nomination_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationRevocationScheduled {
+                era: 1,
+                nominator: 2,
+                candidate: 1,
+                scheduled_exit: 3,
+            }));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominatorLeftCandidate {
+                nominator: 2,
+                candidate: 1,
+                unstaked_amount: 10,
+                total_candidate_staked: 30
+            });
+        });
+}
+
+#[test]
+fn can_revoke_nomination_if_revoking_another_nomination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            // this is an exit implicitly because last nomination revoked
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
+        });
+}
+
+#[test]
+fn nominator_not_allowed_revoke_if_already_leaving() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
             assert_noop!(
-                ParachainStaking::schedule_leave_nominators(Origin::signed(2)),
-                Error::<Test>::NominatorDNE
+                ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3),
+                <Error<Test>>::PendingNominationRequestAlreadyExists,
             );
         });
 }
 
-// EXECUTE LEAVE NOMINATORS
+#[test]
+fn cannot_revoke_nomination_if_not_nominator() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1),
+            Error::<Test>::NominatorDNE
+        );
+    });
+}
 
 #[test]
-fn execute_leave_nominators_event_emits_correctly() {
+fn cannot_revoke_nomination_that_dne() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
-            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
+            assert_noop!(
+                ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3),
+                Error::<Test>::NominationDNE
+            );
         });
 }
 
 #[test]
-fn execute_leave_nominators_unreserves_balance() {
+// See `cannot_execute_revoke_nomination_below_min_nominator_stake` for where the "must be above
+// MinNominatorStk" rule is now enforced.
+fn can_schedule_revoke_nomination_below_min_nominator_stake() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 20), (2, 8), (3, 20)])
+        .with_candidates(vec![(1, 20), (3, 20)])
+        .with_nominations(vec![(2, 1, 5), (2, 3, 3)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+        });
+}
+
+// NOMINATOR BOND MORE
+
+#[test]
+fn nominator_bond_more_reserves_balance() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 00);
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
-            assert_eq!(crate::mock::query_lock_amount(2, NOMINATOR_LOCK_ID), None);
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 5);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
         });
 }
 
 #[test]
-fn execute_leave_nominators_decreases_total_staked() {
+fn nominator_bond_more_increases_total_staked() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
             assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::total(), 30);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(ParachainStaking::total(), 45);
         });
 }
 
 #[test]
-fn execute_leave_nominators_removes_nominator_state() {
+fn nominator_bond_more_updates_nominator_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert!(ParachainStaking::nominator_state(2).is_some());
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
-            assert!(ParachainStaking::nominator_state(2).is_none());
+            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 10);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 15);
         });
 }
 
 #[test]
-fn execute_leave_nominators_removes_pending_nomination_requests() {
+fn nominator_bond_more_updates_candidate_state_top_nominations() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 10), (2, 15)])
-        .with_candidates(vec![(1, 10)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            let state = ParachainStaking::nomination_scheduled_requests(&1);
-            assert_eq!(
-                state,
-                vec![ScheduledRequest {
-                    nominator: 2,
-                    when_executable: 3,
-                    action: NominationAction::Decrease(5),
-                }],
-            );
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
-            assert!(ParachainStaking::nominator_state(2).is_none());
-            assert!(
-                !ParachainStaking::nomination_scheduled_requests(&1)
-                    .iter()
-                    .any(|x| x.nominator == 2),
-                "nomination request not removed"
-            )
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 10);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().total, 10);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 15);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().total, 15);
         });
 }
 
 #[test]
-fn execute_leave_nominators_removes_nominations_from_collator_state() {
+fn nominator_bond_more_updates_candidate_state_bottom_nominations() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 100), (2, 20), (3, 20), (4, 20), (5, 20)])
-        .with_candidates(vec![(2, 20), (3, 20), (4, 20), (5, 20)])
-        .with_nominations(vec![(1, 2, 10), (1, 3, 10), (1, 4, 10), (1, 5, 10)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 20), (4, 1, 20), (5, 1, 20), (6, 1, 20)])
         .build()
         .execute_with(|| {
-            for i in 2..6 {
-                let candidate_state =
-                    ParachainStaking::candidate_info(i).expect("initialized in ext builder");
-                assert_eq!(candidate_state.total_counted, 30);
-                let top_nominations =
-                    ParachainStaking::top_nominations(i).expect("initialized in ext builder");
-                assert_eq!(top_nominations.nominations[0].owner, 1);
-                assert_eq!(top_nominations.nominations[0].amount, 10);
-                assert_eq!(top_nominations.total, 10);
-            }
-            assert_eq!(ParachainStaking::nominator_state(1).unwrap().nominations.0.len(), 4usize);
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(1)));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(1), 1, 10));
-            for i in 2..6 {
-                let candidate_state =
-                    ParachainStaking::candidate_info(i).expect("initialized in ext builder");
-                assert_eq!(candidate_state.total_counted, 20);
-                let top_nominations =
-                    ParachainStaking::top_nominations(i).expect("initialized in ext builder");
-                assert!(top_nominations.nominations.is_empty());
-            }
+            assert_eq!(
+                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].owner,
+                2
+            );
+            assert_eq!(
+                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].amount,
+                10
+            );
+            assert_eq!(ParachainStaking::bottom_nominations(1).unwrap().total, 10);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationIncreased {
+                nominator: 2,
+                candidate: 1,
+                amount: 5,
+                in_top: false
+            }));
+            assert_eq!(
+                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].owner,
+                2
+            );
+            assert_eq!(
+                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].amount,
+                15
+            );
+            assert_eq!(ParachainStaking::bottom_nominations(1).unwrap().total, 15);
         });
 }
 
 #[test]
-fn cannot_execute_leave_nominators_before_delay() {
+fn nominator_bond_more_increases_total() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_noop!(
-                ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1),
-                Error::<Test>::NominatorCannotLeaveYet
-            );
-            // can execute after delay
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::total(), 40);
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_eq!(ParachainStaking::total(), 45);
         });
 }
 
 #[test]
-fn cannot_execute_leave_nominators_if_single_nomination_revoke_manually_cancelled() {
+fn can_nominator_bond_more_for_leaving_candidate() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 3));
-            roll_to(10);
-            assert_noop!(
-                ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2),
-                Error::<Test>::NominatorNotLeaving
-            );
-            // can execute after manually scheduling revoke, and the era delay after which
-            // all revokes can be executed
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
-            roll_to(20);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
         });
 }
 
 #[test]
-fn insufficient_execute_leave_nominators_weight_hint_fails() {
+fn nominator_bond_more_disallowed_when_revoke_scheduled() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 25)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            for i in 3..7 {
-                assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(i)));
-            }
-            roll_to(10);
-            for i in 3..7 {
-                assert_noop!(
-                    ParachainStaking::execute_leave_nominators(Origin::signed(i), i, 0),
-                    Error::<Test>::TooLowNominationCountToLeaveNominators
-                );
-            }
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_noop!(
+                ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5),
+                <Error<Test>>::PendingNominationRevoke
+            );
         });
 }
 
 #[test]
-fn sufficient_execute_leave_nominators_weight_hint_succeeds() {
+fn nominator_bond_more_allowed_when_bond_decrease_scheduled() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 25)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 15)])
         .build()
         .execute_with(|| {
-            for i in 3..7 {
-                assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(i)));
-            }
-            roll_to(10);
-            for i in 3..7 {
-                assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(i), i, 1));
-            }
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5,));
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
         });
 }
 
-// CANCEL LEAVE NOMINATORS
+// NOMINATOR BOND LESS
 
 #[test]
-fn cancel_leave_nominators_emits_correct_event() {
+fn nominator_bond_less_event_emits_correctly() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominatorExitCancelled {
-                nominator: 2
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationDecreaseScheduled {
+                nominator: 2,
+                candidate: 1,
+                amount_to_decrease: 5,
+                execute_era: 3,
             }));
         });
 }
 
 #[test]
-fn cancel_leave_nominators_updates_nominator_state() {
+fn nominator_bond_less_updates_nominator_state() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
-            let nominator =
-                ParachainStaking::nominator_state(&2).expect("just cancelled exit so exists");
-            assert!(nominator.is_active());
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            let state = ParachainStaking::nomination_scheduled_requests(&1);
+            assert_eq!(
+                state,
+                vec![ScheduledRequest {
+                    nominator: 2,
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                }],
+            );
         });
 }
 
 #[test]
-fn cannot_cancel_leave_nominators_if_single_nomination_revoke_manually_cancelled() {
+fn nominator_not_allowed_bond_less_if_leaving() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 3));
-            roll_to(10);
             assert_noop!(
-                ParachainStaking::cancel_leave_nominators(Origin::signed(2)),
-                Error::<Test>::NominatorNotLeaving
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 1),
+                <Error<Test>>::PendingNominationRequestAlreadyExists,
             );
-            // can execute after manually scheduling revoke, without waiting for era delay after
-            // which all revokes can be executed
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
-            assert_ok!(ParachainStaking::cancel_leave_nominators(Origin::signed(2)));
         });
 }
 
-// SCHEDULE REVOKE NOMINATION
-
 #[test]
-fn revoke_nomination_event_emits_correctly() {
+fn cannot_nominator_bond_less_if_revoking() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_balances(vec![(1, 30), (2, 25), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
         .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationRevocationScheduled {
-                era: 1,
-                nominator: 2,
-                candidate: 1,
-                scheduled_exit: 3,
-            }));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_event_emitted!(Event::NominatorLeftCandidate {
-                nominator: 2,
-                candidate: 1,
-                unstaked_amount: 10,
-                total_candidate_staked: 30
-            });
+            assert_noop!(
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 1),
+                Error::<Test>::PendingNominationRequestAlreadyExists
+            );
         });
 }
 
 #[test]
-fn can_revoke_nomination_if_revoking_another_nomination() {
+fn cannot_nominator_bond_less_if_not_nominator() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5),
+            Error::<Test>::NominatorDNE
+        );
+    });
+}
+
+#[test]
+fn cannot_nominator_bond_less_if_candidate_dne() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            // this is an exit implicitly because last nomination revoked
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3));
+            assert_noop!(
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 5),
+                Error::<Test>::NominationDNE
+            );
         });
 }
 
 #[test]
-fn nominator_not_allowed_revoke_if_already_leaving() {
+fn cannot_nominator_bond_less_if_nomination_dne() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 10), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
             assert_noop!(
-                ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3),
-                <Error<Test>>::PendingNominationRequestAlreadyExists,
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 5),
+                Error::<Test>::NominationDNE
             );
         });
 }
 
 #[test]
-fn cannot_revoke_nomination_if_not_nominator() {
-    ExtBuilder::default().build().execute_with(|| {
-        assert_noop!(
-            ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1),
-            Error::<Test>::NominatorDNE
-        );
-    });
-}
-
-#[test]
-fn cannot_revoke_nomination_that_dne() {
+fn cannot_nominator_bond_less_below_min_collator_stk() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
@@ -1816,971 +3309,1189 @@ fn cannot_revoke_nomination_that_dne() {
         .build()
         .execute_with(|| {
             assert_noop!(
-                ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 3),
-                Error::<Test>::NominationDNE
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 6),
+                Error::<Test>::NominatorBondBelowMin
             );
         });
 }
 
 #[test]
-// See `cannot_execute_revoke_nomination_below_min_nominator_stake` for where the "must be above
-// MinNominatorStk" rule is now enforced.
-fn can_schedule_revoke_nomination_below_min_nominator_stake() {
+fn cannot_nominator_bond_less_more_than_total_nomination() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 8), (3, 20)])
-        .with_candidates(vec![(1, 20), (3, 20)])
-        .with_nominations(vec![(2, 1, 5), (2, 3, 3)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_noop!(
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 11),
+                Error::<Test>::NominatorBondBelowMin
+            );
         });
 }
 
-// NOMINATOR BOND MORE
-
 #[test]
-fn nominator_bond_more_reserves_balance() {
+fn cannot_nominator_bond_less_below_min_nomination() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 5);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
+            assert_noop!(
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 8),
+                Error::<Test>::NominationBelowMin
+            );
         });
 }
 
+// EXECUTE PENDING NOMINATION REQUEST
+
+// 1. REVOKE NOMINATION
+
 #[test]
-fn nominator_bond_more_increases_total_staked() {
+fn execute_revoke_nomination_emits_exit_event_if_exit_happens() {
+    // last nomination is revocation
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
+        .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_eq!(ParachainStaking::total(), 45);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominatorLeftCandidate {
+                nominator: 2,
+                candidate: 1,
+                unstaked_amount: 10,
+                total_candidate_staked: 30
+            });
+            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
         });
 }
 
 #[test]
-fn nominator_bond_more_updates_nominator_state() {
+fn cannot_execute_revoke_nomination_below_min_nominator_stake() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 20), (2, 8), (3, 20)])
+        .with_candidates(vec![(1, 20), (3, 20)])
+        .with_nominations(vec![(2, 1, 5), (2, 3, 3)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 10);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 15);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_noop!(
+                ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1),
+                Error::<Test>::NominatorBondBelowMin
+            );
+            // but nominator can cancel the request and request to leave instead:
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
+            roll_to(20);
+            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2));
         });
 }
 
 #[test]
-fn nominator_bond_more_updates_candidate_state_top_nominations() {
+fn revoke_nomination_executes_exit_if_last_nomination() {
+    // last nomination is revocation
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
+        .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 10);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().total, 10);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 15);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().total, 15);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominatorLeftCandidate {
+                nominator: 2,
+                candidate: 1,
+                unstaked_amount: 10,
+                total_candidate_staked: 30
+            });
+            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
         });
 }
 
 #[test]
-fn nominator_bond_more_updates_candidate_state_bottom_nominations() {
+fn execute_revoke_nomination_emits_correct_event() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10), (3, 1, 20), (4, 1, 20), (5, 1, 20), (6, 1, 20)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(
-                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].owner,
-                2
-            );
-            assert_eq!(
-                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].amount,
-                10
-            );
-            assert_eq!(ParachainStaking::bottom_nominations(1).unwrap().total, 10);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationIncreased {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_event_emitted!(Event::NominatorLeftCandidate {
                 nominator: 2,
                 candidate: 1,
-                amount: 5,
-                in_top: false
-            }));
-            assert_eq!(
-                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].owner,
-                2
-            );
-            assert_eq!(
-                ParachainStaking::bottom_nominations(1).expect("exists").nominations[0].amount,
-                15
-            );
-            assert_eq!(ParachainStaking::bottom_nominations(1).unwrap().total, 15);
+                unstaked_amount: 10,
+                total_candidate_staked: 30
+            });
         });
 }
 
 #[test]
-fn nominator_bond_more_increases_total() {
+fn execute_revoke_nomination_unreserves_balance() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
+        .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
-            assert_eq!(ParachainStaking::total(), 45);
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
         });
 }
 
 #[test]
-fn can_nominator_bond_more_for_leaving_candidate() {
+fn execute_revoke_nomination_adds_revocation_to_nominator_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
+                .iter()
+                .any(|x| x.nominator == 2));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert!(ParachainStaking::nomination_scheduled_requests(&1)
+                .iter()
+                .any(|x| x.nominator == 2));
         });
 }
 
 #[test]
-fn nominator_bond_more_disallowed_when_revoke_scheduled() {
+fn execute_revoke_nomination_removes_revocation_from_nominator_state_upon_execution() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 25)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_noop!(
-                ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5),
-                <Error<Test>>::PendingNominationRevoke
-            );
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
+                .iter()
+                .any(|x| x.nominator == 2));
         });
 }
 
 #[test]
-fn nominator_bond_more_allowed_when_bond_decrease_scheduled() {
+fn execute_revoke_nomination_removes_revocation_from_state_for_single_nomination_leave() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 25)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5,));
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 1, 5));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(
+                !ParachainStaking::nomination_scheduled_requests(&1)
+                    .iter()
+                    .any(|x| x.nominator == 2),
+                "nomination was not removed"
+            );
         });
 }
 
-// NOMINATOR BOND LESS
-
 #[test]
-fn nominator_bond_less_event_emits_correctly() {
+fn execute_revoke_nomination_decreases_total_staked() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationDecreaseScheduled {
-                nominator: 2,
-                candidate: 1,
-                amount_to_decrease: 5,
-                execute_era: 3,
-            }));
+            assert_eq!(ParachainStaking::total(), 40);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::total(), 30);
         });
 }
 
 #[test]
-fn nominator_bond_less_updates_nominator_state() {
+fn execute_revoke_nomination_for_last_nomination_removes_nominator_state() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            let state = ParachainStaking::nomination_scheduled_requests(&1);
-            assert_eq!(
-                state,
-                vec![ScheduledRequest {
-                    nominator: 2,
-                    when_executable: 3,
-                    action: NominationAction::Decrease(5),
-                }],
-            );
+            assert!(ParachainStaking::nominator_state(2).is_some());
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            // this will be confusing for people
+            // if status is leaving, then execute_nomination_request works if last nomination
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::nominator_state(2).is_none());
         });
 }
 
 #[test]
-fn nominator_not_allowed_bond_less_if_leaving() {
+fn execute_revoke_nomination_removes_nomination_from_candidate_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
+        .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 1),
-                <Error<Test>>::PendingNominationRequestAlreadyExists,
-            );
+            assert_eq!(ParachainStaking::candidate_info(1).expect("exists").nomination_count, 1u32);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::candidate_info(1)
+                .expect("exists")
+                .nomination_count
+                .is_zero());
         });
 }
 
 #[test]
-fn cannot_nominator_bond_less_if_revoking() {
+fn can_execute_revoke_nomination_for_leaving_candidate() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 25), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
             assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 1),
-                Error::<Test>::PendingNominationRequestAlreadyExists
-            );
+            roll_to(10);
+            // can execute nomination request for leaving candidate
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
         });
 }
 
 #[test]
-fn cannot_nominator_bond_less_if_not_nominator() {
-    ExtBuilder::default().build().execute_with(|| {
-        assert_noop!(
-            ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5),
-            Error::<Test>::NominatorDNE
-        );
-    });
+fn can_execute_leave_candidates_if_revoking_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            roll_to(10);
+            // revocation executes during execute leave candidates (callable by anyone)
+            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
+            assert!(!ParachainStaking::is_nominator(&2));
+            assert_eq!(Balances::reserved_balance(&2), 0);
+            assert_eq!(Balances::free_balance(&2), 10);
+        });
 }
 
 #[test]
-fn cannot_nominator_bond_less_if_candidate_dne() {
+fn nominator_bond_more_after_revoke_nomination_does_not_effect_exit() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 30), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 5),
-                Error::<Test>::NominationDNE
-            );
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 3, 10));
+            roll_to(100);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert!(ParachainStaking::is_nominator(&2));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
         });
 }
 
 #[test]
-fn cannot_nominator_bond_less_if_nomination_dne() {
+fn nominator_bond_less_after_revoke_nomination_does_not_effect_exit() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10), (3, 30)])
+        .with_balances(vec![(1, 30), (2, 30), (3, 30)])
         .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
         .build()
         .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationRevocationScheduled {
+                era: 1,
+                nominator: 2,
+                candidate: 1,
+                scheduled_exit: 3,
+            }));
             assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 5),
-                Error::<Test>::NominationDNE
+                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 2),
+                Error::<Test>::PendingNominationRequestAlreadyExists
             );
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 2));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 3));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationDecreased {
+                nominator: 2,
+                candidate: 3,
+                amount: 2,
+                in_top: true
+            }));
+            assert!(ParachainStaking::is_nominator(&2));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 22);
         });
 }
 
+// 2. EXECUTE BOND LESS
+
 #[test]
-fn cannot_nominator_bond_less_below_min_collator_stk() {
+fn execute_nominator_bond_less_unreserves_balance() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 6),
-                Error::<Test>::NominatorBondBelowMin
-            );
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 5);
         });
 }
 
 #[test]
-fn cannot_nominator_bond_less_more_than_total_nomination() {
+fn execute_nominator_bond_less_decreases_total_staked() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 11),
-                Error::<Test>::NominatorBondBelowMin
-            );
+            assert_eq!(ParachainStaking::total(), 40);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::total(), 35);
         });
 }
 
 #[test]
-fn cannot_nominator_bond_less_below_min_nomination() {
+fn execute_nominator_bond_less_updates_nominator_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 8),
-                Error::<Test>::NominationBelowMin
-            );
+            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 10);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 5);
         });
 }
 
-// EXECUTE PENDING NOMINATION REQUEST
-
-// 1. REVOKE NOMINATION
-
 #[test]
-fn execute_revoke_nomination_emits_exit_event_if_exit_happens() {
-    // last nomination is revocation
+fn execute_nominator_bond_less_updates_candidate_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 10);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
             roll_to(10);
             assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_event_emitted!(Event::NominatorLeftCandidate {
-                nominator: 2,
-                candidate: 1,
-                unstaked_amount: 10,
-                total_candidate_staked: 30
-            });
-            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
+            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 5);
         });
 }
 
 #[test]
-fn cannot_execute_revoke_nomination_below_min_nominator_stake() {
+fn execute_nominator_bond_less_decreases_total() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 8), (3, 20)])
-        .with_candidates(vec![(1, 20), (3, 20)])
-        .with_nominations(vec![(2, 1, 5), (2, 3, 3)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_eq!(ParachainStaking::total(), 40);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
             roll_to(10);
-            assert_noop!(
-                ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1),
-                Error::<Test>::NominatorBondBelowMin
-            );
-            // but nominator can cancel the request and request to leave instead:
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
-            assert_ok!(ParachainStaking::schedule_leave_nominators(Origin::signed(2)));
-            roll_to(20);
-            assert_ok!(ParachainStaking::execute_leave_nominators(Origin::signed(2), 2, 2));
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_eq!(ParachainStaking::total(), 35);
         });
 }
 
 #[test]
-fn revoke_nomination_executes_exit_if_last_nomination() {
-    // last nomination is revocation
+fn execute_nominator_bond_less_updates_just_bottom_nominations() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 20), (2, 10), (3, 11), (4, 12), (5, 14), (6, 15)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 11), (4, 1, 12), (5, 1, 14), (6, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            let pre_call_candidate_info =
+                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
+            let pre_call_top_nominations =
+                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
+            let pre_call_bottom_nominations =
+                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 2));
             roll_to(10);
             assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_event_emitted!(Event::NominatorLeftCandidate {
-                nominator: 2,
-                candidate: 1,
-                unstaked_amount: 10,
-                total_candidate_staked: 30
-            });
-            assert_event_emitted!(Event::NominatorLeft { nominator: 2, unstaked_amount: 10 });
+            let post_call_candidate_info =
+                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
+            let post_call_top_nominations =
+                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
+            let post_call_bottom_nominations =
+                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
+            let mut not_equal = false;
+            for Bond { owner, amount } in pre_call_bottom_nominations.nominations {
+                for Bond { owner: post_owner, amount: post_amount } in
+                    &post_call_bottom_nominations.nominations
+                {
+                    if &owner == post_owner {
+                        if &amount != post_amount {
+                            not_equal = true;
+                            break
+                        }
+                    }
+                }
+            }
+            assert!(not_equal);
+            let mut equal = true;
+            for Bond { owner, amount } in pre_call_top_nominations.nominations {
+                for Bond { owner: post_owner, amount: post_amount } in
+                    &post_call_top_nominations.nominations
+                {
+                    if &owner == post_owner {
+                        if &amount != post_amount {
+                            equal = false;
+                            break
+                        }
+                    }
+                }
+            }
+            assert!(equal);
+            assert_eq!(
+                pre_call_candidate_info.total_counted,
+                post_call_candidate_info.total_counted
+            );
         });
 }
 
 #[test]
-fn execute_revoke_nomination_emits_correct_event() {
+fn execute_nominator_bond_less_does_not_delete_bottom_nominations() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 20), (2, 10), (3, 11), (4, 12), (5, 14), (6, 15)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10), (3, 1, 11), (4, 1, 12), (5, 1, 14), (6, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            let pre_call_candidate_info =
+                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
+            let pre_call_top_nominations =
+                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
+            let pre_call_bottom_nominations =
+                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(6), 1, 4));
             roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_event_emitted!(Event::NominatorLeftCandidate {
-                nominator: 2,
-                candidate: 1,
-                unstaked_amount: 10,
-                total_candidate_staked: 30
-            });
+            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(6), 6, 1));
+            let post_call_candidate_info =
+                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
+            let post_call_top_nominations =
+                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
+            let post_call_bottom_nominations =
+                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
+            let mut equal = true;
+            for Bond { owner, amount } in pre_call_bottom_nominations.nominations {
+                for Bond { owner: post_owner, amount: post_amount } in
+                    &post_call_bottom_nominations.nominations
+                {
+                    if &owner == post_owner {
+                        if &amount != post_amount {
+                            equal = false;
+                            break
+                        }
+                    }
+                }
+            }
+            assert!(equal);
+            let mut not_equal = false;
+            for Bond { owner, amount } in pre_call_top_nominations.nominations {
+                for Bond { owner: post_owner, amount: post_amount } in
+                    &post_call_top_nominations.nominations
+                {
+                    if &owner == post_owner {
+                        if &amount != post_amount {
+                            not_equal = true;
+                            break
+                        }
+                    }
+                }
+            }
+            assert!(not_equal);
+            assert_eq!(
+                pre_call_candidate_info.total_counted - 4,
+                post_call_candidate_info.total_counted
+            );
         });
 }
 
 #[test]
-fn execute_revoke_nomination_unreserves_balance() {
+fn can_execute_nominator_bond_less_for_leaving_candidate() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_nominations(vec![(2, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
             roll_to(10);
+            // can execute bond more nomination request for leaving candidate
             assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
         });
 }
 
+// CANCEL PENDING NOMINATION REQUEST
+// 1. CANCEL REVOKE NOMINATION
+
 #[test]
-fn execute_revoke_nomination_adds_revocation_to_nominator_state() {
+fn cancel_revoke_nomination_emits_correct_event() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
-                .iter()
-                .any(|x| x.nominator == 2));
             assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert!(ParachainStaking::nomination_scheduled_requests(&1)
-                .iter()
-                .any(|x| x.nominator == 2));
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledNominationRequest {
+                nominator: 2,
+                collator: 1,
+                cancelled_request: CancelledScheduledRequest {
+                    when_executable: 3,
+                    action: NominationAction::Revoke(10),
+                },
+            }));
         });
 }
 
 #[test]
-fn execute_revoke_nomination_removes_revocation_from_nominator_state_upon_execution() {
+fn cancel_revoke_nomination_updates_nominator_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            let state = ParachainStaking::nomination_scheduled_requests(&1);
+            assert_eq!(
+                state,
+                vec![ScheduledRequest {
+                    nominator: 2,
+                    when_executable: 3,
+                    action: NominationAction::Revoke(10),
+                }],
+            );
+            assert_eq!(
+                ParachainStaking::nominator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("nominator state must exist"),
+                10
+            );
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
             assert!(!ParachainStaking::nomination_scheduled_requests(&1)
                 .iter()
                 .any(|x| x.nominator == 2));
+            assert_eq!(
+                ParachainStaking::nominator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("nominator state must exist"),
+                0
+            );
         });
 }
 
+// 2. CANCEL NOMINATOR BOND LESS
+
 #[test]
-fn execute_revoke_nomination_removes_revocation_from_state_for_single_nomination_leave() {
+fn cancel_nominator_bond_less_correct_event() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
-        .with_candidates(vec![(1, 30), (3, 20)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_nominations(vec![(2, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert!(
-                !ParachainStaking::nomination_scheduled_requests(&1)
-                    .iter()
-                    .any(|x| x.nominator == 2),
-                "nomination was not removed"
-            );
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledNominationRequest {
+                nominator: 2,
+                collator: 1,
+                cancelled_request: CancelledScheduledRequest {
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                },
+            }));
         });
 }
 
 #[test]
-fn execute_revoke_nomination_decreases_total_staked() {
+fn cancel_nominator_bond_less_updates_nominator_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30), (2, 15)])
         .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_nominations(vec![(2, 1, 15)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::total(), 30);
+            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
+            let state = ParachainStaking::nomination_scheduled_requests(&1);
+            assert_eq!(
+                state,
+                vec![ScheduledRequest {
+                    nominator: 2,
+                    when_executable: 3,
+                    action: NominationAction::Decrease(5),
+                }],
+            );
+            assert_eq!(
+                ParachainStaking::nominator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("nominator state must exist"),
+                5
+            );
+            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
+            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
+                .iter()
+                .any(|x| x.nominator == 2));
+            assert_eq!(
+                ParachainStaking::nominator_state(&2)
+                    .map(|x| x.less_total)
+                    .expect("nominator state must exist"),
+                0
+            );
         });
 }
 
+// SET PAYEE
+
 #[test]
-fn execute_revoke_nomination_for_last_nomination_removes_nominator_state() {
+fn set_payee_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert!(ParachainStaking::nominator_state(2).is_some());
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            // this will be confusing for people
-            // if status is leaving, then execute_nomination_request works if last nomination
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert!(ParachainStaking::nominator_state(2).is_none());
+            assert_ok!(ParachainStaking::set_payee(
+                Origin::signed(1),
+                RewardDestination::Account(5)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PayeeSet {
+                account: 1,
+                payee: RewardDestination::Account(5),
+            }));
+            assert_eq!(ParachainStaking::payee(1), RewardDestination::Account(5));
         });
 }
 
 #[test]
-fn execute_revoke_nomination_removes_nomination_from_candidate_state() {
+fn set_payee_to_bonded_clears_storage() {
     ExtBuilder::default()
         .with_balances(vec![(1, 30), (2, 10)])
         .with_candidates(vec![(1, 30)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::candidate_info(1).expect("exists").nomination_count, 1u32);
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert!(ParachainStaking::candidate_info(1)
-                .expect("exists")
-                .nomination_count
-                .is_zero());
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(2), RewardDestination::Restake));
+            assert!(<Payee<Test>>::contains_key(2));
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(2), RewardDestination::Bonded));
+            assert!(!<Payee<Test>>::contains_key(2));
+            assert_eq!(ParachainStaking::payee(2), RewardDestination::Bonded);
         });
 }
 
 #[test]
-fn can_execute_revoke_nomination_for_leaving_candidate() {
+fn cannot_set_payee_if_not_staker() {
+    ExtBuilder::default().with_balances(vec![(1, 30)]).build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_payee(Origin::signed(1), RewardDestination::Restake),
+            Error::<Test>::CannotSetPayeeIfNotStaker
+        );
+    });
+}
+
+#[test]
+fn cannot_set_payee_to_same_value() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            // can execute nomination request for leaving candidate
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            assert_noop!(
+                ParachainStaking::set_payee(Origin::signed(1), RewardDestination::Bonded),
+                Error::<Test>::NoWritingSameValue
+            );
         });
 }
 
 #[test]
-fn can_execute_leave_candidates_if_revoking_candidate() {
+fn rewards_are_paid_to_payee_account() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            roll_to(10);
-            // revocation executes during execute leave candidates (callable by anyone)
-            assert_ok!(ParachainStaking::execute_leave_candidates(Origin::signed(1), 1, 1));
-            assert!(!ParachainStaking::is_nominator(&2));
-            assert_eq!(Balances::reserved_balance(&2), 0);
+            assert_ok!(ParachainStaking::set_payee(
+                Origin::signed(1),
+                RewardDestination::Account(5)
+            ));
+            assert_ok!(ParachainStaking::set_payee(
+                Origin::signed(2),
+                RewardDestination::Account(6)
+            ));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 20,
+                    destination: RewardDestination::Account(5),
+                },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 10,
+                    destination: RewardDestination::Account(6),
+                },
+            ]);
+            assert_eq!(Balances::free_balance(&1), 20);
             assert_eq!(Balances::free_balance(&2), 10);
+            assert_eq!(Balances::free_balance(&5), 20);
+            assert_eq!(Balances::free_balance(&6), 10);
         });
 }
 
 #[test]
-fn nominator_bond_more_after_revoke_nomination_does_not_effect_exit() {
+fn rewards_are_restaked_when_payee_is_restake() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 30), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_ok!(ParachainStaking::nominator_bond_more(Origin::signed(2), 3, 10));
-            roll_to(100);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert!(ParachainStaking::is_nominator(&2));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 10);
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(1), RewardDestination::Restake));
+            assert_ok!(ParachainStaking::set_payee(Origin::signed(2), RewardDestination::Restake));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 20,
+                    destination: RewardDestination::Restake,
+                },
+                Event::<Test>::CandidateBondedMore { candidate: 1, amount: 20, new_total_bond: 40 },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 10,
+                    destination: RewardDestination::Restake,
+                },
+                Event::<Test>::NominationIncreased {
+                    nominator: 2,
+                    candidate: 1,
+                    amount: 10,
+                    in_top: true,
+                },
+                Event::<Test>::Compounded { candidate: 1, nominator: 2, amount: 10 },
+            ]);
+            let candidate = ParachainStaking::candidate_info(1).expect("candidate exists");
+            assert_eq!(candidate.bond, 40);
+            assert_eq!(candidate.total_counted, 60);
+            assert_eq!(ParachainStaking::total(), 60);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 60);
         });
 }
 
 #[test]
-fn nominator_bond_less_after_revoke_nomination_does_not_effect_exit() {
+fn auto_compound_does_not_apply_to_rewards_paid_to_payee_account() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 30), (3, 30)])
-        .with_candidates(vec![(1, 30), (3, 30)])
-        .with_nominations(vec![(2, 1, 10), (2, 3, 10)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationRevocationScheduled {
-                era: 1,
-                nominator: 2,
-                candidate: 1,
-                scheduled_exit: 3,
-            }));
-            assert_noop!(
-                ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 2),
-                Error::<Test>::PendingNominationRequestAlreadyExists
-            );
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 3, 2));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 3));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationDecreased {
-                nominator: 2,
-                candidate: 3,
-                amount: 2,
-                in_top: true
-            }));
-            assert!(ParachainStaking::is_nominator(&2));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 22);
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            assert_ok!(ParachainStaking::set_payee(
+                Origin::signed(2),
+                RewardDestination::Account(6)
+            ));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 20,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 10,
+                    destination: RewardDestination::Account(6),
+                },
+            ]);
+            assert_eq!(ParachainStaking::nominator_state(2).expect("nominator exists").total(), 10);
         });
 }
 
-// 2. EXECUTE BOND LESS
+// CLAIM REWARDS
 
 #[test]
-fn execute_nominator_bond_less_unreserves_balance() {
+fn rewards_are_credited_in_claim_mode() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 0);
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::get_nominator_stakable_free_balance(&2), 5);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::RewardCredited { account: 1, candidate: 1, rewards: 20 },
+                Event::<Test>::RewardCredited { account: 2, candidate: 1, rewards: 10 },
+            ]);
+            assert_eq!(Balances::free_balance(&1), 20);
+            assert_eq!(Balances::free_balance(&2), 10);
+            assert_eq!(ParachainStaking::claimable_rewards(1, 1), 20);
+            assert_eq!(ParachainStaking::claimable_rewards(2, 1), 10);
+            // credited rewards stay locked in the reward pot until claimed
+            assert_eq!(ParachainStaking::locked_era_payout(), 30);
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_decreases_total_staked() {
+fn claim_rewards_pays_credited_rewards() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::total(), 35);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(2), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 2,
+                rewards: 10,
+                destination: RewardDestination::Bonded,
+            }));
+            assert_eq!(Balances::free_balance(&2), 20);
+            assert!(ParachainStaking::total_claimable_rewards(&2).is_zero());
+            assert_eq!(ParachainStaking::claimable_rewards(1, 1), 20);
+            assert_eq!(ParachainStaking::locked_era_payout(), 20);
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_updates_nominator_state() {
+fn claimable_rewards_accumulate_across_eras() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 10);
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::nominator_state(2).expect("exists").total(), 5);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_author(1, 1, 1);
+            set_author(2, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            // top up the pot with the reward of era 2, era 1 rewards are still locked in it
+            Balances::make_free_balance_be(&ParachainStaking::compute_reward_pot_account_id(), 60);
+            roll_to_era_begin(4);
+            assert_eq!(ParachainStaking::claimable_rewards(1, 1), 40);
+            assert_eq!(ParachainStaking::claimable_rewards(2, 1), 20);
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(1), 1));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Rewarded {
+                account: 1,
+                rewards: 40,
+                destination: RewardDestination::Bonded,
+            }));
+            assert_eq!(Balances::free_balance(&1), 60);
+            assert_eq!(ParachainStaking::locked_era_payout(), 20);
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_updates_candidate_state() {
+fn claim_rewards_applies_auto_compound() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
         .with_nominations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 10);
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].owner, 2);
-            assert_eq!(ParachainStaking::top_nominations(1).unwrap().nominations[0].amount, 5);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(2), 1));
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 10,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::<Test>::NominationIncreased {
+                    nominator: 2,
+                    candidate: 1,
+                    amount: 5,
+                    in_top: true,
+                },
+                Event::<Test>::Compounded { candidate: 1, nominator: 2, amount: 5 },
+            ]);
+            assert_eq!(ParachainStaking::nominator_state(2).expect("nominator exists").total(), 15);
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_decreases_total() {
+fn claim_rewards_is_limited_to_candidate_count() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 20), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_nominations(vec![(3, 1, 10), (3, 2, 10)])
         .build()
         .execute_with(|| {
-            assert_eq!(ParachainStaking::total(), 40);
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            assert_eq!(ParachainStaking::total(), 35);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_author(1, 1, 1);
+            set_author(1, 2, 1);
+            set_reward_pot(60);
+            roll_to_era_begin(3);
+            roll_one_block();
+            assert_eq!(ParachainStaking::total_claimable_rewards(&3), 20);
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(3), 1));
+            assert_eq!(ParachainStaking::total_claimable_rewards(&3), 10);
+            assert_eq!(Balances::free_balance(&3), 10);
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(3), 1));
+            assert!(ParachainStaking::total_claimable_rewards(&3).is_zero());
+            assert_eq!(Balances::free_balance(&3), 20);
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_updates_just_bottom_nominations() {
+fn cannot_claim_rewards_if_none_credited() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 10), (3, 11), (4, 12), (5, 14), (6, 15)])
+        .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![(2, 1, 10), (3, 1, 11), (4, 1, 12), (5, 1, 14), (6, 1, 15)])
         .build()
         .execute_with(|| {
-            let pre_call_candidate_info =
-                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
-            let pre_call_top_nominations =
-                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
-            let pre_call_bottom_nominations =
-                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 2));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
-            let post_call_candidate_info =
-                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
-            let post_call_top_nominations =
-                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
-            let post_call_bottom_nominations =
-                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
-            let mut not_equal = false;
-            for Bond { owner, amount } in pre_call_bottom_nominations.nominations {
-                for Bond { owner: post_owner, amount: post_amount } in
-                    &post_call_bottom_nominations.nominations
-                {
-                    if &owner == post_owner {
-                        if &amount != post_amount {
-                            not_equal = true;
-                            break
-                        }
-                    }
-                }
-            }
-            assert!(not_equal);
-            let mut equal = true;
-            for Bond { owner, amount } in pre_call_top_nominations.nominations {
-                for Bond { owner: post_owner, amount: post_amount } in
-                    &post_call_top_nominations.nominations
-                {
-                    if &owner == post_owner {
-                        if &amount != post_amount {
-                            equal = false;
-                            break
-                        }
-                    }
-                }
-            }
-            assert!(equal);
-            assert_eq!(
-                pre_call_candidate_info.total_counted,
-                post_call_candidate_info.total_counted
+            assert_noop!(
+                ParachainStaking::claim_rewards(Origin::signed(1), 1),
+                Error::<Test>::NoRewardsToClaim
             );
         });
 }
 
 #[test]
-fn execute_nominator_bond_less_does_not_delete_bottom_nominations() {
+fn credited_rewards_remain_claimable_after_switching_to_push() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 10), (3, 11), (4, 12), (5, 14), (6, 15)])
+        .with_balances(vec![(1, 20)])
         .with_candidates(vec![(1, 20)])
-        .with_nominations(vec![(2, 1, 10), (3, 1, 11), (4, 1, 12), (5, 1, 14), (6, 1, 15)])
         .build()
         .execute_with(|| {
-            let pre_call_candidate_info =
-                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
-            let pre_call_top_nominations =
-                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
-            let pre_call_bottom_nominations =
-                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(6), 1, 4));
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(6), 6, 1));
-            let post_call_candidate_info =
-                ParachainStaking::candidate_info(&1).expect("nominated by all so exists");
-            let post_call_top_nominations =
-                ParachainStaking::top_nominations(&1).expect("nominated by all so exists");
-            let post_call_bottom_nominations =
-                ParachainStaking::bottom_nominations(&1).expect("nominated by all so exists");
-            let mut equal = true;
-            for Bond { owner, amount } in pre_call_bottom_nominations.nominations {
-                for Bond { owner: post_owner, amount: post_amount } in
-                    &post_call_bottom_nominations.nominations
-                {
-                    if &owner == post_owner {
-                        if &amount != post_amount {
-                            equal = false;
-                            break
-                        }
-                    }
-                }
-            }
-            assert!(equal);
-            let mut not_equal = false;
-            for Bond { owner, amount } in pre_call_top_nominations.nominations {
-                for Bond { owner: post_owner, amount: post_amount } in
-                    &post_call_top_nominations.nominations
-                {
-                    if &owner == post_owner {
-                        if &amount != post_amount {
-                            not_equal = true;
-                            break
-                        }
-                    }
-                }
-            }
-            assert!(not_equal);
-            assert_eq!(
-                pre_call_candidate_info.total_counted - 4,
-                post_call_candidate_info.total_counted
-            );
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Claim));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_ok!(ParachainStaking::set_payout_mode(Origin::root(), PayoutMode::Push));
+            assert_ok!(ParachainStaking::claim_rewards(Origin::signed(1), 1));
+            assert_eq!(Balances::free_balance(&1), 50);
+            assert!(ParachainStaking::total_claimable_rewards(&1).is_zero());
         });
 }
 
+// RETRY FAILED REWARD PAYMENT
+
+/// Pays the era 1 rewards of candidates 1 and 2, emptying the reward pot after the first one is
+/// paid so the payment of the second one fails. Returns the candidate that was not paid.
+fn fail_second_collator_reward() -> u64 {
+    set_author(1, 1, 1);
+    set_author(1, 2, 1);
+    set_reward_pot(40);
+    roll_to_era_begin(3);
+    Balances::make_free_balance_be(&ParachainStaking::compute_reward_pot_account_id(), 0);
+    roll_one_block();
+    let (unpaid, era, payment) =
+        <FailedRewardPayments<Test>>::iter().next().expect("one payment failed");
+    assert_eq!(era, 1);
+    assert_eq!(payment, FailedRewardPayment { amount: 20, attempts: 1 });
+    assert_last_event!(MetaEvent::ParachainStaking(Event::ErrorPayingStakingReward {
+        payee: unpaid,
+        rewards: 20,
+    }));
+    unpaid
+}
+
 #[test]
-fn can_execute_nominator_bond_less_for_leaving_candidate() {
+fn failed_reward_payment_is_queued_and_stays_locked() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_leave_candidates(Origin::signed(1), 1));
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            roll_to(10);
-            // can execute bond more nomination request for leaving candidate
-            assert_ok!(ParachainStaking::execute_nomination_request(Origin::signed(2), 2, 1));
+            fail_second_collator_reward();
+            assert_eq!(<FailedRewardPayments<Test>>::iter().count(), 1);
+            assert_eq!(ParachainStaking::locked_era_payout(), 20);
         });
 }
 
-// CANCEL PENDING NOMINATION REQUEST
-// 1. CANCEL REVOKE NOMINATION
-
 #[test]
-fn cancel_revoke_nomination_emits_correct_event() {
+fn failed_reward_payment_is_retried_in_later_block() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledNominationRequest {
-                nominator: 2,
-                collator: 1,
-                cancelled_request: CancelledScheduledRequest {
-                    when_executable: 3,
-                    action: NominationAction::Revoke(10),
-                },
-            }));
+            let unpaid = fail_second_collator_reward();
+            Balances::make_free_balance_be(&ParachainStaking::compute_reward_pot_account_id(), 20);
+            roll_one_block();
+            assert_event_emitted!(Event::RewardPaymentRetried {
+                era: 1,
+                account: unpaid,
+                rewards: 20,
+                destination: RewardDestination::Bonded,
+            });
+            assert!(ParachainStaking::failed_reward_payments(unpaid, 1).is_none());
+            assert_eq!(Balances::free_balance(&unpaid), 40);
+            assert!(ParachainStaking::locked_era_payout().is_zero());
         });
 }
 
 #[test]
-fn cancel_revoke_nomination_updates_nominator_state() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 10)])
-        .build()
-        .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
-            let state = ParachainStaking::nomination_scheduled_requests(&1);
-            assert_eq!(
-                state,
-                vec![ScheduledRequest {
-                    nominator: 2,
-                    when_executable: 3,
-                    action: NominationAction::Revoke(10),
-                }],
-            );
-            assert_eq!(
-                ParachainStaking::nominator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("nominator state must exist"),
-                10
-            );
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
-            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
-                .iter()
-                .any(|x| x.nominator == 2));
+fn failed_reward_payment_is_abandoned_after_max_attempts() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            let unpaid = fail_second_collator_reward();
+            roll_one_block();
+            assert_last_event!(MetaEvent::ParachainStaking(Event::ErrorPayingStakingReward {
+                payee: unpaid,
+                rewards: 20,
+            }));
             assert_eq!(
-                ParachainStaking::nominator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("nominator state must exist"),
-                0
+                ParachainStaking::failed_reward_payments(unpaid, 1),
+                Some(FailedRewardPayment { amount: 20, attempts: 2 })
             );
+            roll_one_block();
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RewardPaymentAbandoned {
+                era: 1,
+                account: unpaid,
+                rewards: 20,
+            }));
+            assert!(ParachainStaking::failed_reward_payments(unpaid, 1).is_none());
+            assert!(ParachainStaking::locked_era_payout().is_zero());
+            assert_eq!(Balances::free_balance(&unpaid), 20);
         });
 }
 
-// 2. CANCEL NOMINATOR BOND LESS
+#[test]
+fn failed_reward_payment_is_not_retried_without_weight_budget() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            let unpaid = fail_second_collator_reward();
+            crate::mock::RewardPaymentRetryWeight::set(0);
+            roll_one_block();
+            assert_eq!(
+                ParachainStaking::failed_reward_payments(unpaid, 1),
+                Some(FailedRewardPayment { amount: 20, attempts: 1 })
+            );
+        });
+}
 
 #[test]
-fn cancel_nominator_bond_less_correct_event() {
+fn retry_failed_reward_payment_can_be_called_by_anyone() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::CancelledNominationRequest {
-                nominator: 2,
-                collator: 1,
-                cancelled_request: CancelledScheduledRequest {
-                    when_executable: 3,
-                    action: NominationAction::Decrease(5),
-                },
+            let unpaid = fail_second_collator_reward();
+            crate::mock::RewardPaymentRetryWeight::set(0);
+            Balances::make_free_balance_be(&ParachainStaking::compute_reward_pot_account_id(), 20);
+            assert_ok!(ParachainStaking::retry_failed_reward_payment(Origin::signed(7), 1, unpaid));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RewardPaymentRetried {
+                era: 1,
+                account: unpaid,
+                rewards: 20,
+                destination: RewardDestination::Bonded,
             }));
+            assert_eq!(Balances::free_balance(&unpaid), 40);
+            assert!(ParachainStaking::locked_era_payout().is_zero());
         });
 }
 
 #[test]
-fn cancel_nominator_bond_less_updates_nominator_state() {
+fn failed_manual_retries_do_not_count_as_attempts() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 15)])
-        .with_candidates(vec![(1, 30)])
-        .with_nominations(vec![(2, 1, 15)])
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_nominator_bond_less(Origin::signed(2), 1, 5));
-            let state = ParachainStaking::nomination_scheduled_requests(&1);
-            assert_eq!(
-                state,
-                vec![ScheduledRequest {
-                    nominator: 2,
-                    when_executable: 3,
-                    action: NominationAction::Decrease(5),
-                }],
-            );
+            let unpaid = fail_second_collator_reward();
+            crate::mock::RewardPaymentRetryWeight::set(0);
+            for _ in 0..crate::mock::MaxRewardPaymentAttempts::get() {
+                assert_noop!(
+                    ParachainStaking::retry_failed_reward_payment(Origin::signed(7), 1, unpaid),
+                    pallet_balances::Error::<Test>::InsufficientBalance
+                );
+            }
             assert_eq!(
-                ParachainStaking::nominator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("nominator state must exist"),
-                5
+                ParachainStaking::failed_reward_payments(unpaid, 1),
+                Some(FailedRewardPayment { amount: 20, attempts: 1 })
             );
-            assert_ok!(ParachainStaking::cancel_nomination_request(Origin::signed(2), 1));
-            assert!(!ParachainStaking::nomination_scheduled_requests(&1)
-                .iter()
-                .any(|x| x.nominator == 2));
-            assert_eq!(
-                ParachainStaking::nominator_state(&2)
-                    .map(|x| x.less_total)
-                    .expect("nominator state must exist"),
-                0
+        });
+}
+
+#[test]
+fn cannot_retry_reward_payment_that_did_not_fail() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::retry_failed_reward_payment(Origin::signed(7), 1, 1),
+                Error::<Test>::FailedRewardPaymentDNE
             );
         });
 }
@@ -2914,6 +4625,7 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     */
                     account: 1,
                     rewards: 20,
+                    destination: RewardDestination::Bonded,
                 },
                 Event::Rewarded {
                     /*Explanation of how reward is computed:
@@ -2926,9 +4638,14 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     */
                     account: 6,
                     rewards: 10,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::Rewarded { account: 7, rewards: 10, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 10,
+                    destination: RewardDestination::Bonded,
                 },
-                Event::Rewarded { account: 7, rewards: 10 },
-                Event::Rewarded { account: 10, rewards: 10 },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -2965,10 +4682,14 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 22 },
-                Event::Rewarded { account: 6, rewards: 11 },
-                Event::Rewarded { account: 7, rewards: 11 },
-                Event::Rewarded { account: 10, rewards: 11 },
+                Event::Rewarded { account: 1, rewards: 22, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 11,
+                    destination: RewardDestination::Bonded,
+                },
                 Event::CollatorChosen { era: 6, collator_account: 1, total_exposed_amount: 50 },
                 Event::CollatorChosen { era: 6, collator_account: 2, total_exposed_amount: 40 },
                 Event::CollatorChosen { era: 6, collator_account: 3, total_exposed_amount: 20 },
@@ -2980,10 +4701,14 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 22 },
-                Event::Rewarded { account: 6, rewards: 11 },
-                Event::Rewarded { account: 7, rewards: 11 },
-                Event::Rewarded { account: 10, rewards: 11 },
+                Event::Rewarded { account: 1, rewards: 22, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 11,
+                    destination: RewardDestination::Bonded,
+                },
                 Event::NominatorLeftCandidate {
                     nominator: 6,
                     candidate: 1,
@@ -3002,9 +4727,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 29 },
-                Event::Rewarded { account: 7, rewards: 14 },
-                Event::Rewarded { account: 10, rewards: 14 },
+                Event::Rewarded { account: 1, rewards: 29, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 14, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 14,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new2);
             assert_eq_events!(expected.clone());
@@ -3025,9 +4754,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 30 },
-                Event::Rewarded { account: 7, rewards: 15 },
-                Event::Rewarded { account: 10, rewards: 15 },
+                Event::Rewarded { account: 1, rewards: 30, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 15, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 15,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new3);
             assert_eq_events!(expected.clone());
@@ -3047,9 +4780,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 32 },
-                Event::Rewarded { account: 7, rewards: 16 },
-                Event::Rewarded { account: 10, rewards: 16 },
+                Event::Rewarded { account: 1, rewards: 32, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 16, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 16,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new4);
             assert_eq_events!(expected.clone());
@@ -3076,9 +4813,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 33 },
-                Event::Rewarded { account: 7, rewards: 17 },
-                Event::Rewarded { account: 10, rewards: 17 },
+                Event::Rewarded { account: 1, rewards: 33, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 17, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 17,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new5);
             assert_eq_events!(expected.clone());
@@ -3099,9 +4840,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 35 },
-                Event::Rewarded { account: 7, rewards: 17 },
-                Event::Rewarded { account: 10, rewards: 17 },
+                Event::Rewarded { account: 1, rewards: 35, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 17, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 17,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new6);
             assert_eq_events!(expected.clone());
@@ -3120,10 +4865,14 @@ fn parachain_bond_inflation_reserve_matches_config() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 30 },
-                Event::Rewarded { account: 7, rewards: 15 },
-                Event::Rewarded { account: 10, rewards: 15 },
-                Event::Rewarded { account: 8, rewards: 15 },
+                Event::Rewarded { account: 1, rewards: 30, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 15, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 15,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::Rewarded { account: 8, rewards: 15, destination: RewardDestination::Bonded },
             ];
             expected.append(&mut new7);
             assert_eq_events!(expected);
@@ -3212,9 +4961,9 @@ fn rewards_matches_config() {
                     selected_collators_number: 2,
                     total_balance: 80,
                 },
-                Event::Rewarded { account: 4, rewards: 15 },
-                Event::Rewarded { account: 5, rewards: 7 },
-                Event::Rewarded { account: 6, rewards: 7 },
+                Event::Rewarded { account: 4, rewards: 15, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 5, rewards: 7, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 7, destination: RewardDestination::Bonded },
             ];
             expected.append(&mut new2);
             assert_eq_events!(expected);
@@ -3480,7 +5229,11 @@ fn payout_distribution_to_solo_collators() {
                     selected_collators_number: 5,
                     total_balance: 400,
                 },
-                Event::Rewarded { account: 1, rewards: 305 },
+                Event::Rewarded {
+                    account: 1,
+                    rewards: 305,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -3514,8 +5267,16 @@ fn payout_distribution_to_solo_collators() {
                     selected_collators_number: 5,
                     total_balance: 400,
                 },
-                Event::Rewarded { account: 1, rewards: 192 },
-                Event::Rewarded { account: 2, rewards: 128 },
+                Event::Rewarded {
+                    account: 1,
+                    rewards: 192,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::Rewarded {
+                    account: 2,
+                    rewards: 128,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new1);
             assert_eq_events!(expected.clone());
@@ -3551,11 +5312,11 @@ fn payout_distribution_to_solo_collators() {
                     selected_collators_number: 5,
                     total_balance: 400,
                 },
-                Event::Rewarded { account: 5, rewards: 67 },
-                Event::Rewarded { account: 3, rewards: 67 },
-                Event::Rewarded { account: 4, rewards: 67 },
-                Event::Rewarded { account: 1, rewards: 67 },
-                Event::Rewarded { account: 2, rewards: 67 },
+                Event::Rewarded { account: 5, rewards: 67, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 3, rewards: 67, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 4, rewards: 67, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 1, rewards: 67, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 2, rewards: 67, destination: RewardDestination::Bonded },
             ];
             expected.append(&mut new2);
             assert_eq_events!(expected);
@@ -3836,10 +5597,14 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 20 },
-                Event::Rewarded { account: 6, rewards: 10 },
-                Event::Rewarded { account: 7, rewards: 10 },
-                Event::Rewarded { account: 10, rewards: 10 },
+                Event::Rewarded { account: 1, rewards: 20, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 10, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 10, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 10,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new);
             assert_eq_events!(expected.clone());
@@ -3876,10 +5641,14 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 21 },
-                Event::Rewarded { account: 6, rewards: 10 },
-                Event::Rewarded { account: 7, rewards: 10 },
-                Event::Rewarded { account: 10, rewards: 10 },
+                Event::Rewarded { account: 1, rewards: 21, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 10, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 10, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 10,
+                    destination: RewardDestination::Bonded,
+                },
                 Event::CollatorChosen { era: 6, collator_account: 1, total_exposed_amount: 50 },
                 Event::CollatorChosen { era: 6, collator_account: 2, total_exposed_amount: 40 },
                 Event::CollatorChosen { era: 6, collator_account: 3, total_exposed_amount: 20 },
@@ -3891,10 +5660,14 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 22 },
-                Event::Rewarded { account: 6, rewards: 11 },
-                Event::Rewarded { account: 7, rewards: 11 },
-                Event::Rewarded { account: 10, rewards: 11 },
+                Event::Rewarded { account: 1, rewards: 22, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 6, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 11, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 11,
+                    destination: RewardDestination::Bonded,
+                },
                 Event::NominatorLeftCandidate {
                     nominator: 6,
                     candidate: 1,
@@ -3924,9 +5697,13 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 29 },
-                Event::Rewarded { account: 7, rewards: 14 },
-                Event::Rewarded { account: 10, rewards: 14 },
+                Event::Rewarded { account: 1, rewards: 29, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 14, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 14,
+                    destination: RewardDestination::Bonded,
+                },
                 Event::CollatorChosen { era: 8, collator_account: 1, total_exposed_amount: 40 },
                 Event::CollatorChosen { era: 8, collator_account: 2, total_exposed_amount: 40 },
                 Event::CollatorChosen { era: 8, collator_account: 3, total_exposed_amount: 20 },
@@ -3938,9 +5715,13 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 30 },
-                Event::Rewarded { account: 7, rewards: 15 },
-                Event::Rewarded { account: 10, rewards: 15 },
+                Event::Rewarded { account: 1, rewards: 30, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 15, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 15,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new3);
             assert_eq_events!(expected.clone());
@@ -3960,9 +5741,13 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 130,
                 },
-                Event::Rewarded { account: 1, rewards: 32 },
-                Event::Rewarded { account: 7, rewards: 16 },
-                Event::Rewarded { account: 10, rewards: 16 },
+                Event::Rewarded { account: 1, rewards: 32, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 16, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 16,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new4);
             assert_eq_events!(expected.clone());
@@ -3989,9 +5774,13 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 33 },
-                Event::Rewarded { account: 7, rewards: 17 },
-                Event::Rewarded { account: 10, rewards: 17 },
+                Event::Rewarded { account: 1, rewards: 33, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 17, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 17,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new5);
             assert_eq_events!(expected.clone());
@@ -4011,9 +5800,13 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 35 },
-                Event::Rewarded { account: 7, rewards: 17 },
-                Event::Rewarded { account: 10, rewards: 17 },
+                Event::Rewarded { account: 1, rewards: 35, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 17, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 17,
+                    destination: RewardDestination::Bonded,
+                },
             ];
             expected.append(&mut new6);
             assert_eq_events!(expected.clone());
@@ -4033,10 +5826,14 @@ fn payouts_follow_nomination_changes() {
                     selected_collators_number: 5,
                     total_balance: 140,
                 },
-                Event::Rewarded { account: 1, rewards: 30 },
-                Event::Rewarded { account: 7, rewards: 15 },
-                Event::Rewarded { account: 10, rewards: 15 },
-                Event::Rewarded { account: 8, rewards: 15 },
+                Event::Rewarded { account: 1, rewards: 30, destination: RewardDestination::Bonded },
+                Event::Rewarded { account: 7, rewards: 15, destination: RewardDestination::Bonded },
+                Event::Rewarded {
+                    account: 10,
+                    rewards: 15,
+                    destination: RewardDestination::Bonded,
+                },
+                Event::Rewarded { account: 8, rewards: 15, destination: RewardDestination::Bonded },
             ];
             expected.append(&mut new7);
             assert_eq_events!(expected);
@@ -4111,7 +5908,7 @@ fn candidate_pool_updates_when_total_counted_changes() {
         .execute_with(|| {
             fn is_candidate_pool_bond(account: u64, bond: u128) {
                 let pool = ParachainStaking::candidate_pool();
-                for candidate in pool.0 {
+                for candidate in pool {
                     if candidate.owner == account {
                         assert_eq!(
                             candidate.amount, bond,
@@ -4375,22 +6172,34 @@ fn no_rewards_paid_until_after_reward_payment_delay() {
                     total_balance: 80,
                 },
                 // rewards will begin immediately following a NewEra
-                Event::Rewarded { account: 3, rewards: 1 },
+                Event::Rewarded { account: 3, rewards: 1, destination: RewardDestination::Bonded },
             ]);
             assert_eq_events!(expected);
 
             // roll to the next block where we start era 3; we should have era change and first
             // payout made.
             roll_one_block();
-            expected.push(Event::Rewarded { account: 4, rewards: 2 });
+            expected.push(Event::Rewarded {
+                account: 4,
+                rewards: 2,
+                destination: RewardDestination::Bonded,
+            });
             assert_eq_events!(expected);
 
             roll_one_block();
-            expected.push(Event::Rewarded { account: 1, rewards: 1 });
+            expected.push(Event::Rewarded {
+                account: 1,
+                rewards: 1,
+                destination: RewardDestination::Bonded,
+            });
             assert_eq_events!(expected);
 
             roll_one_block();
-            expected.push(Event::Rewarded { account: 2, rewards: 1 });
+            expected.push(Event::Rewarded {
+                account: 2,
+                rewards: 1,
+                destination: RewardDestination::Bonded,
+            });
             assert_eq_events!(expected);
 
             // there should be no more payments in this era...
@@ -4470,7 +6279,7 @@ fn deferred_payment_storage_items_are_cleaned_up() {
                     selected_collators_number: 2,
                     total_balance: 40,
                 },
-                Event::Rewarded { account: 1, rewards: 1 },
+                Event::Rewarded { account: 1, rewards: 1, destination: RewardDestination::Bonded },
             ]);
             assert_eq_events!(expected);
 
@@ -4484,7 +6293,11 @@ fn deferred_payment_storage_items_are_cleaned_up() {
                 <DelayedPayouts<Test>>::contains_key(1),
                 "DelayedPayouts should be populated after RewardPaymentDelay"
             );
-            assert!(<Points<Test>>::contains_key(1));
+            assert!(
+                !<Points<Test>>::contains_key(1),
+                "Points should be moved into DelayedPayouts after RewardPaymentDelay"
+            );
+            assert_eq!(ParachainStaking::delayed_payouts(1).unwrap().total_points, 2);
             assert!(
                 !<Staked<Test>>::contains_key(1),
                 "Staked should be cleaned up after era change"
@@ -4498,8 +6311,9 @@ fn deferred_payment_storage_items_are_cleaned_up() {
             assert!(!<Points<Test>>::contains_key(3), "We never awarded points for era 3");
             assert!(<Staked<Test>>::contains_key(3));
 
-            // collator 1 has been paid in this last block and associated storage cleaned up
-            assert!(!<AtStake<Test>>::contains_key(1, 1));
+            // collator 1 has been paid in this last block and associated storage cleaned up,
+            // except for its snapshot which is kept to slash the era
+            assert!(<AtStake<Test>>::contains_key(1, 1));
             assert!(!<AwardedPts<Test>>::contains_key(1, 1));
 
             // but collator 2 hasn't been paid
@@ -4509,7 +6323,7 @@ fn deferred_payment_storage_items_are_cleaned_up() {
             era = 4;
             roll_to_era_begin(era.into());
             expected.append(&mut vec![
-                Event::Rewarded { account: 2, rewards: 1 }, // from previous era
+                Event::Rewarded { account: 2, rewards: 1, destination: RewardDestination::Bonded }, // from previous era
                 Event::CollatorChosen { era, collator_account: 1, total_exposed_amount: 20 },
                 Event::CollatorChosen { era, collator_account: 2, total_exposed_amount: 20 },
                 Event::NewEra {
@@ -4521,8 +6335,8 @@ fn deferred_payment_storage_items_are_cleaned_up() {
             ]);
             assert_eq_events!(expected);
 
-            // collators have both been paid and storage fully cleaned up for era 1
-            assert!(!<AtStake<Test>>::contains_key(1, 2));
+            // collators have both been paid and storage cleaned up for era 1
+            assert!(<AtStake<Test>>::contains_key(1, 2));
             assert!(!<AwardedPts<Test>>::contains_key(1, 2));
             assert!(!<Staked<Test>>::contains_key(1));
             assert!(!<Points<Test>>::contains_key(1)); // points should be cleaned up
@@ -4532,6 +6346,14 @@ fn deferred_payment_storage_items_are_cleaned_up() {
 
             // no more events expected
             assert_eq_events!(expected);
+
+            // the snapshots of era 1 are removed once it can no longer be slashed
+            roll_to_era_begin(5);
+            assert!(<AtStake<Test>>::contains_key(1, 1));
+            roll_to_era_begin(6);
+            assert!(!<AtStake<Test>>::contains_key(1, 1));
+            assert!(!<AtStake<Test>>::contains_key(1, 2));
+            assert!(<AtStake<Test>>::contains_key(2, 1));
         });
 }
 
@@ -4648,9 +6470,21 @@ fn deferred_payment_steady_state_event_flow() {
                         total_balance: 1600,
                     },
                     // first payout should occur on era change
-                    Event::Rewarded { account: 3, rewards: 16 },
-                    Event::Rewarded { account: 22, rewards: 8 },
-                    Event::Rewarded { account: 33, rewards: 8 },
+                    Event::Rewarded {
+                        account: 3,
+                        rewards: 16,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 22,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 33,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
@@ -4658,32 +6492,72 @@ fn deferred_payment_steady_state_event_flow() {
 
                 roll_one_block();
                 let expected = vec![
-                    Event::Rewarded { account: 4, rewards: 16 },
-                    Event::Rewarded { account: 33, rewards: 8 },
-                    Event::Rewarded { account: 44, rewards: 8 },
+                    Event::Rewarded {
+                        account: 4,
+                        rewards: 16,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 33,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 44,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
                 roll_one_block();
                 let expected = vec![
-                    Event::Rewarded { account: 1, rewards: 16 },
-                    Event::Rewarded { account: 11, rewards: 8 },
-                    Event::Rewarded { account: 44, rewards: 8 },
+                    Event::Rewarded {
+                        account: 1,
+                        rewards: 16,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 11,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 44,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
                 roll_one_block();
                 let expected = vec![
-                    Event::Rewarded { account: 2, rewards: 16 },
-                    Event::Rewarded { account: 11, rewards: 8 },
-                    Event::Rewarded { account: 22, rewards: 8 },
+                    Event::Rewarded {
+                        account: 2,
+                        rewards: 16,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 11,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
+                    Event::Rewarded {
+                        account: 22,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
                 roll_one_block();
                 let expected = vec![
                     // we paid everyone out by now, should repeat last event
-                    Event::Rewarded { account: 22, rewards: 8 },
+                    Event::Rewarded {
+                        account: 22,
+                        rewards: 8,
+                        destination: RewardDestination::Bonded,
+                    },
                 ];
                 assert_eq_last_events!(expected);
 
@@ -4815,8 +6689,16 @@ fn test_nominator_scheduled_for_revoke_is_rewarded_for_previous_eras_but_not_for
             roll_to_era_begin(3);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended"
             );
@@ -4824,7 +6706,11 @@ fn test_nominator_scheduled_for_revoke_is_rewarded_for_previous_eras_but_not_for
             set_reward_pot(5);
             roll_to_era_begin(4);
             assert_eq_last_events!(
-                vec![Event::<Test>::Rewarded { account: 1, rewards: 5 }],
+                vec![Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 5,
+                    destination: RewardDestination::Bonded
+                }],
                 "nominator was rewarded unexpectedly"
             );
             let collator_snapshot = ParachainStaking::at_stake(ParachainStaking::era().current, 1);
@@ -4871,7 +6757,11 @@ fn test_nominator_scheduled_for_revoke_is_rewarded_when_request_cancelled() {
             set_reward_pot(5);
             roll_to_era_begin(4);
             assert_eq_last_events!(
-                vec![Event::<Test>::Rewarded { account: 1, rewards: 5 }],
+                vec![Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 5,
+                    destination: RewardDestination::Bonded
+                }],
                 "nominator was rewarded unexpectedly",
             );
             let collator_snapshot = ParachainStaking::at_stake(ParachainStaking::era().current, 1);
@@ -4889,8 +6779,16 @@ fn test_nominator_scheduled_for_revoke_is_rewarded_when_request_cancelled() {
             roll_to_era_begin(5);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended",
             );
@@ -4926,8 +6824,16 @@ fn test_nominator_scheduled_for_bond_decrease_is_rewarded_for_previous_eras_but_
             roll_to_era_begin(3);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 2 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended"
             );
@@ -4936,8 +6842,16 @@ fn test_nominator_scheduled_for_bond_decrease_is_rewarded_for_previous_eras_but_
             roll_to_era_begin(4);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was rewarded unexpectedly"
             );
@@ -4986,8 +6900,16 @@ fn test_nominator_scheduled_for_bond_decrease_is_rewarded_when_request_cancelled
             roll_to_era_begin(4);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was rewarded unexpectedly",
             );
@@ -5006,8 +6928,16 @@ fn test_nominator_scheduled_for_bond_decrease_is_rewarded_when_request_cancelled
             roll_to_era_begin(5);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 2 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended",
             );
@@ -5042,8 +6972,16 @@ fn test_nominator_scheduled_for_leave_is_rewarded_for_previous_eras_but_not_for_
             roll_to_era_begin(3);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended"
             );
@@ -5051,7 +6989,11 @@ fn test_nominator_scheduled_for_leave_is_rewarded_for_previous_eras_but_not_for_
             set_reward_pot(5);
             roll_to_era_begin(4);
             assert_eq_last_events!(
-                vec![Event::<Test>::Rewarded { account: 1, rewards: 5 },],
+                vec![Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 5,
+                    destination: RewardDestination::Bonded
+                },],
                 "nominator was rewarded unexpectedly"
             );
             let collator_snapshot = ParachainStaking::at_stake(ParachainStaking::era().current, 1);
@@ -5097,7 +7039,11 @@ fn test_nominator_scheduled_for_leave_is_rewarded_when_request_cancelled() {
             set_reward_pot(5);
             roll_to_era_begin(4);
             assert_eq_last_events!(
-                vec![Event::<Test>::Rewarded { account: 1, rewards: 5 },],
+                vec![Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 5,
+                    destination: RewardDestination::Bonded
+                },],
                 "nominator was rewarded unexpectedly",
             );
             let collator_snapshot = ParachainStaking::at_stake(ParachainStaking::era().current, 1);
@@ -5115,8 +7061,16 @@ fn test_nominator_scheduled_for_leave_is_rewarded_when_request_cancelled() {
             roll_to_era_begin(5);
             assert_eq_last_events!(
                 vec![
-                    Event::<Test>::Rewarded { account: 1, rewards: 3 },
-                    Event::<Test>::Rewarded { account: 2, rewards: 2 },
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 3,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 2,
+                        destination: RewardDestination::Bonded
+                    },
                 ],
                 "nominator was not rewarded as intended",
             );
@@ -5145,7 +7099,7 @@ fn test_nomination_request_exists_returns_true_when_decrease_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 1,
-                vec![ScheduledRequest {
+                bounded_vec![ScheduledRequest {
                     nominator: 2,
                     when_executable: 3,
                     action: NominationAction::Decrease(5),
@@ -5165,7 +7119,7 @@ fn test_nomination_request_exists_returns_true_when_revoke_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 1,
-                vec![ScheduledRequest {
+                bounded_vec![ScheduledRequest {
                     nominator: 2,
                     when_executable: 3,
                     action: NominationAction::Revoke(5),
@@ -5197,7 +7151,7 @@ fn test_nomination_request_revoke_exists_returns_false_when_decrease_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 1,
-                vec![ScheduledRequest {
+                bounded_vec![ScheduledRequest {
                     nominator: 2,
                     when_executable: 3,
                     action: NominationAction::Decrease(5),
@@ -5217,7 +7171,7 @@ fn test_nomination_request_revoke_exists_returns_true_when_revoke_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 1,
-                vec![ScheduledRequest {
+                bounded_vec![ScheduledRequest {
                     nominator: 2,
                     when_executable: 3,
                     action: NominationAction::Revoke(5),
@@ -5235,14 +7189,8 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up() {
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                2,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
-            <NominationScheduledRequests<Test>>::insert(
-                3,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
+            <NominationScheduledRequests<Test>>::insert(2, BoundedVec::default());
+            <NominationScheduledRequests<Test>>::insert(3, BoundedVec::default());
             assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
                 Origin::signed(1),
                 vec![2, 3, 4] // 4 does not exist, but is OK for idempotency
@@ -5261,14 +7209,8 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up_only_speci
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                2,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
-            <NominationScheduledRequests<Test>>::insert(
-                3,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
+            <NominationScheduledRequests<Test>>::insert(2, BoundedVec::default());
+            <NominationScheduledRequests<Test>>::insert(3, BoundedVec::default());
             assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
                 Origin::signed(1),
                 vec![2]
@@ -5287,13 +7229,10 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_requests
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                2,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
+            <NominationScheduledRequests<Test>>::insert(2, BoundedVec::default());
             <NominationScheduledRequests<Test>>::insert(
                 3,
-                vec![ScheduledRequest {
+                bounded_vec![ScheduledRequest {
                     nominator: 10,
                     when_executable: 1,
                     action: NominationAction::Revoke(10),
@@ -5318,10 +7257,7 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_candidat
         .build()
         .execute_with(|| {
             // invalid state
-            <NominationScheduledRequests<Test>>::insert(
-                1,
-                Vec::<ScheduledRequest<u64, u128>>::new(),
-            );
+            <NominationScheduledRequests<Test>>::insert(1, BoundedVec::default());
             assert_noop!(
                 ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
                     Origin::signed(1),
@@ -5483,3 +7419,116 @@ fn test_nominator_with_deprecated_status_leaving_cannot_execute_leave_nominators
             );
         });
 }
+
+#[test]
+fn nominator_rewards_are_auto_compounded() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(
+                vec![
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 20,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 10,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::NominationIncreased {
+                        nominator: 2,
+                        candidate: 1,
+                        amount: 5,
+                        in_top: true,
+                    },
+                    Event::<Test>::Compounded { candidate: 1, nominator: 2, amount: 5 },
+                ],
+                "nominator rewards were not compounded as intended"
+            );
+            let nominator_state = ParachainStaking::nominator_state(2).expect("nominator exists");
+            assert_eq!(nominator_state.total(), 15);
+            assert_eq!(crate::mock::query_lock_amount(2, NOMINATOR_LOCK_ID), Some(15));
+            assert_eq!(
+                ParachainStaking::candidate_info(1).expect("candidate exists").total_counted,
+                35
+            );
+            assert_eq!(ParachainStaking::total(), 35);
+            assert_eq!(ParachainStaking::candidate_pool()[0].amount, 35);
+        });
+}
+
+#[test]
+fn nominator_rewards_are_not_auto_compounded_when_revoke_is_pending() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_nominations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50),
+                0,
+                1
+            ));
+            // scheduled after the era 1 snapshot so the nominator is still rewarded for era 1
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(Origin::signed(2), 1));
+            set_author(1, 1, 1);
+            set_reward_pot(30);
+            roll_to_era_begin(3);
+            assert_eq_last_events!(
+                vec![
+                    Event::<Test>::Rewarded {
+                        account: 1,
+                        rewards: 20,
+                        destination: RewardDestination::Bonded
+                    },
+                    Event::<Test>::Rewarded {
+                        account: 2,
+                        rewards: 10,
+                        destination: RewardDestination::Bonded
+                    },
+                ],
+                "nominator rewards were compounded unexpectedly"
+            );
+            assert_event_not_emitted!(Event::<Test>::Compounded {
+                candidate: 1,
+                nominator: 2,
+                amount: 5
+            });
+        });
+}
+
+#[test]
+fn nomination_inserted_beyond_capacity_is_rejected() {
+    let mut nominations = Nominations::<u64, u128, MaxTopNominationsPerCandidate>::default();
+    for owner in 1..=4u64 {
+        assert_ok!(nominations
+            .insert_sorted_greatest_to_least::<Test>(Bond { owner, amount: owner as u128 * 10 }));
+    }
+    assert_eq!(
+        nominations.insert_sorted_greatest_to_least::<Test>(Bond { owner: 5, amount: 50 }),
+        Err(Error::<Test>::ExceedMaxNominationsPerCandidate.into())
+    );
+    assert_eq!(
+        nominations.nominations.iter().map(|bond| bond.owner).collect::<Vec<_>>(),
+        vec![4, 3, 2, 1]
+    );
+    assert_eq!(nominations.total, 100);
+}
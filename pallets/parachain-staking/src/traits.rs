@@ -16,6 +16,8 @@
 
 //! traits for parachain-staking
 
+use sp_runtime::Perbill;
+
 pub trait OnCollatorPayout<AccountId, Balance> {
     fn on_collator_payout(
         for_era: crate::EraIndex,
@@ -41,3 +43,13 @@ impl OnNewEra for () {
         0
     }
 }
+
+/// Entry point for the runtime to report offences committed by collators.
+pub trait ReportOffence<AccountId> {
+    /// Penalises each `(offender, era, fraction)` by slashing `fraction` of the offender's stake
+    /// in `era`, and chills the offender until it calls `go_online`.
+    /// Each offence is only penalised once.
+    fn report_offence(
+        offenders: &[(AccountId, crate::EraIndex, Perbill)],
+    ) -> frame_support::pallet_prelude::Weight;
+}
@@ -17,8 +17,10 @@
 //! Types for parachain-staking
 
 use crate::{
-    set::OrderedSet, BalanceOf, BottomNominations, CandidateInfo, Config, Era, EraIndex, Error,
-    Event, NominatorState, Pallet, TopNominations, Total, COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID,
+    set::{BoundedOrderedSet, OrderedSet},
+    BalanceOf, BottomNominations, CandidateInfo, Config, Era, EraIndex, Error, Event,
+    NominatorState, Pallet, RewardPoint, StakingParams, TopNominations, Total, COLLATOR_LOCK_ID,
+    NOMINATOR_LOCK_ID,
 };
 use frame_support::{
     pallet_prelude::*,
@@ -27,16 +29,29 @@ use frame_support::{
 use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{
     traits::{AtLeast32BitUnsigned, Saturating, Zero},
-    Percent, RuntimeDebug,
+    Perbill, Percent, RuntimeDebug,
 };
-use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, prelude::*};
+use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, fmt::Debug, prelude::*};
+
+/// Convenience type to add two `Get<u32>` bounds, such as the top and bottom nominations of a
+/// candidate
+pub struct AddGet<T, R> {
+    _phantom: PhantomData<(T, R)>,
+}
+
+impl<T: Get<u32>, R: Get<u32>> Get<u32> for AddGet<T, R> {
+    fn get() -> u32 {
+        T::get().saturating_add(R::get())
+    }
+}
 
 pub struct CountedNominations<T: Config> {
     pub uncounted_stake: BalanceOf<T>,
-    pub rewardable_nominations: Vec<Bond<T::AccountId, BalanceOf<T>>>,
+    pub rewardable_nominations:
+        BoundedVec<Bond<T::AccountId, BalanceOf<T>>, T::MaxTopNominationsPerCandidate>,
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct Bond<AccountId, Balance> {
     pub owner: AccountId,
     pub amount: Balance,
@@ -78,7 +93,7 @@ impl<AccountId: Ord, Balance> PartialEq for Bond<AccountId, Balance> {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// The activity status of the collator
 pub enum CollatorStatus {
     /// Committed to be online and producing valid blocks (not equivocating)
@@ -95,25 +110,33 @@ impl Default for CollatorStatus {
     }
 }
 
-#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNominations))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
 /// Snapshot of collator state at the start of the era for which they are selected
-pub struct CollatorSnapshot<AccountId, Balance> {
+pub struct CollatorSnapshot<AccountId, Balance, MaxNominations: Get<u32>> {
     /// The total value locked by the collator.
     pub bond: Balance,
 
     /// The rewardable nominations. This list is a subset of total nominators, where certain
     /// nominators are adjusted based on their scheduled
     /// [NominationChange::Revoke] or [NominationChange::Decrease] action.
-    pub nominations: Vec<Bond<AccountId, Balance>>,
+    pub nominations: BoundedVec<Bond<AccountId, Balance>, MaxNominations>,
 
     /// The total counted value locked for the collator, including the self bond + total staked by
     /// top nominators.
     pub total: Balance,
+
+    /// The commission the collator takes from its era reward before the remainder is shared
+    /// pro-rata with its nominators.
+    pub commission: Perbill,
 }
 
-impl<A: PartialEq, B: PartialEq> PartialEq for CollatorSnapshot<A, B> {
+impl<A: PartialEq, B: PartialEq, S: Get<u32>> PartialEq for CollatorSnapshot<A, B, S> {
     fn eq(&self, other: &Self) -> bool {
-        let must_be_true = self.bond == other.bond && self.total == other.total;
+        let must_be_true = self.bond == other.bond &&
+            self.total == other.total &&
+            self.commission == other.commission;
         if !must_be_true {
             return false
         }
@@ -128,20 +151,38 @@ impl<A: PartialEq, B: PartialEq> PartialEq for CollatorSnapshot<A, B> {
     }
 }
 
-impl<A, B: Default> Default for CollatorSnapshot<A, B> {
-    fn default() -> CollatorSnapshot<A, B> {
-        CollatorSnapshot { bond: B::default(), nominations: Vec::new(), total: B::default() }
+impl<A, B: Default, S: Get<u32>> Default for CollatorSnapshot<A, B, S> {
+    fn default() -> CollatorSnapshot<A, B, S> {
+        CollatorSnapshot {
+            bond: B::default(),
+            nominations: BoundedVec::default(),
+            total: B::default(),
+            commission: Perbill::zero(),
+        }
+    }
+}
+
+impl<A: Debug, B: Debug, S: Get<u32>> Debug for CollatorSnapshot<A, B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        f.debug_struct("CollatorSnapshot")
+            .field("bond", &self.bond)
+            .field("nominations", &self.nominations)
+            .field("total", &self.total)
+            .field("commission", &self.commission)
+            .finish()
     }
 }
 
-#[derive(Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Info needed to make delayed payments to stakers after era end
 pub struct DelayedPayout<Balance> {
-    /// Total era reward (result of compute_total_reward_to_pay() at era end)
-    /* TODO - remove this field because it will have the same value as the one below */
+    /// Total era reward (result of compute_total_reward_to_pay() at era end), including the
+    /// parachain bond reserve
     pub era_issuance: Balance,
     /// The total inflation paid this era to stakers (e.g. less parachain bond fund)
     pub total_staking_reward: Balance,
+    /// Total points awarded to collators in the era, moved from `Points` when the era is due
+    pub total_points: RewardPoint,
 }
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -182,13 +223,20 @@ impl<A, B> From<Collator2<A, B>> for CollatorCandidate<A, B> {
     }
 }
 
-#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Request scheduled to change the collator candidate self-bond
 pub struct CandidateBondLessRequest<Balance> {
     pub amount: Balance,
     pub when_executable: EraIndex,
 }
 
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Request scheduled to change the collator candidate commission
+pub struct CommissionChangeRequest {
+    pub commission: Perbill,
+    pub when_executable: EraIndex,
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// DEPRECATED, replaced by `CandidateMetadata` and two storage instances of `Nominations`
 /// Collator candidate state with self bond + nominations
@@ -213,21 +261,41 @@ pub struct CollatorCandidate<AccountId, Balance> {
     pub state: CollatorStatus,
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNominations))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
 /// Type for top and bottom nomination storage item
-pub struct Nominations<AccountId, Balance> {
-    pub nominations: Vec<Bond<AccountId, Balance>>,
+pub struct Nominations<AccountId, Balance, MaxNominations: Get<u32>> {
+    pub nominations: BoundedVec<Bond<AccountId, Balance>, MaxNominations>,
     pub total: Balance,
 }
 
-impl<A, B: Default> Default for Nominations<A, B> {
-    fn default() -> Nominations<A, B> {
-        Nominations { nominations: Vec::new(), total: B::default() }
+impl<A, B: Default, S: Get<u32>> Default for Nominations<A, B, S> {
+    fn default() -> Nominations<A, B, S> {
+        Nominations { nominations: BoundedVec::default(), total: B::default() }
+    }
+}
+
+impl<A: Clone, B: Clone, S: Get<u32>> Clone for Nominations<A, B, S> {
+    fn clone(&self) -> Self {
+        Nominations { nominations: self.nominations.clone(), total: self.total.clone() }
+    }
+}
+
+impl<A: Debug, B: Debug, S: Get<u32>> Debug for Nominations<A, B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        f.debug_struct("Nominations")
+            .field("nominations", &self.nominations)
+            .field("total", &self.total)
+            .finish()
     }
 }
 
-impl<AccountId, Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating>
-    Nominations<AccountId, Balance>
+impl<
+        AccountId: PartialEq,
+        Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating,
+        S: Get<u32>,
+    > Nominations<AccountId, Balance, S>
 {
     pub fn sort_greatest_to_least(&mut self) {
         self.nominations.sort_by(|a, b| b.amount.cmp(&a.amount));
@@ -235,34 +303,39 @@ impl<AccountId, Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating
     /// Insert sorted greatest to least and increase .total accordingly
     /// Insertion respects first come first serve so new nominations are pushed after existing
     /// nominations if the amount is the same
-    pub fn insert_sorted_greatest_to_least(&mut self, nomination: Bond<AccountId, Balance>) {
-        self.total = self.total.saturating_add(nomination.amount);
-        // if nominations nonempty && last_element == nomination.amount => push input and return
-        if !self.nominations.is_empty() {
-            // if last_element == nomination.amount => push the nomination and return early
-            if self.nominations[self.nominations.len() - 1].amount == nomination.amount {
-                self.nominations.push(nomination);
-                // early return
-                return
-            }
-        }
-        // else binary search insertion
-        match self.nominations.binary_search_by(|x| nomination.amount.cmp(&x.amount)) {
-            // sorted insertion on sorted vec
-            // enforces first come first serve for equal bond amounts
-            Ok(i) => {
-                let mut new_index = i + 1;
-                while new_index <= (self.nominations.len() - 1) {
-                    if self.nominations[new_index].amount == nomination.amount {
-                        new_index = new_index.saturating_add(1);
-                    } else {
-                        self.nominations.insert(new_index, nomination);
-                        return
-                    }
-                }
-                self.nominations.push(nomination)
+    /// Errors if the nominations are at full capacity
+    pub fn insert_sorted_greatest_to_least<T: Config>(
+        &mut self,
+        nomination: Bond<AccountId, Balance>,
+    ) -> DispatchResult {
+        let amount = nomination.amount;
+        // first come first serve: insert after the existing nominations of the same amount
+        let index = self.nominations.partition_point(|x| x.amount >= amount);
+        self.nominations
+            .try_insert(index, nomination)
+            .map_err(|_| Error::<T>::ExceedMaxNominationsPerCandidate)?;
+        self.total = self.total.saturating_add(amount);
+        Ok(())
+    }
+    /// Remove the nomination of `owner`
+    /// Return the removed nomination, None if `owner` has no nomination
+    pub fn remove(&mut self, owner: &AccountId) -> Option<Bond<AccountId, Balance>> {
+        let index = self.nominations.iter().position(|x| &x.owner == owner)?;
+        let nomination = self.nominations.remove(index);
+        self.total = self.total.saturating_sub(nomination.amount);
+        Some(nomination)
+    }
+    /// Apply `f` to the amount of the nomination of `owner` and keep the nominations sorted
+    /// greatest to least, the total is not updated
+    /// Return false if `owner` has no nomination
+    pub fn mutate_amount(&mut self, owner: &AccountId, f: impl FnOnce(Balance) -> Balance) -> bool {
+        match self.nominations.iter_mut().find(|x| &x.owner == owner) {
+            Some(nomination) => {
+                nomination.amount = f(nomination.amount);
+                self.sort_greatest_to_least();
+                true
             },
-            Err(i) => self.nominations.insert(i, nomination),
+            None => false,
         }
     }
     /// Return the capacity status for top nominations
@@ -292,7 +365,7 @@ impl<AccountId, Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating
     }
 }
 
-#[derive(PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Capacity status for top or bottom nominations
 pub enum CapacityStatus {
     /// Reached capacity
@@ -303,7 +376,7 @@ pub enum CapacityStatus {
     Partial,
 }
 
-#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// All candidate info except the top and bottom nominations
 pub struct CandidateMetadata<Balance> {
     /// This candidate's self bond amount
@@ -326,6 +399,10 @@ pub struct CandidateMetadata<Balance> {
     pub request: Option<CandidateBondLessRequest<Balance>>,
     /// Current status of the collator
     pub status: CollatorStatus,
+    /// Share of the era reward taken by the collator before the pro-rata split
+    pub commission: Perbill,
+    /// Maximum 1 pending request to change the commission at any given time
+    pub commission_request: Option<CommissionChangeRequest>,
 }
 
 impl<
@@ -351,6 +428,8 @@ impl<
             bottom_capacity: CapacityStatus::Empty,
             request: None,
             status: CollatorStatus::Active,
+            commission: Perbill::zero(),
+            commission_request: None,
         }
     }
     pub fn is_active(&self) -> bool {
@@ -362,7 +441,7 @@ impl<
     pub fn schedule_leave<T: Config>(&mut self) -> Result<(EraIndex, EraIndex), DispatchError> {
         ensure!(!self.is_leaving(), Error::<T>::CandidateAlreadyLeaving);
         let now = <Era<T>>::get().current;
-        let when = now + T::LeaveCandidatesDelay::get();
+        let when = now + <StakingParams<T>>::get().leave_candidates_delay;
         self.status = CollatorStatus::Leaving(when);
         Ok((now, when))
     }
@@ -419,10 +498,11 @@ impl<
         // ensure bond above min after decrease
         ensure!(self.bond > less, Error::<T>::CandidateBondBelowMin);
         ensure!(
-            self.bond - less >= T::MinCandidateStk::get().into(),
+            self.bond - less >= <StakingParams<T>>::get().min_candidate_stk.into(),
             Error::<T>::CandidateBondBelowMin
         );
-        let when_executable = <Era<T>>::get().current + T::CandidateBondLessDelay::get();
+        let when_executable =
+            <Era<T>>::get().current + <StakingParams<T>>::get().candidate_bond_less_delay;
         self.request = Some(CandidateBondLessRequest { amount: less, when_executable });
         Ok(when_executable)
     }
@@ -478,11 +558,38 @@ impl<
         Pallet::<T>::deposit_event(event);
         Ok(())
     }
+    /// Schedule a change of the collator commission, replacing any pending change
+    /// Returns the era from which the new commission applies to selected collator snapshots
+    pub fn schedule_commission_change<T: Config>(
+        &mut self,
+        commission: Perbill,
+    ) -> Result<EraIndex, DispatchError> {
+        ensure!(commission <= T::MaxCommission::get(), Error::<T>::CommissionAboveMax);
+        let pending = self.commission_request.map(|r| r.commission);
+        ensure!(pending.unwrap_or(self.commission) != commission, Error::<T>::NoWritingSameValue);
+        let when_executable =
+            <Era<T>>::get().current + <StakingParams<T>>::get().commission_change_delay;
+        self.commission_request = Some(CommissionChangeRequest { commission, when_executable });
+        Ok(when_executable)
+    }
+    /// Apply the pending commission change if it is due in era `now`
+    /// Returns the (old, new) commission if a change was applied
+    pub fn apply_due_commission_change(&mut self, now: EraIndex) -> Option<(Perbill, Perbill)> {
+        match self.commission_request {
+            Some(request) if request.when_executable <= now => {
+                let old = self.commission;
+                self.commission = request.commission;
+                self.commission_request = None;
+                Some((old, request.commission))
+            },
+            _ => None,
+        }
+    }
     /// Reset top nominations metadata
     pub fn reset_top_data<T: Config>(
         &mut self,
         candidate: T::AccountId,
-        top_nominations: &Nominations<T::AccountId, BalanceOf<T>>,
+        top_nominations: &Nominations<T::AccountId, BalanceOf<T>, T::MaxTopNominationsPerCandidate>,
     ) where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
@@ -500,7 +607,11 @@ impl<
     /// Reset bottom nominations metadata
     pub fn reset_bottom_data<T: Config>(
         &mut self,
-        bottom_nominations: &Nominations<T::AccountId, BalanceOf<T>>,
+        bottom_nominations: &Nominations<
+            T::AccountId,
+            BalanceOf<T>,
+            T::MaxBottomNominationsPerCandidate,
+        >,
     ) where
         BalanceOf<T>: Into<Balance>,
     {
@@ -521,13 +632,19 @@ impl<
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
+        Pallet::<T>::nomination_policy_check_new(
+            candidate,
+            &nomination.owner,
+            nomination.amount,
+            self.nomination_count,
+        )?;
         let mut less_total_staked = None;
         let nominator_added = match self.top_capacity {
             CapacityStatus::Full => {
                 // top is full, insert into top iff the lowest_top < amount
                 if self.lowest_top_nomination_amount < nomination.amount.into() {
                     // bumps lowest top to the bottom inside this function call
-                    less_total_staked = self.add_top_nomination::<T>(candidate, nomination);
+                    less_total_staked = self.add_top_nomination::<T>(candidate, nomination)?;
                     NominatorAdded::AddedToTop { new_total: self.total_counted }
                 } else {
                     // if bottom is full, only insert if greater than lowest bottom (which will
@@ -541,13 +658,13 @@ impl<
                         less_total_staked = Some(self.lowest_bottom_nomination_amount);
                     }
                     // insert into bottom
-                    self.add_bottom_nomination::<T>(false, candidate, nomination);
+                    self.add_bottom_nomination::<T>(false, candidate, nomination)?;
                     NominatorAdded::AddedToBottom
                 }
             },
             // top is either empty or partially full
             _ => {
-                self.add_top_nomination::<T>(candidate, nomination);
+                self.add_top_nomination::<T>(candidate, nomination)?;
                 NominatorAdded::AddedToTop { new_total: self.total_counted }
             },
         };
@@ -560,7 +677,7 @@ impl<
         &mut self,
         candidate: &T::AccountId,
         nomination: Bond<T::AccountId, BalanceOf<T>>,
-    ) -> Option<Balance>
+    ) -> Result<Option<Balance>, DispatchError>
     where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
@@ -568,18 +685,24 @@ impl<
         let mut top_nominations = <TopNominations<T>>::get(candidate)
             .expect("CandidateInfo existence => TopNominations existence");
         let max_top_nominations_per_candidate = T::MaxTopNominationsPerCandidate::get();
-        if top_nominations.nominations.len() as u32 == max_top_nominations_per_candidate {
-            // pop lowest top nomination
-            let new_bottom_nomination = top_nominations.nominations.pop().expect("");
-            top_nominations.total =
-                top_nominations.total.saturating_sub(new_bottom_nomination.amount);
+        let new_bottom_nomination =
+            if top_nominations.nominations.len() as u32 == max_top_nominations_per_candidate {
+                // pop lowest top nomination
+                let new_bottom_nomination = top_nominations.nominations.pop().expect("");
+                top_nominations.total =
+                    top_nominations.total.saturating_sub(new_bottom_nomination.amount);
+                Some(new_bottom_nomination)
+            } else {
+                None
+            };
+        // insert into top before moving the lowest top nomination, which writes to storage
+        top_nominations.insert_sorted_greatest_to_least::<T>(nomination)?;
+        if let Some(new_bottom_nomination) = new_bottom_nomination {
             if matches!(self.bottom_capacity, CapacityStatus::Full) {
                 less_total_staked = Some(self.lowest_bottom_nomination_amount);
             }
-            self.add_bottom_nomination::<T>(true, candidate, new_bottom_nomination);
+            self.add_bottom_nomination::<T>(true, candidate, new_bottom_nomination)?;
         }
-        // insert into top
-        top_nominations.insert_sorted_greatest_to_least(nomination);
         // update candidate info
         self.reset_top_data::<T>(candidate.clone(), &top_nominations);
         if less_total_staked.is_none() {
@@ -587,7 +710,7 @@ impl<
             self.nomination_count = self.nomination_count.saturating_add(1u32);
         }
         <TopNominations<T>>::insert(&candidate, top_nominations);
-        less_total_staked
+        Ok(less_total_staked)
     }
     /// Add nomination to bottom nominations
     /// Check before call that if capacity is full, inserted nomination is higher than lowest
@@ -598,14 +721,15 @@ impl<
         bumped_from_top: bool,
         candidate: &T::AccountId,
         nomination: Bond<T::AccountId, BalanceOf<T>>,
-    ) where
+    ) -> DispatchResult
+    where
         BalanceOf<T>: Into<Balance> + From<Balance>,
     {
         let mut bottom_nominations = <BottomNominations<T>>::get(candidate)
             .expect("CandidateInfo existence => BottomNominations existence");
         // if bottom is full, kick the lowest bottom (which is expected to be lower than input
         // as per check)
-        let increase_nomination_count = if bottom_nominations.nominations.len() as u32 ==
+        let lowest_bottom_to_be_kicked = if bottom_nominations.nominations.len() as u32 ==
             T::MaxBottomNominationsPerCandidate::get()
         {
             let lowest_bottom_to_be_kicked = bottom_nominations
@@ -617,6 +741,18 @@ impl<
             // the lowest bottom to enforce first come first served
             bottom_nominations.total =
                 bottom_nominations.total.saturating_sub(lowest_bottom_to_be_kicked.amount);
+            Some(lowest_bottom_to_be_kicked)
+        } else {
+            None
+        };
+        // insert before the kicked nominator is updated, so a failure leaves storage untouched
+        bottom_nominations.insert_sorted_greatest_to_least::<T>(nomination)?;
+        // only increase nomination count if new bottom nomination (1) doesn't come from top &&
+        // (2) doesn't pop the lowest nomination from the bottom
+        if lowest_bottom_to_be_kicked.is_none() && !bumped_from_top {
+            self.nomination_count = self.nomination_count.saturating_add(1u32);
+        }
+        if let Some(lowest_bottom_to_be_kicked) = lowest_bottom_to_be_kicked {
             // update nominator state
             // total staked is updated via propagation of lowest bottom nomination amount prior
             // to call
@@ -629,6 +765,10 @@ impl<
                 &lowest_bottom_to_be_kicked.owner,
                 &mut nominator_state,
             );
+            <Pallet<T>>::nomination_remove_auto_compound(
+                &candidate,
+                &lowest_bottom_to_be_kicked.owner,
+            );
 
             Pallet::<T>::deposit_event(Event::NominationKicked {
                 nominator: lowest_bottom_to_be_kicked.owner.clone(),
@@ -637,6 +777,7 @@ impl<
             });
             if leaving {
                 <NominatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+                Pallet::<T>::staker_exited(&lowest_bottom_to_be_kicked.owner);
                 Pallet::<T>::deposit_event(Event::NominatorLeft {
                     nominator: lowest_bottom_to_be_kicked.owner,
                     unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -644,18 +785,10 @@ impl<
             } else {
                 <NominatorState<T>>::insert(&lowest_bottom_to_be_kicked.owner, nominator_state);
             }
-            false
-        } else {
-            !bumped_from_top
-        };
-        // only increase nomination count if new bottom nomination (1) doesn't come from top &&
-        // (2) doesn't pop the lowest nomination from the bottom
-        if increase_nomination_count {
-            self.nomination_count = self.nomination_count.saturating_add(1u32);
         }
-        bottom_nominations.insert_sorted_greatest_to_least(nomination);
         self.reset_bottom_data::<T>(&bottom_nominations);
         <BottomNominations<T>>::insert(candidate, bottom_nominations);
+        Ok(())
     }
     /// Remove nomination
     /// Removes from top if amount is above lowest top or top is not full
@@ -701,22 +834,7 @@ impl<
         // remove top nomination
         let mut top_nominations = <TopNominations<T>>::get(candidate)
             .expect("CandidateInfo exists => TopNominations exists");
-        let mut actual_amount_option: Option<BalanceOf<T>> = None;
-        top_nominations.nominations = top_nominations
-            .nominations
-            .clone()
-            .into_iter()
-            .filter(|d| {
-                if d.owner != nominator {
-                    true
-                } else {
-                    actual_amount_option = Some(d.amount);
-                    false
-                }
-            })
-            .collect();
-        let actual_amount = actual_amount_option.ok_or(Error::<T>::NominationDNE)?;
-        top_nominations.total = top_nominations.total.saturating_sub(actual_amount);
+        top_nominations.remove(&nominator).ok_or(Error::<T>::NominationDNE)?;
         // if bottom nonempty => bump top bottom to top
         if !matches!(self.bottom_capacity, CapacityStatus::Empty) {
             let mut bottom_nominations =
@@ -725,10 +843,10 @@ impl<
             let highest_bottom_nomination = bottom_nominations.nominations.remove(0);
             bottom_nominations.total =
                 bottom_nominations.total.saturating_sub(highest_bottom_nomination.amount);
+            // insert highest bottom into top nominations
+            top_nominations.insert_sorted_greatest_to_least::<T>(highest_bottom_nomination)?;
             self.reset_bottom_data::<T>(&bottom_nominations);
             <BottomNominations<T>>::insert(candidate, bottom_nominations);
-            // insert highest bottom into top nominations
-            top_nominations.insert_sorted_greatest_to_least(highest_bottom_nomination);
         }
         // update candidate info
         self.reset_top_data::<T>(candidate.clone(), &top_nominations);
@@ -750,22 +868,7 @@ impl<
         // remove bottom nomination
         let mut bottom_nominations = <BottomNominations<T>>::get(candidate)
             .expect("CandidateInfo exists => BottomNominations exists");
-        let mut actual_amount_option: Option<BalanceOf<T>> = None;
-        bottom_nominations.nominations = bottom_nominations
-            .nominations
-            .clone()
-            .into_iter()
-            .filter(|d| {
-                if d.owner != nominator {
-                    true
-                } else {
-                    actual_amount_option = Some(d.amount);
-                    false
-                }
-            })
-            .collect();
-        let actual_amount = actual_amount_option.ok_or(Error::<T>::NominationDNE)?;
-        bottom_nominations.total = bottom_nominations.total.saturating_sub(actual_amount);
+        bottom_nominations.remove(&nominator).ok_or(Error::<T>::NominationDNE)?;
         // update candidate info
         self.reset_bottom_data::<T>(&bottom_nominations);
         self.nomination_count = self.nomination_count.saturating_sub(1u32);
@@ -815,24 +918,10 @@ impl<
     {
         let mut top_nominations = <TopNominations<T>>::get(candidate)
             .expect("CandidateInfo exists => TopNominations exists");
-        let mut in_top = false;
-        top_nominations.nominations = top_nominations
-            .nominations
-            .clone()
-            .into_iter()
-            .map(|d| {
-                if d.owner != nominator {
-                    d
-                } else {
-                    in_top = true;
-                    let new_amount = d.amount.saturating_add(more);
-                    Bond { owner: d.owner, amount: new_amount }
-                }
-            })
-            .collect();
+        let in_top =
+            top_nominations.mutate_amount(&nominator, |amount| amount.saturating_add(more));
         ensure!(in_top, Error::<T>::NominationDNE);
         top_nominations.total = top_nominations.total.saturating_add(more);
-        top_nominations.sort_greatest_to_least();
         self.reset_top_data::<T>(candidate.clone(), &top_nominations);
         <TopNominations<T>>::insert(candidate, top_nominations);
         Ok(true)
@@ -850,28 +939,12 @@ impl<
     {
         let mut bottom_nominations =
             <BottomNominations<T>>::get(candidate).ok_or(Error::<T>::CandidateDNE)?;
-        let mut nomination_option: Option<Bond<T::AccountId, BalanceOf<T>>> = None;
         let in_top_after = if (bond.saturating_add(more)).into() > self.lowest_top_nomination_amount
         {
             // bump it from bottom
-            bottom_nominations.nominations = bottom_nominations
-                .nominations
-                .clone()
-                .into_iter()
-                .filter(|d| {
-                    if d.owner != nominator {
-                        true
-                    } else {
-                        nomination_option = Some(Bond {
-                            owner: d.owner.clone(),
-                            amount: d.amount.saturating_add(more),
-                        });
-                        false
-                    }
-                })
-                .collect();
-            let nomination = nomination_option.ok_or(Error::<T>::NominationDNE)?;
-            bottom_nominations.total = bottom_nominations.total.saturating_sub(bond);
+            let mut nomination =
+                bottom_nominations.remove(&nominator).ok_or(Error::<T>::NominationDNE)?;
+            nomination.amount = nomination.amount.saturating_add(more);
             // add it to top
             let mut top_nominations = <TopNominations<T>>::get(candidate)
                 .expect("CandidateInfo existence => TopNominations existence");
@@ -884,32 +957,19 @@ impl<
                     .expect("Top capacity full => Exists at least 1 top nomination");
                 top_nominations.total =
                     top_nominations.total.saturating_sub(new_bottom_nomination.amount);
-                bottom_nominations.insert_sorted_greatest_to_least(new_bottom_nomination);
+                bottom_nominations.insert_sorted_greatest_to_least::<T>(new_bottom_nomination)?;
             }
             // insert into top
-            top_nominations.insert_sorted_greatest_to_least(nomination);
+            top_nominations.insert_sorted_greatest_to_least::<T>(nomination)?;
             self.reset_top_data::<T>(candidate.clone(), &top_nominations);
             <TopNominations<T>>::insert(candidate, top_nominations);
             true
         } else {
-            let mut in_bottom = false;
             // just increase the nomination
-            bottom_nominations.nominations = bottom_nominations
-                .nominations
-                .clone()
-                .into_iter()
-                .map(|d| {
-                    if d.owner != nominator {
-                        d
-                    } else {
-                        in_bottom = true;
-                        Bond { owner: d.owner, amount: d.amount.saturating_add(more) }
-                    }
-                })
-                .collect();
+            let in_bottom =
+                bottom_nominations.mutate_amount(&nominator, |amount| amount.saturating_add(more));
             ensure!(in_bottom, Error::<T>::NominationDNE);
             bottom_nominations.total = bottom_nominations.total.saturating_add(more);
-            bottom_nominations.sort_greatest_to_least();
             false
         };
         self.reset_bottom_data::<T>(&bottom_nominations);
@@ -969,26 +1029,10 @@ impl<
         let mut top_nominations =
             <TopNominations<T>>::get(candidate).ok_or(Error::<T>::CandidateDNE)?;
         let in_top_after = if bond_after_less_than_highest_bottom && full_top_and_nonempty_bottom {
-            let mut nomination_option: Option<Bond<T::AccountId, BalanceOf<T>>> = None;
             // take nomination from top
-            top_nominations.nominations = top_nominations
-                .nominations
-                .clone()
-                .into_iter()
-                .filter(|d| {
-                    if d.owner != nominator {
-                        true
-                    } else {
-                        top_nominations.total = top_nominations.total.saturating_sub(d.amount);
-                        nomination_option = Some(Bond {
-                            owner: d.owner.clone(),
-                            amount: d.amount.saturating_sub(less),
-                        });
-                        false
-                    }
-                })
-                .collect();
-            let nomination = nomination_option.ok_or(Error::<T>::NominationDNE)?;
+            let mut nomination =
+                top_nominations.remove(&nominator).ok_or(Error::<T>::NominationDNE)?;
+            nomination.amount = nomination.amount.saturating_sub(less);
             // pop highest bottom by reverse and popping
             let mut bottom_nominations = <BottomNominations<T>>::get(candidate)
                 .expect("CandidateInfo existence => BottomNominations existence");
@@ -996,31 +1040,18 @@ impl<
             bottom_nominations.total =
                 bottom_nominations.total.saturating_sub(highest_bottom_nomination.amount);
             // insert highest bottom into top
-            top_nominations.insert_sorted_greatest_to_least(highest_bottom_nomination);
+            top_nominations.insert_sorted_greatest_to_least::<T>(highest_bottom_nomination)?;
             // insert previous top into bottom
-            bottom_nominations.insert_sorted_greatest_to_least(nomination);
+            bottom_nominations.insert_sorted_greatest_to_least::<T>(nomination)?;
             self.reset_bottom_data::<T>(&bottom_nominations);
             <BottomNominations<T>>::insert(candidate, bottom_nominations);
             false
         } else {
             // keep it in the top
-            let mut is_in_top = false;
-            top_nominations.nominations = top_nominations
-                .nominations
-                .clone()
-                .into_iter()
-                .map(|d| {
-                    if d.owner != nominator {
-                        d
-                    } else {
-                        is_in_top = true;
-                        Bond { owner: d.owner, amount: d.amount.saturating_sub(less) }
-                    }
-                })
-                .collect();
+            let is_in_top =
+                top_nominations.mutate_amount(&nominator, |amount| amount.saturating_sub(less));
             ensure!(is_in_top, Error::<T>::NominationDNE);
             top_nominations.total = top_nominations.total.saturating_sub(less);
-            top_nominations.sort_greatest_to_least();
             true
         };
         self.reset_top_data::<T>(candidate.clone(), &top_nominations);
@@ -1039,22 +1070,9 @@ impl<
     {
         let mut bottom_nominations = <BottomNominations<T>>::get(candidate)
             .expect("CandidateInfo exists => BottomNominations exists");
-        let mut in_bottom = false;
-        bottom_nominations.nominations = bottom_nominations
-            .nominations
-            .clone()
-            .into_iter()
-            .map(|d| {
-                if d.owner != nominator {
-                    d
-                } else {
-                    in_bottom = true;
-                    Bond { owner: d.owner, amount: d.amount.saturating_sub(less) }
-                }
-            })
-            .collect();
+        let in_bottom =
+            bottom_nominations.mutate_amount(&nominator, |amount| amount.saturating_sub(less));
         ensure!(in_bottom, Error::<T>::NominationDNE);
-        bottom_nominations.sort_greatest_to_least();
         self.reset_bottom_data::<T>(&bottom_nominations);
         <BottomNominations<T>>::insert(candidate, bottom_nominations);
         Ok(false)
@@ -1119,18 +1137,8 @@ impl<
     }
 }
 
-impl<A: Clone, B: Copy> From<CollatorCandidate<A, B>> for CollatorSnapshot<A, B> {
-    fn from(other: CollatorCandidate<A, B>) -> CollatorSnapshot<A, B> {
-        CollatorSnapshot {
-            bond: other.bond,
-            nominations: other.top_nominations,
-            total: other.total_counted,
-        }
-    }
-}
-
 #[allow(deprecated)]
-#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum NominatorStatus {
     /// Active with no scheduled exit
     Active,
@@ -1139,13 +1147,15 @@ pub enum NominatorStatus {
     Leaving(EraIndex),
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNominations))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
 /// Nominator state
-pub struct Nominator<AccountId, Balance> {
+pub struct Nominator<AccountId, Balance, MaxNominations: Get<u32>> {
     /// Nominator account
     pub id: AccountId,
     /// All current nominations
-    pub nominations: OrderedSet<Bond<AccountId, Balance>>,
+    pub nominations: BoundedOrderedSet<Bond<AccountId, Balance>, MaxNominations>,
     /// Total balance locked for this nominator
     pub total: Balance,
     /// Sum of pending revocation amounts + bond less amounts
@@ -1154,8 +1164,32 @@ pub struct Nominator<AccountId, Balance> {
     pub status: NominatorStatus,
 }
 
+impl<A: Clone, B: Clone, S: Get<u32>> Clone for Nominator<A, B, S> {
+    fn clone(&self) -> Self {
+        Nominator {
+            id: self.id.clone(),
+            nominations: self.nominations.clone(),
+            total: self.total.clone(),
+            less_total: self.less_total.clone(),
+            status: self.status.clone(),
+        }
+    }
+}
+
+impl<A: Debug, B: Debug, S: Get<u32>> Debug for Nominator<A, B, S> {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        f.debug_struct("Nominator")
+            .field("id", &self.id)
+            .field("nominations", &self.nominations)
+            .field("total", &self.total)
+            .field("less_total", &self.less_total)
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
 // Temporary manual implementation for migration testing purposes
-impl<A: PartialEq, B: PartialEq> PartialEq for Nominator<A, B> {
+impl<A: PartialEq, B: PartialEq, S: Get<u32>> PartialEq for Nominator<A, B, S> {
     fn eq(&self, other: &Self) -> bool {
         let must_be_true = self.id == other.id &&
             self.total == other.total &&
@@ -1186,23 +1220,25 @@ impl<
             + Zero
             + Default
             + Saturating,
-    > Nominator<AccountId, Balance>
+        MaxNominations: Get<u32>,
+    > Nominator<AccountId, Balance, MaxNominations>
 {
-    pub fn new(id: AccountId, collator: AccountId, amount: Balance) -> Self {
-        Nominator {
+    /// Return None if `MaxNominations` does not allow a single nomination
+    pub fn new(id: AccountId, collator: AccountId, amount: Balance) -> Option<Self> {
+        Some(Nominator {
             id,
-            nominations: OrderedSet::from(vec![Bond { owner: collator, amount }]),
+            nominations: BoundedOrderedSet::try_from_vec(vec![Bond { owner: collator, amount }])?,
             total: amount,
             less_total: Balance::zero(),
             status: NominatorStatus::Active,
-        }
+        })
     }
 
     pub fn default_with_total(id: AccountId, amount: Balance) -> Self {
         Nominator {
             id,
             total: amount,
-            nominations: OrderedSet::from(vec![]),
+            nominations: BoundedOrderedSet::new(),
             less_total: Balance::zero(),
             status: NominatorStatus::Active,
         }
@@ -1252,13 +1288,14 @@ impl<
         matches!(self.status, NominatorStatus::Active)
     }
 
-    pub fn add_nomination(&mut self, bond: Bond<AccountId, Balance>) -> bool {
+    /// Return whether the nomination was added, an error if `MaxNominations` is reached
+    pub fn add_nomination(&mut self, bond: Bond<AccountId, Balance>) -> Result<bool, ()> {
         let amt = bond.amount;
-        if self.nominations.insert(bond) {
+        if self.nominations.try_insert(bond)? {
             self.total = self.total.saturating_add(amt);
-            true
+            Ok(true)
         } else {
-            false
+            Ok(false)
         }
     }
     // Return Some(remaining balance), must be more than MinNominatorStk
@@ -1268,22 +1305,9 @@ impl<
         BalanceOf<T>: From<Balance>,
         T::AccountId: From<AccountId>,
     {
-        let mut amt: Option<Balance> = None;
-        let nominations = self
-            .nominations
-            .0
-            .iter()
-            .filter_map(|x| {
-                if &x.owner == collator {
-                    amt = Some(x.amount);
-                    None
-                } else {
-                    Some(x.clone())
-                }
-            })
-            .collect();
+        let amt = self.get_bond_amount(collator);
         if let Some(balance) = amt {
-            self.nominations = OrderedSet::from(nominations);
+            self.nominations.0.retain(|x| &x.owner != collator);
             self.total_sub::<T>(balance).expect("Decreasing lock cannot fail, qed");
             Some(self.total)
         } else {
@@ -1298,13 +1322,14 @@ impl<
     where
         BalanceOf<T>: From<Balance>,
         T::AccountId: From<AccountId>,
-        Nominator<T::AccountId, BalanceOf<T>>: From<Nominator<AccountId, Balance>>,
+        Nominator<T::AccountId, BalanceOf<T>, T::MaxNominationsPerNominator>:
+            From<Nominator<AccountId, Balance, MaxNominations>>,
     {
         let nominator_id: T::AccountId = self.id.clone().into();
         let candidate_id: T::AccountId = candidate.clone().into();
         let balance_amt: BalanceOf<T> = amount.into();
         // increase nomination
-        for x in &mut self.nominations.0 {
+        for x in self.nominations.0.iter_mut() {
             if x.owner == candidate {
                 let before_amount: BalanceOf<T> = x.amount.into();
                 x.amount = x.amount.saturating_add(amount);
@@ -1328,7 +1353,8 @@ impl<
                 <CandidateInfo<T>>::insert(&candidate_id, collator_state);
                 let new_total_staked = <Total<T>>::get().saturating_add(balance_amt);
                 <Total<T>>::put(new_total_staked);
-                let nom_st: Nominator<T::AccountId, BalanceOf<T>> = self.clone().into();
+                let nom_st: Nominator<T::AccountId, BalanceOf<T>, T::MaxNominationsPerNominator> =
+                    self.clone().into();
                 <NominatorState<T>>::insert(&nominator_id, nom_st);
                 Pallet::<T>::deposit_event(Event::NominationIncreased {
                     nominator: nominator_id,
@@ -1508,7 +1534,7 @@ pub struct Nominator2<AccountId, Balance> {
 // 	}
 // }
 
-#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// The current era index and transition information
 pub struct EraInfo<BlockNumber> {
     /// Current era index
@@ -1544,12 +1570,12 @@ impl<
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
-/// Reserve information { account, percent_of_inflation }
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Reserve information { account, percent_of_era_reward }
 pub struct ParachainBondConfig<AccountId> {
     /// Account which receives funds intended for parachain bond
     pub account: AccountId,
-    /// Percent of inflation set aside for parachain bond account
+    /// Percent of each era reward set aside for parachain bond account
     pub percent: Percent,
 }
 impl<A: Decode> Default for ParachainBondConfig<A> {
@@ -1566,3 +1592,124 @@ pub enum BondAdjust<Balance> {
     Increase(Balance),
     Decrease,
 }
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Where the staking rewards of a collator or nominator are paid
+pub enum RewardDestination<AccountId> {
+    /// Paid to the bonded account as free balance
+    Bonded,
+    /// Paid to the given account as free balance
+    Account(AccountId),
+    /// Paid to the bonded account and restaked into the bond that earned it
+    Restake,
+}
+
+impl<A> Default for RewardDestination<A> {
+    fn default() -> RewardDestination<A> {
+        RewardDestination::Bonded
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// How era rewards are delivered to stakers
+pub enum PayoutMode {
+    /// Rewards are transferred to stakers as they are processed after the era
+    Push,
+    /// Rewards are credited to stakers as they are processed and transferred by `claim_rewards`
+    Claim,
+}
+
+impl Default for PayoutMode {
+    fn default() -> PayoutMode {
+        PayoutMode::Push
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// A reward payment that failed and is waiting to be retried
+pub struct FailedRewardPayment<Balance> {
+    /// Reward owed to the staker
+    pub amount: Balance,
+    /// Number of attempts made to pay the reward, including the original payout
+    pub attempts: u32,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Staking parameters set by `MonetaryGovernanceOrigin`, initially the `Config` constants
+pub struct StakingParameters<Balance> {
+    /// Minimum stake required for any candidate to be in `SelectedCandidates` for the era
+    pub min_collator_stk: Balance,
+    /// Minimum stake required for any account to be a collator candidate
+    pub min_candidate_stk: Balance,
+    /// Minimum stake for any registered on-chain account to be a nominator
+    pub min_nominator_stk: Balance,
+    /// Minimum stake for any registered on-chain account to nominate
+    pub min_nomination: Balance,
+    /// Number of eras that candidates remain bonded before exit request is executable
+    pub leave_candidates_delay: EraIndex,
+    /// Number of eras candidate requests to decrease self-bond must wait to be executable
+    pub candidate_bond_less_delay: EraIndex,
+    /// Number of eras candidate requests to change the commission must wait to be executable
+    pub commission_change_delay: EraIndex,
+    /// Number of eras that nominators remain bonded before exit request is executable
+    pub leave_nominators_delay: EraIndex,
+    /// Number of eras that nominations remain bonded before revocation request is executable
+    pub revoke_nomination_delay: EraIndex,
+    /// Number of eras that nomination less requests must wait before executable
+    pub nomination_bond_less_delay: EraIndex,
+    /// Number of eras after which block authors are rewarded
+    pub reward_payment_delay: EraIndex,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// A value of one of the [StakingParameters]
+pub enum StakingParameter<Balance> {
+    MinCollatorStk(Balance),
+    MinCandidateStk(Balance),
+    MinNominatorStk(Balance),
+    MinNomination(Balance),
+    LeaveCandidatesDelay(EraIndex),
+    CandidateBondLessDelay(EraIndex),
+    CommissionChangeDelay(EraIndex),
+    LeaveNominatorsDelay(EraIndex),
+    RevokeNominationDelay(EraIndex),
+    NominationBondLessDelay(EraIndex),
+    RewardPaymentDelay(EraIndex),
+}
+
+impl<Balance: Copy> StakingParameters<Balance> {
+    /// Returns the value of the same parameter as `parameter`
+    pub fn get(&self, parameter: &StakingParameter<Balance>) -> StakingParameter<Balance> {
+        use StakingParameter::*;
+        match parameter {
+            MinCollatorStk(_) => MinCollatorStk(self.min_collator_stk),
+            MinCandidateStk(_) => MinCandidateStk(self.min_candidate_stk),
+            MinNominatorStk(_) => MinNominatorStk(self.min_nominator_stk),
+            MinNomination(_) => MinNomination(self.min_nomination),
+            LeaveCandidatesDelay(_) => LeaveCandidatesDelay(self.leave_candidates_delay),
+            CandidateBondLessDelay(_) => CandidateBondLessDelay(self.candidate_bond_less_delay),
+            CommissionChangeDelay(_) => CommissionChangeDelay(self.commission_change_delay),
+            LeaveNominatorsDelay(_) => LeaveNominatorsDelay(self.leave_nominators_delay),
+            RevokeNominationDelay(_) => RevokeNominationDelay(self.revoke_nomination_delay),
+            NominationBondLessDelay(_) => NominationBondLessDelay(self.nomination_bond_less_delay),
+            RewardPaymentDelay(_) => RewardPaymentDelay(self.reward_payment_delay),
+        }
+    }
+    /// Sets the value of `parameter`
+    pub fn set(&mut self, parameter: StakingParameter<Balance>) {
+        use StakingParameter::*;
+        match parameter {
+            MinCollatorStk(value) => self.min_collator_stk = value,
+            MinCandidateStk(value) => self.min_candidate_stk = value,
+            MinNominatorStk(value) => self.min_nominator_stk = value,
+            MinNomination(value) => self.min_nomination = value,
+            LeaveCandidatesDelay(value) => self.leave_candidates_delay = value,
+            CandidateBondLessDelay(value) => self.candidate_bond_less_delay = value,
+            CommissionChangeDelay(value) => self.commission_change_delay = value,
+            LeaveNominatorsDelay(value) => self.leave_nominators_delay = value,
+            RevokeNominationDelay(value) => self.revoke_nomination_delay = value,
+            NominationBondLessDelay(value) => self.nomination_bond_less_delay = value,
+            RewardPaymentDelay(value) => self.reward_payment_delay = value,
+        }
+    }
+}
@@ -58,10 +58,28 @@ pub trait WeightInfo {
 	fn set_staking_expectations() -> Weight;
     #[rustfmt::skip]
 	fn set_inflation() -> Weight;
+    #[rustfmt::skip]
+	fn set_parachain_bond_account() -> Weight;
+    #[rustfmt::skip]
+	fn set_parachain_bond_reserve_percent() -> Weight;
     #[rustfmt::skip]
 	fn set_total_selected() -> Weight;
     #[rustfmt::skip]
 	fn set_blocks_per_era() -> Weight;
+    #[rustfmt::skip]
+	fn set_payout_mode() -> Weight;
+    #[rustfmt::skip]
+	fn set_eligible_ratio() -> Weight;
+    #[rustfmt::skip]
+	fn set_staking_parameter() -> Weight;
+    #[rustfmt::skip]
+	fn cancel_deferred_slash(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn report_offence() -> Weight;
+    #[rustfmt::skip]
+	fn check_collator_liveness(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn repair_staking_state(x: u32, y: u32, ) -> Weight;
     #[rustfmt::skip]
 	fn join_candidates(x: u32, ) -> Weight;
     #[rustfmt::skip]
@@ -82,8 +100,16 @@ pub trait WeightInfo {
 	fn execute_candidate_bond_less() -> Weight;
     #[rustfmt::skip]
 	fn cancel_candidate_bond_less() -> Weight;
+    #[rustfmt::skip]
+	fn set_commission() -> Weight;
     #[rustfmt::skip]
 	fn nominate(x: u32, y: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn nominate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn nominate_many(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
     #[rustfmt::skip]
 	fn schedule_leave_nominators() -> Weight;
     #[rustfmt::skip]
@@ -104,10 +130,50 @@ pub trait WeightInfo {
 	fn cancel_revoke_nomination() -> Weight;
     #[rustfmt::skip]
 	fn cancel_nominator_bond_less() -> Weight;
+    #[rustfmt::skip]
+	fn set_payee() -> Weight;
+    #[rustfmt::skip]
+	fn claim_rewards(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn retry_failed_reward_payment() -> Weight;
+    #[rustfmt::skip]
+	fn create_pool(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn pool_nominate(x: u32, y: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn join_pool() -> Weight;
+    #[rustfmt::skip]
+	fn unbond_from_pool() -> Weight;
+    #[rustfmt::skip]
+	fn withdraw_unbonded_from_pool(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn claim_pool_rewards() -> Weight;
+    #[rustfmt::skip]
+	fn liquid_stake(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn liquid_unstake() -> Weight;
+    #[rustfmt::skip]
+	fn withdraw_liquid_unbonded(x: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn set_liquid_strategy() -> Weight;
+    #[rustfmt::skip]
+	fn switch_nomination(x: u32, y: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn set_controller() -> Weight;
+    #[rustfmt::skip]
+	fn clear_controller() -> Weight;
+    #[rustfmt::skip]
+	fn set_operator_profile() -> Weight;
+    #[rustfmt::skip]
+	fn clear_operator_profile() -> Weight;
+    #[rustfmt::skip]
+	fn set_nomination_policy() -> Weight;
     #[rustfmt::skip]
 	fn era_transition_on_initialize(x: u32, y: u32, ) -> Weight;
     #[rustfmt::skip]
 	fn pay_one_collator_reward(y: u32, ) -> Weight;
+    #[rustfmt::skip]
+	fn apply_slash(y: u32, ) -> Weight;
     #[rustfmt::skip]
 	fn base_on_initialize() -> Weight;
     #[rustfmt::skip]
@@ -131,6 +197,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+    // Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_parachain_bond_account() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_parachain_bond_reserve_percent() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	#[rustfmt::skip]
     fn set_total_selected() -> Weight {
 		(20_404_000 as Weight)
@@ -145,6 +225,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+    // Storage: ParachainStaking RewardPayoutMode (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_payout_mode() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking EligibleRatio (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_eligible_ratio() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking PendingStakingParams (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_staking_parameter() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+    fn cancel_deferred_slash(x: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking AtStake (r:1 w:0)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking ProcessedOffences (r:1 w:1)
+	#[rustfmt::skip]
+    fn report_offence() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+    // Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+    // Storage: ParachainStaking AuthoredBlocks (r:1 w:1)
+    // Storage: ParachainStaking UnderperformingEras (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+	#[rustfmt::skip]
+    fn check_collator_liveness(x: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((24_000_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking BottomNominations (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:0)
+    // Storage: ParachainStaking CandidatePoolNodes (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn repair_staking_state(x: u32, y: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((62_000_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(x as Weight)))
+	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking NominatorState (r:1 w:0)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
@@ -152,22 +310,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking Total (r:1 w:1)
     // Storage: ParachainStaking TopNominations (r:0 w:1)
     // Storage: ParachainStaking BottomNominations (r:0 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn join_candidates(x: u32, ) -> Weight {
 		(70_223_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((69_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_leave_candidates(x: u32, ) -> Weight {
 		(53_351_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((66_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -177,40 +337,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
     // Storage: ParachainStaking BottomNominations (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:0 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_leave_candidates(x: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 99_000
 			.saturating_add((33_350_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(x as Weight)))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_leave_candidates(x: u32, ) -> Weight {
 		(50_534_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((66_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn go_offline() -> Weight {
 		(27_716_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn go_online() -> Weight {
 		(27_463_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -224,10 +391,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_candidate_bond_less() -> Weight {
 		(26_710_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -241,10 +409,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_candidate_bond_less() -> Weight {
 		(22_585_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_commission() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
     // Storage: System Account (r:1 w:1)
@@ -253,6 +431,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking TopNominations (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
 	#[rustfmt::skip]
     fn nominate(x: u32, y: u32, ) -> Weight {
 		(81_464_000 as Weight)
@@ -260,15 +441,71 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((273_000 as Weight).saturating_mul(x as Weight))
 			// Standard Error: 2_000
 			.saturating_add((156_000 as Weight).saturating_mul(y as Weight))
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+    // Storage: System Account (r:1 w:1)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn nominate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight {
+		(84_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((156_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((120_000 as Weight).saturating_mul(y as Weight))
+			// Standard Error: 1_000
+			.saturating_add((273_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:4 w:4)
+    // Storage: ParachainStaking TopNominations (r:4 w:4)
+    // Storage: ParachainStaking CandidatePool (r:4 w:4)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn nominate_many(x: u32, ) -> Weight {
+		(24_310_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((79_842_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:0)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_auto_compound(x: u32, y: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((120_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((90_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_leave_nominators() -> Weight {
 		(32_060_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
@@ -278,30 +515,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: System Account (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_leave_nominators(x: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 44_000
 			.saturating_add((39_035_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_leave_nominators() -> Weight {
 		(31_955_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_revoke_nomination() -> Weight {
 		(31_793_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
@@ -319,10 +560,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_nominator_bond_less() -> Weight {
 		(31_467_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
@@ -332,11 +574,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: System Account (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_revoke_nomination() -> Weight {
 		(82_319_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
@@ -361,12 +605,275 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_nominator_bond_less() -> Weight {
 		(35_951_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking NominatorState (r:1 w:0)
+    // Storage: ParachainStaking Payee (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_payee() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:11 w:10)
+    // Storage: ParachainStaking Payee (r:10 w:0)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:10 w:0)
+    // Storage: ParachainStaking LockedEraPayout (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+    fn claim_rewards(x: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((45_000_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking FailedRewardPayments (r:1 w:1)
+    // Storage: ParachainStaking Payee (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking LockedEraPayout (r:1 w:1)
+	#[rustfmt::skip]
+    fn retry_failed_reward_payment() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Pools (r:0 w:1)
+    // Storage: ParachainStaking PoolMembers (r:0 w:1)
+    // Storage: ParachainStaking NextPoolId (r:1 w:1)
+	#[rustfmt::skip]
+    fn create_pool(x: u32, ) -> Weight {
+		(78_900_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((84_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn pool_nominate(x: u32, y: u32, ) -> Weight {
+		(92_400_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((84_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((412_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn join_pool() -> Weight {
+		(88_100_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking PoolUnbondings (r:1 w:1)
+	#[rustfmt::skip]
+    fn unbond_from_pool() -> Weight {
+		(74_600_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking PoolUnbondings (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+	#[rustfmt::skip]
+    fn withdraw_unbonded_from_pool(x: u32, ) -> Weight {
+		(31_200_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((46_300_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+    fn claim_pool_rewards() -> Weight {
+		(48_700_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking LiquidStrategy (r:1 w:0)
+    // Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking CandidatePoolNodes (r:1 w:1)
+    // Storage: ParachainStaking Payee (r:1 w:1)
+	#[rustfmt::skip]
+    fn liquid_stake(x: u32, ) -> Weight {
+		(88_900_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((8_400_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: System Account (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondings (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingPoints (r:1 w:1)
+	#[rustfmt::skip]
+    fn liquid_unstake() -> Weight {
+		(71_400_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+    // Storage: ParachainStaking LiquidUnbondings (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingPoints (r:1 w:1)
+	#[rustfmt::skip]
+    fn withdraw_liquid_unbonded(x: u32, ) -> Weight {
+		(29_600_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((46_300_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking LiquidStrategy (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_liquid_strategy() -> Weight {
+		(14_800_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking LastNominationSwitch (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:2 w:0)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:2 w:2)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:2 w:2)
+    // Storage: ParachainStaking TopNominations (r:2 w:2)
+    // Storage: ParachainStaking CandidatePool (r:2 w:2)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn switch_nomination(x: u32, y: u32, ) -> Weight {
+		(92_310_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((291_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((162_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:2 w:0)
+    // Storage: ParachainStaking NominatorState (r:2 w:0)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:2 w:2)
+	#[rustfmt::skip]
+    fn set_controller() -> Weight {
+		(27_113_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:0 w:1)
+	#[rustfmt::skip]
+    fn clear_controller() -> Weight {
+		(19_402_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_operator_profile() -> Weight {
+		(38_217_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+    fn clear_operator_profile() -> Weight {
+		(31_560_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_nomination_policy() -> Weight {
+		(28_904_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
     // Storage: ParachainStaking Points (r:1 w:0)
     // Storage: ParachainStaking Staked (r:1 w:2)
     // Storage: ParachainStaking InflationConfig (r:1 w:0)
@@ -398,7 +905,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ParachainStaking DelayedPayouts (r:1 w:0)
     // Storage: ParachainStaking Points (r:1 w:0)
     // Storage: ParachainStaking AwardedPts (r:2 w:1)
-    // Storage: ParachainStaking AtStake (r:1 w:1)
+    // Storage: ParachainStaking AtStake (r:1 w:0)
     // Storage: System Account (r:1 w:1)
     // Storage: MoonbeamOrbiters OrbiterPerEra (r:1 w:0)
 	#[rustfmt::skip]
@@ -408,9 +915,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((16_304_000 as Weight).saturating_mul(y as Weight))
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)))
 	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+	#[rustfmt::skip]
+    fn apply_slash(y: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((42_000_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(y as Weight)))
+	}
 	#[rustfmt::skip]
     fn base_on_initialize() -> Weight {
 		(4_762_000 as Weight)
@@ -418,8 +943,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	#[rustfmt::skip]
     fn note_author() -> Weight {
 		(71_461_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 }
 
@@ -439,6 +964,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+    // Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_parachain_bond_account() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking ParachainBondInfo (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_parachain_bond_reserve_percent() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
     // Storage: ParachainStaking TotalSelected (r:1 w:1)
 	#[rustfmt::skip]
     fn set_total_selected() -> Weight {
@@ -451,6 +990,84 @@ impl WeightInfo for () {
 	#[rustfmt::skip]
     fn set_blocks_per_era() -> Weight {
 		(57_722_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((10 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking RewardPayoutMode (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_payout_mode() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking EligibleRatio (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_eligible_ratio() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking PendingStakingParams (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_staking_parameter() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+	#[rustfmt::skip]
+    fn cancel_deferred_slash(x: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking AtStake (r:1 w:0)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking UnappliedSlashes (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking ProcessedOffences (r:1 w:1)
+	#[rustfmt::skip]
+    fn report_offence() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+    // Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+    // Storage: ParachainStaking AuthoredBlocks (r:1 w:1)
+    // Storage: ParachainStaking UnderperformingEras (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+	#[rustfmt::skip]
+    fn check_collator_liveness(x: u32, ) -> Weight {
+		(3_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((24_000_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking BottomNominations (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:0)
+    // Storage: ParachainStaking CandidatePoolNodes (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn repair_staking_state(x: u32, y: u32, ) -> Weight {
+		(25_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((62_000_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(y as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
@@ -461,22 +1078,24 @@ impl WeightInfo for () {
     // Storage: ParachainStaking Total (r:1 w:1)
     // Storage: ParachainStaking TopNominations (r:0 w:1)
     // Storage: ParachainStaking BottomNominations (r:0 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn join_candidates(x: u32, ) -> Weight {
 		(70_223_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((69_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_leave_candidates(x: u32, ) -> Weight {
 		(53_351_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((66_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -486,40 +1105,47 @@ impl WeightInfo for () {
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
     // Storage: ParachainStaking BottomNominations (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:0 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_leave_candidates(x: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 99_000
 			.saturating_add((33_350_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(x as Weight)))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_leave_candidates(x: u32, ) -> Weight {
 		(50_534_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((66_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn go_offline() -> Weight {
 		(27_716_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn go_online() -> Weight {
 		(27_463_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -533,10 +1159,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_candidate_bond_less() -> Weight {
 		(26_710_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
@@ -550,10 +1177,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
     // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_candidate_bond_less() -> Weight {
 		(22_585_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_commission() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
     // Storage: System Account (r:1 w:1)
@@ -562,6 +1199,9 @@ impl WeightInfo for () {
     // Storage: ParachainStaking TopNominations (r:1 w:1)
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
 	#[rustfmt::skip]
     fn nominate(x: u32, y: u32, ) -> Weight {
 		(81_464_000 as Weight)
@@ -569,15 +1209,71 @@ impl WeightInfo for () {
 			.saturating_add((273_000 as Weight).saturating_mul(x as Weight))
 			// Standard Error: 2_000
 			.saturating_add((156_000 as Weight).saturating_mul(y as Weight))
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
+    // Storage: System Account (r:1 w:1)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn nominate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight {
+		(84_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((156_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((120_000 as Weight).saturating_mul(y as Weight))
+			// Standard Error: 1_000
+			.saturating_add((273_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:4 w:4)
+    // Storage: ParachainStaking TopNominations (r:4 w:4)
+    // Storage: ParachainStaking CandidatePool (r:4 w:4)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn nominate_many(x: u32, ) -> Weight {
+		(24_310_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((79_842_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:0)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_auto_compound(x: u32, y: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((120_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((90_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_leave_nominators() -> Weight {
 		(32_060_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
@@ -587,30 +1283,34 @@ impl WeightInfo for () {
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: System Account (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_leave_nominators(x: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 44_000
 			.saturating_add((39_035_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(x as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_leave_nominators() -> Weight {
 		(31_955_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_revoke_nomination() -> Weight {
 		(31_793_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
@@ -628,10 +1328,11 @@ impl WeightInfo for () {
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn schedule_nominator_bond_less() -> Weight {
 		(31_467_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
@@ -641,11 +1342,13 @@ impl WeightInfo for () {
     // Storage: ParachainStaking CandidatePool (r:1 w:1)
     // Storage: System Account (r:1 w:1)
     // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking LastNominationSwitch (r:0 w:1)
 	#[rustfmt::skip]
     fn execute_revoke_nomination() -> Weight {
 		(82_319_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
@@ -670,11 +1373,274 @@ impl WeightInfo for () {
 	}
     // Storage: ParachainStaking NominatorState (r:1 w:1)
     // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
 	#[rustfmt::skip]
     fn cancel_nominator_bond_less() -> Weight {
 		(35_951_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking NominatorState (r:1 w:0)
+    // Storage: ParachainStaking Payee (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+	#[rustfmt::skip]
+    fn set_payee() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:11 w:10)
+    // Storage: ParachainStaking Payee (r:10 w:0)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:10 w:0)
+    // Storage: ParachainStaking LockedEraPayout (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+    fn claim_rewards(x: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((45_000_000 as Weight).saturating_mul(x as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking FailedRewardPayments (r:1 w:1)
+    // Storage: ParachainStaking Payee (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking LockedEraPayout (r:1 w:1)
+	#[rustfmt::skip]
+    fn retry_failed_reward_payment() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking Pools (r:0 w:1)
+    // Storage: ParachainStaking PoolMembers (r:0 w:1)
+    // Storage: ParachainStaking NextPoolId (r:1 w:1)
+	#[rustfmt::skip]
+    fn create_pool(x: u32, ) -> Weight {
+		(78_900_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((84_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn pool_nominate(x: u32, y: u32, ) -> Weight {
+		(92_400_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((84_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((412_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+	#[rustfmt::skip]
+    fn join_pool() -> Weight {
+		(88_100_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking PoolUnbondings (r:1 w:1)
+	#[rustfmt::skip]
+    fn unbond_from_pool() -> Weight {
+		(74_600_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking PoolUnbondings (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+	#[rustfmt::skip]
+    fn withdraw_unbonded_from_pool(x: u32, ) -> Weight {
+		(31_200_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((46_300_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking Pools (r:1 w:1)
+    // Storage: ParachainStaking PoolMembers (r:1 w:1)
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+	#[rustfmt::skip]
+    fn claim_pool_rewards() -> Weight {
+		(48_700_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking LiquidStrategy (r:1 w:0)
+    // Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking CandidatePoolNodes (r:1 w:1)
+    // Storage: ParachainStaking Payee (r:1 w:1)
+	#[rustfmt::skip]
+    fn liquid_stake(x: u32, ) -> Weight {
+		(88_900_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((8_400_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+    // Storage: ParachainStaking ClaimableRewards (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: System Account (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:4 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking LiquidUnbondings (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingPoints (r:1 w:1)
+	#[rustfmt::skip]
+    fn liquid_unstake() -> Weight {
+		(71_400_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+    // Storage: ParachainStaking LiquidUnbondings (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:2 w:2)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingTotal (r:1 w:1)
+    // Storage: ParachainStaking LiquidUnbondingPoints (r:1 w:1)
+	#[rustfmt::skip]
+    fn withdraw_liquid_unbonded(x: u32, ) -> Weight {
+		(29_600_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((46_300_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(x as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(x as Weight)))
+	}
+    // Storage: ParachainStaking LiquidStrategy (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_liquid_strategy() -> Weight {
+		(14_800_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking Era (r:1 w:0)
+    // Storage: ParachainStaking LastNominationSwitch (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:2 w:0)
+    // Storage: ParachainStaking StakingParams (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:2 w:2)
+    // Storage: ParachainStaking AutoCompoundingNominations (r:2 w:2)
+    // Storage: ParachainStaking TopNominations (r:2 w:2)
+    // Storage: ParachainStaking CandidatePool (r:2 w:2)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:0)
+	#[rustfmt::skip]
+    fn switch_nomination(x: u32, y: u32, ) -> Weight {
+		(92_310_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((291_000 as Weight).saturating_mul(x as Weight))
+			// Standard Error: 1_000
+			.saturating_add((162_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:2 w:0)
+    // Storage: ParachainStaking NominatorState (r:2 w:0)
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:2 w:2)
+	#[rustfmt::skip]
+    fn set_controller() -> Weight {
+		(27_113_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+    // Storage: ParachainStaking Controllers (r:1 w:1)
+    // Storage: ParachainStaking Stashes (r:0 w:1)
+	#[rustfmt::skip]
+    fn clear_controller() -> Weight {
+		(19_402_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_operator_profile() -> Weight {
+		(38_217_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking OperatorProfiles (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+	#[rustfmt::skip]
+    fn clear_operator_profile() -> Weight {
+		(31_560_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+    // Storage: ParachainStaking Stashes (r:1 w:0)
+    // Storage: ParachainStaking CandidateInfo (r:1 w:0)
+    // Storage: ParachainStaking NominationPolicies (r:1 w:1)
+	#[rustfmt::skip]
+    fn set_nomination_policy() -> Weight {
+		(28_904_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
     // Storage: ParachainStaking Points (r:1 w:0)
     // Storage: ParachainStaking Staked (r:1 w:2)
@@ -707,7 +1673,7 @@ impl WeightInfo for () {
     // Storage: ParachainStaking DelayedPayouts (r:1 w:0)
     // Storage: ParachainStaking Points (r:1 w:0)
     // Storage: ParachainStaking AwardedPts (r:2 w:1)
-    // Storage: ParachainStaking AtStake (r:1 w:1)
+    // Storage: ParachainStaking AtStake (r:1 w:0)
     // Storage: System Account (r:1 w:1)
     // Storage: MoonbeamOrbiters OrbiterPerEra (r:1 w:0)
 	#[rustfmt::skip]
@@ -717,9 +1683,27 @@ impl WeightInfo for () {
 			.saturating_add((16_304_000 as Weight).saturating_mul(y as Weight))
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)))
 	}
+    // Storage: ParachainStaking CandidateInfo (r:1 w:1)
+    // Storage: Balances Locks (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: ParachainStaking Total (r:1 w:1)
+    // Storage: ParachainStaking CandidatePool (r:1 w:1)
+    // Storage: ParachainStaking NominatorState (r:1 w:1)
+    // Storage: ParachainStaking TopNominations (r:1 w:1)
+    // Storage: ParachainStaking NominationScheduledRequests (r:1 w:0)
+	#[rustfmt::skip]
+    fn apply_slash(y: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((42_000_000 as Weight).saturating_mul(y as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(y as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(y as Weight)))
+	}
 	#[rustfmt::skip]
     fn base_on_initialize() -> Weight {
 		(4_762_000 as Weight)
@@ -727,7 +1711,7 @@ impl WeightInfo for () {
 	#[rustfmt::skip]
     fn note_author() -> Weight {
 		(71_461_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
 }